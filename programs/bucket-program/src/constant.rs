@@ -1,24 +1,74 @@
 pub const MAX_BASIS_POINTS: u16 = 10000;
 
-/// based on current PDA attributes, max collateral elements is approx 315.
-/// beyond this, we risk exceeding the 10MB account size limitation.
+/// collateral allocations are in parts per million, i.e. 10_000 => 1%, 1_000_000 => 100%. the
+/// authorized collateral's allocations always sum to MAX_ALLOCATION_PPM.
+pub const MAX_ALLOCATION_PPM: u32 = 1_000_000;
+
+/// allocations used to be in bps. one bps is this many ppm.
+pub const ALLOCATION_PPM_PER_BPS: u32 = 100;
+
+/// max number of collateral elements a bucket can hold. the bucket is a zero-copy account with a
+/// fixed capacity collateral array. at 37 bytes per element, the account is larger than the 10KB an
+/// account created through a CPI can hold, so buckets are created and grown with resize_bucket.
 pub const MAX_COLLATERAL_ELEMENTS: usize = 315;
 
+/// number of NAV snapshots kept in a bucket's snapshot history. once the history is full, each new
+/// snapshot overwrites the oldest one.
+pub const NAV_SNAPSHOT_HISTORY_LEN: usize = 32;
+
+/// current layout version of the bucket account
+pub const BUCKET_VERSION: u8 = 1;
+
+/// layout version of every event. see event.rs.
+pub const EVENT_VERSION: u8 = 1;
+
 /// PDA seed strings
 pub const BUCKET_SEED: &str = "bucket";
 pub const ISSUE_SEED: &str = "issue";
 pub const WITHDRAW_SEED: &str = "withdraw";
+pub const SWAP_PAIR_SEED: &str = "swap-pair";
+pub const COLLATERAL_WRAPPER_SEED: &str = "collateral-wrapper";
+pub const COLLATERAL_CONFIG_SEED: &str = "collateral-config";
+pub const REBALANCE_STATE_SEED: &str = "rebalance-state";
+pub const PROPOSAL_SEED: &str = "proposal";
+pub const ALLOCATION_RAMP_SEED: &str = "allocation-ramp";
+pub const BUCKET_STATS_SEED: &str = "bucket-stats";
+pub const NAV_SNAPSHOT_SEED: &str = "nav-snapshot";
 pub const TARGET_ORACLE_PRECISION: u32 = 6;
 pub const MAX_ORACLE_CONF: u128 = 100_000;
 pub const SLOTS_BEFORE_STALE: i64 = 60;
 
-/// (todo): store in config somewhere else? or accept dynamic percentage,
-/// while making sure that max slippage stays under some predefined threshold?
-/// this sttic value may lead to routine failed swaps depending on the pool &
-/// per-asset liquidity depth. revisit later.
-///
-/// note: anything lower than 25% slippage causes swap tests to fail on localnet
-/// despite sufficient (~50M) per-asset liquidity. only used in localnet/devnet for testing. need
-/// more research to understand how our approach should evolve for an eventual
-/// mainnet launch.
-pub const MAX_SLIPPAGE_BPS: u64 = 2_500;
+/// slippage allowed on swaps anyone can trigger, i.e. rebalance_to_target and stray collateral
+/// cleanup, measured against oracle prices. these swaps pay out a keeper bounty, so a loose bound
+/// would let a caller sandwich the pool and take the difference on every call.
+pub const MAX_PERMISSIONLESS_SLIPPAGE_BPS: u64 = 30;
+
+/// slippage allowed on swaps the rebalance authority sizes itself, measured against oracle prices.
+/// the authority may route larger swaps through thinner pools than a keeper would, but a compromised
+/// rebalancer still cannot sell collateral far below its oracle value.
+pub const MAX_AUTHORITY_SLIPPAGE_BPS: u64 = 100;
+
+/// caps the value moved by a single rebalance_to_target invocation to a percentage of the bucket's
+/// total collateral value. large rebalances are broken up across multiple calls, which limits price
+/// impact on thin pools.
+pub const MAX_REBALANCE_TO_TARGET_BPS: u64 = 1_000;
+
+/// upper bound on the keeper bounty the authority can configure, in bps of the swap output.
+pub const MAX_KEEPER_BOUNTY_BPS: u16 = 100;
+
+/// default rebalance limits for new buckets. ~1 minute between rebalances and at most 25% of the
+/// bucket's value swapped per ~1 day window, assuming 400ms slots. the authority can change these.
+pub const DEFAULT_MIN_SLOTS_BETWEEN_REBALANCES: u64 = 150;
+pub const DEFAULT_TURNOVER_WINDOW_SLOTS: u64 = 216_000;
+pub const DEFAULT_MAX_TURNOVER_BPS: u16 = 2_500;
+
+/// upper bound on the admin timelock delay, ~30 days assuming 400ms slots. prevents the authority
+/// from locking the bucket's configuration indefinitely.
+pub const MAX_TIMELOCK_DELAY_SLOTS: u64 = 6_480_000;
+
+/// pause bitflags. the guardian or authority can pause these operations for the whole bucket or for
+/// a single collateral. only the authority can unpause.
+pub const PAUSE_DEPOSITS: u8 = 1 << 0;
+pub const PAUSE_REDEEMS: u8 = 1 << 1;
+pub const PAUSE_REBALANCES: u8 = 1 << 2;
+pub const PAUSE_ALL: u8 = PAUSE_DEPOSITS | PAUSE_REDEEMS | PAUSE_REBALANCES;
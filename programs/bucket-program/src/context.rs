@@ -1,14 +1,29 @@
 use {
     crate::{
-        constant::{BUCKET_SEED, ISSUE_SEED, WITHDRAW_SEED},
+        constant::{
+            ALLOCATION_RAMP_SEED, BUCKET_SEED, BUCKET_STATS_SEED, COLLATERAL_CONFIG_SEED,
+            COLLATERAL_WRAPPER_SEED, ISSUE_SEED, NAV_SNAPSHOT_SEED, PROPOSAL_SEED,
+            REBALANCE_STATE_SEED, SWAP_PAIR_SEED, WITHDRAW_SEED,
+        },
         state::{
-            bucket::{Bucket, BUCKET_ACCOUNT_SPACE},
+            allocation_ramp::{AllocationRamp, ALLOCATION_RAMP_ACCOUNT_SPACE},
+            bucket::Bucket,
+            bucket_stats::{BucketStats, BUCKET_STATS_ACCOUNT_SPACE},
+            collateral_config::{CollateralConfig, COLLATERAL_CONFIG_ACCOUNT_SPACE},
+            collateral_wrapper::{CollateralWrapper, COLLATERAL_WRAPPER_ACCOUNT_SPACE},
             issue_authority::IssueAuthority,
+            nav_snapshot::{NavSnapshot, NAV_SNAPSHOT_ACCOUNT_SPACE},
+            proposal::{AdminAction, Proposal},
+            rebalance_state::{RebalanceState, REBALANCE_STATE_ACCOUNT_SPACE},
+            swap_pair::{SwapPair, SWAP_PAIR_ACCOUNT_SPACE},
             withdraw_authority::WithdrawAuthority,
         },
     },
-    anchor_lang::prelude::*,
-    anchor_spl::token::{Burn, Mint, Token, TokenAccount, Transfer},
+    anchor_lang::{prelude::*, solana_program::program_option::COption},
+    anchor_spl::{
+        associated_token::get_associated_token_address,
+        token::{Burn, Mint, Token, TokenAccount, Transfer},
+    },
     crate_token::cpi::accounts::{Issue, NewCrate, Withdraw},
     stable_swap_anchor::{Swap, SwapOutput, SwapToken, SwapUserContext},
 };
@@ -30,63 +45,823 @@ pub struct CreateBucket<'info> {
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    /// created with resize_bucket, since the bucket is larger than an account created through a
+    /// CPI can be. grown to BUCKET_ACCOUNT_SPACE by the instruction.
     #[account(
-        init,
+        zero,
         seeds = [
             BUCKET_SEED.as_bytes(),
             crate_token.key().to_bytes().as_ref()
         ],
+        bump
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// entity with authority to mint and issue new reserve tokens
+    #[account(
+        init,
+        seeds = [
+            ISSUE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer
+    )]
+    pub issue_authority: Account<'info, IssueAuthority>,
+
+    /// entity with authority to withdraw tokens from crate ATAs
+    #[account(
+        init,
+        seeds = [
+            WITHDRAW_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer
+    )]
+    pub withdraw_authority: Account<'info, WithdrawAuthority>,
+
+    /// rebalance cooldown and turnover tracking
+    #[account(
+        init,
+        seeds = [
+            REBALANCE_STATE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
         bump,
         payer = payer,
-        space = BUCKET_ACCOUNT_SPACE
+        space = REBALANCE_STATE_ACCOUNT_SPACE
     )]
-    pub bucket: Account<'info, Bucket>,
+    pub rebalance_state: Box<Account<'info, RebalanceState>>,
 
-    /// entity with authority to mint and issue new reserve tokens
+    /// running totals of the bucket's activity
+    #[account(
+        init,
+        seeds = [
+            BUCKET_STATS_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = payer,
+        space = BUCKET_STATS_ACCOUNT_SPACE
+    )]
+    pub bucket_stats: Box<Account<'info, BucketStats>>,
+
+    /// Account that has authority to invoke rebalance instruction
+    /// CHECK: unsafe account type, we don't read from or write to.
+    pub rebalance_authority: AccountInfo<'info>,
+
+    /// Mint of the reserve token linked to the [crate_token::CrateToken]
+    pub crate_mint: Account<'info, Mint>,
+
+    /// PDA of the [crate_token::CrateToken] protocol. Must adhere to a specific PDA
+    /// address derivation, otherwise CPI call will fail. See source here:
+    /// https://github.com/CrateProtocol/crate/blob/master/programs/crate_token/src/lib.rs#L258-L267
+    /// CHECK: unsafe account type, required for CPI invocation.
+    #[account(mut)]
+    pub crate_token: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateRebalanceAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AuthorizedUpdate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RampAllocations<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    /// created the first time the bucket's allocations are ramped and reused afterwards
+    #[account(
+        init_if_needed,
+        seeds = [
+            ALLOCATION_RAMP_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = ALLOCATION_RAMP_ACCOUNT_SPACE
+    )]
+    pub allocation_ramp: Box<Account<'info, AllocationRamp>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StopAllocationRamp<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            ALLOCATION_RAMP_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = bucket
+    )]
+    pub allocation_ramp: Box<Account<'info, AllocationRamp>>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// must match the bucket's pending authority
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = pending_authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bump: u8, action: AdminAction)]
+pub struct EnqueueProposal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            PROPOSAL_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref(),
+            bucket.load()?.timelock.proposal_count.to_le_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = Proposal::space(&action)
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    /// anyone can execute a proposal once its eta slot has passed. pays the rent of accounts the
+    /// proposal creates.
+    #[account(mut)]
+    pub executor: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bucket,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: receives the proposal's rent. verified against the proposal.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+/// remaining accounts of execute_proposal for UpdateCollateralConfig and SetAllocationBand. the
+/// config's bucket and mint are verified against the proposal in the instruction.
+#[derive(Accounts)]
+pub struct ProposalCollateralConfig<'info> {
+    #[account(mut)]
+    pub collateral_config: Account<'info, CollateralConfig>,
+}
+
+/// remaining accounts of execute_proposal for AddSwapPair
+#[derive(Accounts)]
+pub struct ProposalAddSwapPair<'info> {
+    /// CHECK: created in the instruction. address is verified against the proposal's PDA.
+    #[account(mut)]
+    pub swap_pair: UncheckedAccount<'info>,
+
+    /// CHECK: must be executable. verified against the proposal in the instruction.
+    #[account(executable)]
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// CHECK: must be owned by the swap program. verified against the proposal in the instruction.
+    #[account(constraint = *swap.owner == swap_program.key())]
+    pub swap: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// remaining accounts of execute_proposal for RemoveSwapPair. the pair's bucket and mints are
+/// verified against the proposal in the instruction.
+#[derive(Accounts)]
+pub struct ProposalRemoveSwapPair<'info> {
+    #[account(mut)]
+    pub swap_pair: Account<'info, SwapPair>,
+}
+
+#[derive(Accounts)]
+pub struct CancelProposal<'info> {
+    /// must be the bucket authority or the bucket guardian
+    pub canceller: Signer<'info>,
+
+    #[account(
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bucket,
+        has_one = proposer,
+        close = proposer
+    )]
+    pub proposal: Account<'info, Proposal>,
+
+    /// CHECK: receives the proposal's rent. verified against the proposal.
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// must be the bucket authority or the bucket guardian
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetGuardian<'info> {
+    /// must be the bucket authority or the current guardian
+    pub setter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBucket<'info> {
+    /// receives the rent of every closed account
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority,
+        has_one = crate_mint,
+        close = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    pub crate_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [
+            ISSUE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        close = authority
+    )]
+    pub issue_authority: Box<Account<'info, IssueAuthority>>,
+
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAW_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        close = authority
+    )]
+    pub withdraw_authority: Box<Account<'info, WithdrawAuthority>>,
+
+    #[account(
+        mut,
+        seeds = [
+            REBALANCE_STATE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = bucket,
+        close = authority
+    )]
+    pub rebalance_state: Box<Account<'info, RebalanceState>>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_STATS_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = bucket,
+        close = authority
+    )]
+    pub bucket_stats: Box<Account<'info, BucketStats>>,
+
+    /// CHECK: may not be initialized. closed by the instruction if it exists.
+    #[account(
+        mut,
+        seeds = [
+            NAV_SNAPSHOT_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub nav_snapshot: UncheckedAccount<'info>,
+
+    /// CHECK: may not be initialized. closed by the instruction if it exists.
+    #[account(
+        mut,
+        seeds = [
+            ALLOCATION_RAMP_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub allocation_ramp: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(bucket_bump: u8)]
+pub struct ResizeBucket<'info> {
+    /// anyone can grow a bucket, since its size only depends on the layout. pays the rent for the
+    /// larger account.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: created if it does not exist, otherwise it must be owned by this program. buckets at
+    /// an older layout are grown too, so the account is not deserialized.
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump = bucket_bump
+    )]
+    pub bucket: UncheckedAccount<'info>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// the bucket is unchecked because accounts at an older version cannot be deserialized as the
+/// current [Bucket]. the instruction verifies the authority against the old layout.
+#[derive(Accounts)]
+pub struct MigrateBucket<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: deserialized and verified in the instruction.
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        owner = crate::ID
+    )]
+    pub bucket: UncheckedAccount<'info>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    /// buckets at the old layout were created before the rebalance state existed
+    #[account(
+        init,
+        seeds = [
+            REBALANCE_STATE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = REBALANCE_STATE_ACCOUNT_SPACE
+    )]
+    pub rebalance_state: Box<Account<'info, RebalanceState>>,
+
+    /// same as the rebalance state, buckets at the old layout have no stats
+    #[account(
+        init,
+        seeds = [
+            BUCKET_STATS_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = BUCKET_STATS_ACCOUNT_SPACE
+    )]
+    pub bucket_stats: Box<Account<'info, BucketStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetRebalanceLimits<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            REBALANCE_STATE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = bucket
+    )]
+    pub rebalance_state: Account<'info, RebalanceState>,
+}
+
+#[derive(Accounts)]
+pub struct AddSwapPair<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    pub mint_a: Account<'info, Mint>,
+
+    #[account(constraint = mint_a.key() < mint_b.key())]
+    pub mint_b: Account<'info, Mint>,
+
+    #[account(
+        init,
+        seeds = [
+            SWAP_PAIR_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref(),
+            mint_a.key().to_bytes().as_ref(),
+            mint_b.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = SWAP_PAIR_ACCOUNT_SPACE
+    )]
+    pub swap_pair: Account<'info, SwapPair>,
+
+    /// program that swaps for this pair will be routed through
+    /// CHECK: must be executable. the venue's expected program id is checked in the instruction.
+    #[account(executable)]
+    pub swap_program: UncheckedAccount<'info>,
+
+    /// pool account of the swap program
+    /// CHECK: must be owned by the swap program.
+    #[account(constraint = *swap.owner == swap_program.key())]
+    pub swap: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveSwapPair<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bucket,
+        close = authority
+    )]
+    pub swap_pair: Account<'info, SwapPair>,
+}
+
+#[derive(Accounts)]
+pub struct AddCollateralConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        seeds = [
+            COLLATERAL_CONFIG_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref(),
+            mint.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = COLLATERAL_CONFIG_ACCOUNT_SPACE
+    )]
+    pub collateral_config: Account<'info, CollateralConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateCollateralConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(mut, has_one = bucket)]
+    pub collateral_config: Account<'info, CollateralConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CloseCollateralConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = authority
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bucket,
+        close = authority
+    )]
+    pub collateral_config: Account<'info, CollateralConfig>,
+
+    /// the crate's ATA for the collateral. stray collateral can only be cleared out with the
+    /// config's oracle, so the config can only be closed once the crate holds none of it.
+    /// CHECK: may not exist. verified against the canonical ATA address.
+    #[account(address = get_associated_token_address(crate_token.key, &collateral_config.mint))]
+    pub crate_collateral: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReportAllocationBands<'info> {
+    #[account(
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unsafe account type, required for CPI invocation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    /// CHECK: may not be initialized. see load_target_collateral.
+    #[account(
+        seeds = [
+            ALLOCATION_RAMP_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub allocation_ramp: UncheckedAccount<'info>,
+}
+
+/// anyone can take a snapshot. the first caller pays for the snapshot account.
+#[derive(Accounts)]
+pub struct SnapshotNav<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            BUCKET_SEED.as_bytes(),
+            crate_token.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = crate_mint,
+    )]
+    pub bucket: AccountLoader<'info, Bucket>,
+
+    /// CHECK: unused. used in PDA derivation.
+    pub crate_token: UncheckedAccount<'info>,
+
+    pub crate_mint: Account<'info, Mint>,
+
+    /// CHECK: may not be initialized. see load_target_collateral.
     #[account(
-        init,
         seeds = [
-            ISSUE_SEED.as_bytes(),
+            ALLOCATION_RAMP_SEED.as_bytes(),
             bucket.key().to_bytes().as_ref()
         ],
         bump,
-        payer = payer
     )]
-    pub issue_authority: Account<'info, IssueAuthority>,
+    pub allocation_ramp: UncheckedAccount<'info>,
 
-    /// entity with authority to withdraw tokens from crate ATAs
     #[account(
-        init,
+        init_if_needed,
         seeds = [
-            WITHDRAW_SEED.as_bytes(),
+            NAV_SNAPSHOT_SEED.as_bytes(),
             bucket.key().to_bytes().as_ref()
         ],
         bump,
-        payer = payer
+        payer = payer,
+        space = NAV_SNAPSHOT_ACCOUNT_SPACE
     )]
-    pub withdraw_authority: Account<'info, WithdrawAuthority>,
-
-    /// Account that has authority to invoke rebalance instruction
-    /// CHECK: unsafe account type, we don't read from or write to.
-    pub rebalance_authority: AccountInfo<'info>,
-
-    /// Mint of the reserve token linked to the [crate_token::CrateToken]
-    pub crate_mint: Account<'info, Mint>,
-
-    /// PDA of the [crate_token::CrateToken] protocol. Must adhere to a specific PDA
-    /// address derivation, otherwise CPI call will fail. See source here:
-    /// https://github.com/CrateProtocol/crate/blob/master/programs/crate_token/src/lib.rs#L258-L267
-    /// CHECK: unsafe account type, required for CPI invocation.
-    #[account(mut)]
-    pub crate_token: UncheckedAccount<'info>,
+    pub nav_snapshot: Box<Account<'info, NavSnapshot>>,
 
     pub system_program: Program<'info, System>,
-
-    pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
 }
 
 #[derive(Accounts)]
-pub struct UpdateRebalanceAuthority<'info> {
+pub struct AddCollateralWrapper<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
@@ -98,14 +873,55 @@ pub struct UpdateRebalanceAuthority<'info> {
         bump,
         has_one = authority
     )]
-    pub bucket: Account<'info, Bucket>,
+    pub bucket: AccountLoader<'info, Bucket>,
 
     /// CHECK: unsafe account type, required for CPI invocation.
     pub crate_token: UncheckedAccount<'info>,
+
+    /// collateral mint that is wrapped
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        seeds = [
+            COLLATERAL_WRAPPER_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref(),
+            mint.key().to_bytes().as_ref()
+        ],
+        bump,
+        payer = authority,
+        space = COLLATERAL_WRAPPER_ACCOUNT_SPACE
+    )]
+    pub collateral_wrapper: Account<'info, CollateralWrapper>,
+
+    /// CHECK: must be executable.
+    #[account(executable)]
+    pub wrapper_program: UncheckedAccount<'info>,
+
+    /// wrapper PDA of the decimal wrapper program
+    /// CHECK: must be owned by the wrapper program.
+    #[account(constraint = *wrapper.owner == wrapper_program.key())]
+    pub wrapper: UncheckedAccount<'info>,
+
+    /// mint of the wrapped token. must have more decimals than the underlying collateral.
+    #[account(
+        constraint = wrapper_mint.mint_authority == COption::Some(wrapper.key()),
+        constraint = wrapper_mint.decimals > mint.decimals
+    )]
+    pub wrapper_mint: Account<'info, Mint>,
+
+    #[account(
+        constraint = wrapper_underlying_tokens.mint == mint.key(),
+        constraint = wrapper_underlying_tokens.owner == wrapper.key()
+    )]
+    pub wrapper_underlying_tokens: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(Accounts)]
-pub struct AuthorizedUpdate<'info> {
+pub struct RemoveCollateralWrapper<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
 
     #[account(
@@ -117,10 +933,17 @@ pub struct AuthorizedUpdate<'info> {
         bump,
         has_one = authority
     )]
-    pub bucket: Account<'info, Bucket>,
+    pub bucket: AccountLoader<'info, Bucket>,
 
     /// CHECK: unsafe account type, required for CPI invocation.
     pub crate_token: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        has_one = bucket,
+        close = authority
+    )]
+    pub collateral_wrapper: Account<'info, CollateralWrapper>,
 }
 
 #[derive(Accounts)]
@@ -137,7 +960,7 @@ pub struct Rebalance<'info> {
         ],
         bump,
     )]
-    pub bucket: Account<'info, Bucket>,
+    pub bucket: AccountLoader<'info, Bucket>,
 
     /// CHECK: unused. used in PDA derivation.
     pub crate_token: UncheckedAccount<'info>,
@@ -151,44 +974,49 @@ pub struct Rebalance<'info> {
     )]
     pub withdraw_authority: Box<Account<'info, WithdrawAuthority>>,
 
-    /// =============== CPI ACCOUNTS BELOW ===============
-    /// saber stable swap program
-    /// CHECK: verified via CPI call for saber swap.
-    pub swap: UncheckedAccount<'info>,
-
-    /// The authority of the swap.
-    /// CHECK: verified via CPI call for saber swap.
-    pub swap_authority: UncheckedAccount<'info>,
-
-    /// The authority of the user.
-    /// CHECK: verified via CPI call for saber swap.
-    #[account(mut)]
-    pub user_authority: UncheckedAccount<'info>,
-
-    /// The token account for the pool's reserves of this token.
-    #[account(mut)]
-    pub input_a_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            REBALANCE_STATE_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = bucket
+    )]
+    pub rebalance_state: Box<Account<'info, RebalanceState>>,
 
-    /// The token account for the pool's reserves of this token.
-    #[account(mut)]
-    pub output_b_reserve: Box<Account<'info, TokenAccount>>,
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_STATS_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = bucket
+    )]
+    pub bucket_stats: Box<Account<'info, BucketStats>>,
 
-    /// The token account for the fees associated with the token.
-    /// CHECK: verified via CPI call for saber swap.
-    #[account(mut)]
-    pub output_b_fees: UncheckedAccount<'info>,
+    /// CHECK: may not be initialized. see load_target_collateral.
+    #[account(
+        seeds = [
+            ALLOCATION_RAMP_SEED.as_bytes(),
+            bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub allocation_ramp: UncheckedAccount<'info>,
 
-    /// The pool mint of the swap.
-    /// CHECK: verified via CPI call for saber swap.
-    #[account(mut)]
-    pub pool_mint: UncheckedAccount<'info>,
+    /// whitelisted collateral pair. determines which venue the swap is routed through.
+    #[account(has_one = bucket)]
+    pub swap_pair: Box<Account<'info, SwapPair>>,
 
     /// =============== PROGRAM ACCOUNTS ===============
     /// The crate_token program.
     pub crate_token_program: Program<'info, crate_token::program::CrateToken>,
 
-    /// CHECK: verified via CPI call for saber swap.
-    pub saber_program: UncheckedAccount<'info>,
+    /// program of the swap pair's venue
+    /// CHECK: verified against the swap pair's registered program before any CPI.
+    pub swap_program: UncheckedAccount<'info>,
 
     /// The spl_token program.
     pub token_program: Program<'info, Token>,
@@ -242,8 +1070,42 @@ pub struct Deposit<'info> {
     )]
     pub depositor_reserve: Box<Account<'info, TokenAccount>>,
 
+    /// deposits are priced with the oracle recorded in the config, and are subject to its status
+    /// and caps
+    #[account(
+        mut,
+        seeds = [
+            COLLATERAL_CONFIG_SEED.as_bytes(),
+            common.bucket.key().to_bytes().as_ref(),
+            collateral_mint.key().to_bytes().as_ref()
+        ],
+        bump,
+        has_one = oracle
+    )]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+
     /// CHECK: required for CPI into pyth
     pub oracle: AccountInfo<'info>,
+
+    /// CHECK: may not be initialized. see load_target_collateral.
+    #[account(
+        seeds = [
+            ALLOCATION_RAMP_SEED.as_bytes(),
+            common.bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub allocation_ramp: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_STATS_SEED.as_bytes(),
+            common.bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket_stats: Box<Account<'info, BucketStats>>,
 }
 
 #[derive(Accounts)]
@@ -267,6 +1129,16 @@ pub struct Redeem<'info> {
         constraint = withdrawer_reserve.mint == common.crate_mint.key(),
     )]
     pub withdrawer_reserve: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            BUCKET_STATS_SEED.as_bytes(),
+            common.bucket.key().to_bytes().as_ref()
+        ],
+        bump,
+    )]
+    pub bucket_stats: Box<Account<'info, BucketStats>>,
 }
 
 #[derive(Accounts)]
@@ -278,15 +1150,15 @@ pub struct Common<'info> {
         ],
         bump,
     )]
-    pub bucket: Account<'info, Bucket>,
+    pub bucket: AccountLoader<'info, Bucket>,
 
     /// CHECK: unsafe account type, required for CPI invocation.
     pub crate_token: UncheckedAccount<'info>,
 
     #[account(
         mut,
-        constraint = crate_mint.freeze_authority.unwrap() == crate_token.key(),
-        constraint = crate_mint.mint_authority.unwrap() == crate_token.key(),
+        constraint = crate_mint.freeze_authority == COption::Some(crate_token.key()),
+        constraint = crate_mint.mint_authority == COption::Some(crate_token.key()),
     )]
     pub crate_mint: Account<'info, Mint>,
 
@@ -320,8 +1192,14 @@ pub struct RedeemAsset<'info> {
     /// Protocol fee collateral ATA
     #[account(mut)]
     pub protocol_fee_destination: Box<Account<'info, TokenAccount>>,
+
+    /// config of the collateral. verified against the bucket and mint in the instruction.
+    #[account(mut)]
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
 }
 
+/// parsed from remaining accounts. the token account owners are verified against the crate and
+/// bucket in swap_route_from_account_infos.
 #[derive(Accounts)]
 pub struct RebalanceAsset<'info> {
     pub token_a: Account<'info, Mint>,
@@ -353,6 +1231,164 @@ pub struct RebalanceAsset<'info> {
     pub bucket_dest_ata: Box<Account<'info, TokenAccount>>,
 }
 
+#[derive(Accounts)]
+pub struct TwoHopRebalanceAsset<'info> {
+    pub token_a: Account<'info, Mint>,
+
+    /// intermediate mint both collateral trade against. does not need to be authorized collateral.
+    pub token_intermediate: Account<'info, Mint>,
+
+    pub token_b: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = crate_source_ata.mint == token_a.key()
+    )]
+    pub crate_source_ata: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bucket_source_ata.mint == token_a.key(),
+    )]
+    pub bucket_source_ata: Box<Account<'info, TokenAccount>>,
+
+    /// bucket ATA temporarily holding the intermediate token between the two swaps
+    #[account(
+        mut,
+        constraint = bucket_intermediate_ata.mint == token_intermediate.key(),
+    )]
+    pub bucket_intermediate_ata: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bucket_dest_ata.mint == token_b.key(),
+    )]
+    pub bucket_dest_ata: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = crate_dest_ata.mint == token_b.key(),
+    )]
+    pub crate_dest_ata: Box<Account<'info, TokenAccount>>,
+
+    /// whitelisted pair for the second swap, i.e. intermediate → token_b
+    pub second_swap_pair: Box<Account<'info, SwapPair>>,
+
+    /// program of the second swap pair's venue
+    /// CHECK: verified against the second swap pair's registered program before any CPI.
+    pub second_swap_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DecimalWrapperAsset<'info> {
+    /// wrapper config of the collateral with fewer decimals
+    pub collateral_wrapper: Box<Account<'info, CollateralWrapper>>,
+
+    /// CHECK: verified against the collateral wrapper config.
+    pub wrapper_program: UncheckedAccount<'info>,
+
+    /// CHECK: verified against the collateral wrapper config.
+    pub wrapper: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub wrapper_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: verified against the collateral wrapper config.
+    #[account(mut)]
+    pub wrapper_underlying_tokens: UncheckedAccount<'info>,
+
+    /// bucket ATA holding the wrapped token between the wrap/unwrap and the swap
+    #[account(
+        mut,
+        constraint = bucket_wrapped_ata.mint == wrapper_mint.key()
+    )]
+    pub bucket_wrapped_ata: Box<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct SaberSwapAsset<'info> {
+    /// saber stable swap
+    /// CHECK: verified against the swap pair's registered swap account.
+    pub swap: UncheckedAccount<'info>,
+
+    /// The authority of the swap.
+    /// CHECK: verified via CPI call for saber swap.
+    pub swap_authority: UncheckedAccount<'info>,
+
+    /// The token account for the pool's reserves of the input token.
+    #[account(mut)]
+    pub input_reserve: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the pool's reserves of the output token.
+    #[account(mut)]
+    pub output_reserve: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the fees associated with the output token.
+    /// CHECK: verified via CPI call for saber swap.
+    #[account(mut)]
+    pub output_fees: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConstantProductSwapAsset<'info> {
+    /// pool state of the constant-product venue
+    /// CHECK: verified against the swap pair's registered swap account.
+    pub pool: UncheckedAccount<'info>,
+
+    /// PDA that owns the pool's reserves
+    /// CHECK: verified via CPI call to the venue.
+    pub pool_authority: UncheckedAccount<'info>,
+
+    /// The token account for the pool's reserves of the input token.
+    #[account(mut)]
+    pub input_reserve: Box<Account<'info, TokenAccount>>,
+
+    /// The token account for the pool's reserves of the output token.
+    #[account(mut)]
+    pub output_reserve: Box<Account<'info, TokenAccount>>,
+}
+
+#[derive(Accounts)]
+pub struct KeeperBountyAsset<'info> {
+    /// payer ATA receiving the bounty. the mint depends on the bucket's bounty source.
+    #[account(mut)]
+    pub bounty_destination: Box<Account<'info, TokenAccount>>,
+
+    /// entity with authority to mint and issue new reserve tokens
+    pub issue_authority: Box<Account<'info, IssueAuthority>>,
+
+    /// Mint of the reserve token linked to the [crate_token::CrateToken]
+    #[account(mut)]
+    pub crate_mint: Box<Account<'info, Mint>>,
+}
+
+#[derive(Accounts)]
+pub struct CollateralValuationAsset<'info> {
+    /// Mint of an authorized collateral
+    pub mint: Account<'info, Mint>,
+
+    /// crate ATA holding the bucket's balance of this collateral
+    #[account(constraint = crate_collateral.mint == mint.key())]
+    pub crate_collateral: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: required for CPI into pyth. verified against the collateral config.
+    pub oracle: AccountInfo<'info>,
+
+    /// config of the collateral. verified against the bucket and mint when parsed.
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+}
+
+/// oracle and config of a mint on a swap route that is not part of the valuation accounts, i.e.
+/// collateral that is no longer authorized or a two hop route's intermediate mint. verified against
+/// the bucket and mint in collateral_leg_from_account_infos.
+#[derive(Accounts)]
+pub struct CollateralOracleAsset<'info> {
+    /// CHECK: verified against the collateral config.
+    pub oracle: AccountInfo<'info>,
+
+    pub collateral_config: Box<Account<'info, CollateralConfig>>,
+}
+
 // ======================================
 // CPI CONTEXT TRANSFORMATIONS
 // ======================================
@@ -465,7 +1501,8 @@ impl<'info> Rebalance<'info> {
     // withdraw from a crate ATA to a bucket ATA
     pub fn into_withdraw_collateral_context(
         &self,
-        asset: &RebalanceAsset<'info>,
+        crate_source_ata: &AccountInfo<'info>,
+        bucket_source_ata: &AccountInfo<'info>,
     ) -> CpiContext<'_, '_, '_, 'info, Withdraw<'info>> {
         let cpi_program = self.crate_token_program.to_account_info();
 
@@ -473,72 +1510,131 @@ impl<'info> Rebalance<'info> {
             /// crate protocol PDA
             crate_token: self.crate_token.to_account_info(),
             /// crate collateral ATA
-            crate_underlying: asset.crate_source_ata.to_account_info(),
+            crate_underlying: crate_source_ata.clone(),
             /// entity with authority to withdraw collateral from a crate ATA
             withdraw_authority: self.withdraw_authority.to_account_info(),
             /// bucket collateral ATA
-            withdraw_destination: asset.bucket_source_ata.to_account_info(),
+            withdraw_destination: bucket_source_ata.clone(),
             /// n/a in this ixn
-            author_fee_destination: asset.bucket_source_ata.to_account_info(),
+            author_fee_destination: bucket_source_ata.clone(),
             /// n/a in this ixn
-            protocol_fee_destination: asset.bucket_source_ata.to_account_info(),
+            protocol_fee_destination: bucket_source_ata.clone(),
             token_program: self.token_program.to_account_info(),
         };
 
         CpiContext::new(cpi_program, cpi_accounts)
     }
 
-    // swap from bucket ATA for token A to bucket ATA for token B. bucket will sign the transaction.
+    // swap from a bucket token account to another bucket token account. these are the bucket ATAs for
+    // token A and token B, or a bucket ATA for a wrapped token. bucket will sign the transaction.
     pub fn into_saber_swap_context(
         &self,
-        asset: &RebalanceAsset<'info>,
+        saber: &SaberSwapAsset<'info>,
+        swap_program: &AccountInfo<'info>,
+        user_source: &AccountInfo<'info>,
+        user_destination: &AccountInfo<'info>,
     ) -> CpiContext<'_, '_, '_, 'info, Swap<'info>> {
-        let cpi_program = self.saber_program.to_account_info();
+        let cpi_program = swap_program.clone();
 
         let cpi_accounts = Swap {
             user: SwapUserContext {
                 /// The spl_token program.
                 token_program: self.token_program.to_account_info(),
                 /// The authority of the swap.
-                swap_authority: self.swap_authority.to_account_info(),
+                swap_authority: saber.swap_authority.to_account_info(),
                 /// The authority of the user.
                 user_authority: self.bucket.to_account_info(),
                 /// The swap --> is this the pool?
-                swap: self.swap.to_account_info(),
+                swap: saber.swap.to_account_info(),
             },
             input: SwapToken {
-                /// The token account associated with the swap requester's source ATA
-                user: asset.bucket_source_ata.to_account_info(),
+                /// The token account associated with the swap requester's source token account
+                user: user_source.clone(),
                 /// The token account for the pool’s reserves of this token.
-                reserve: self.input_a_reserve.to_account_info(),
+                reserve: saber.input_reserve.to_account_info(),
             },
             output: SwapOutput {
                 // The token accounts of the user and the token.
                 user_token: SwapToken {
-                    /// The token account associated with the swap requester's destination ATA
-                    user: asset.bucket_dest_ata.to_account_info(),
+                    /// The token account associated with the swap requester's destination token account
+                    user: user_destination.clone(),
                     /// The token account for the pool’s reserves of this token.
-                    reserve: self.output_b_reserve.to_account_info(),
+                    reserve: saber.output_reserve.to_account_info(),
                 },
                 // The token account for the fees associated with the token.
-                fees: self.output_b_fees.to_account_info(),
+                fees: saber.output_fees.to_account_info(),
             },
         };
 
         CpiContext::new(cpi_program, cpi_accounts)
     }
 
-    pub fn into_transfer_token_context(
+    // withdraw the keeper's bounty from the crate ATA that received the swap output
+    pub fn into_withdraw_bounty_context(
         &self,
         asset: &RebalanceAsset<'info>,
+        bounty: &KeeperBountyAsset<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Withdraw<'info>> {
+        let cpi_program = self.crate_token_program.to_account_info();
+
+        let cpi_accounts = Withdraw {
+            /// crate protocol PDA
+            crate_token: self.crate_token.to_account_info(),
+            /// crate collateral ATA
+            crate_underlying: asset.crate_dest_ata.to_account_info(),
+            /// entity with authority to withdraw collateral from a crate ATA
+            withdraw_authority: self.withdraw_authority.to_account_info(),
+            /// keeper collateral ATA
+            withdraw_destination: bounty.bounty_destination.to_account_info(),
+            /// n/a in this ixn
+            author_fee_destination: bounty.bounty_destination.to_account_info(),
+            /// n/a in this ixn
+            protocol_fee_destination: bounty.bounty_destination.to_account_info(),
+            token_program: self.token_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    // issue the keeper's bounty as newly minted reserve tokens
+    pub fn into_issue_bounty_context(
+        &self,
+        bounty: &KeeperBountyAsset<'info>,
+    ) -> CpiContext<'_, '_, '_, 'info, Issue<'info>> {
+        let cpi_program = self.crate_token_program.to_account_info();
+
+        let cpi_accounts = Issue {
+            /// crate protocol PDA
+            crate_token: self.crate_token.to_account_info(),
+            /// crate reserve mint account
+            crate_mint: bounty.crate_mint.to_account_info(),
+            /// entity with authority to issue new crate_mint
+            issue_authority: bounty.issue_authority.to_account_info(),
+            /// keeper reserve ATA
+            mint_destination: bounty.bounty_destination.to_account_info(),
+            /// n/a in this ixn
+            author_fee_destination: bounty.bounty_destination.to_account_info(),
+            /// n/a in this ixn
+            protocol_fee_destination: bounty.bounty_destination.to_account_info(),
+            /// solana token program
+            token_program: self.token_program.to_account_info(),
+        };
+
+        CpiContext::new(cpi_program, cpi_accounts)
+    }
+
+    pub fn into_transfer_token_context(
+        &self,
+        bucket_dest_ata: &AccountInfo<'info>,
+        crate_dest_ata: &AccountInfo<'info>,
     ) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
         let cpi_program = self.token_program.to_account_info();
 
         let cpi_accounts = Transfer {
             /// source ATA => bucket ATA
-            from: asset.bucket_dest_ata.to_account_info(),
+            from: bucket_dest_ata.clone(),
             /// destination ATA => crate ATA
-            to: asset.crate_dest_ata.to_account_info(),
+            to: crate_dest_ata.clone(),
             /// entity authorizing transfer. owner of source ATA.
             authority: self.bucket.to_account_info(),
         };
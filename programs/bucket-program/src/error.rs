@@ -4,8 +4,8 @@ use anchor_lang::prelude::*;
 pub enum ErrorCode {
     #[msg("Must burn reserve token")]
     WrongBurnError,
-    #[msg("Allocation bps error")]
-    AllocationBpsError,
+    #[msg("Allocation ppm error")]
+    AllocationPpmError,
     #[msg("Must deposit an approved collateral mint")]
     WrongCollateralError,
     #[msg("Cannot re-authorized a collateral mint authorized")]
@@ -18,8 +18,6 @@ pub enum ErrorCode {
     CallerCannotRebalanceCollateral,
     #[msg("Must maintain at least 1 approved collateral mint")]
     MinCollateralError,
-    #[msg("Number is too large and is not supported")]
-    NumberOfSizeNotSupported,
     #[msg("Unable To Load Oracles")]
     UnableToLoadOracle,
     #[msg("Math Error")]
@@ -28,9 +26,120 @@ pub enum ErrorCode {
     CastingFailure,
     #[msg("Oracle Values are invalid")]
     InvalidOracle,
+    #[msg("Collateral valuation accounts must match the bucket's authorized collateral")]
+    CollateralValuationAccountsMismatch,
+    #[msg("Collateral allocations are already at their targets")]
+    RebalanceNotRequired,
+    #[msg("Rebalance accounts do not match the computed source and destination collateral")]
+    RebalancePairMismatch,
+    #[msg("Rebalance would increase the bucket's total allocation deviation")]
+    RebalanceIncreasedDeviation,
+    #[msg("Swap lost more value than the slippage tolerance allows")]
+    SwapValueLost,
+    #[msg("Keeper bounty exceeds the max bounty bps")]
+    KeeperBountyTooLarge,
+    #[msg("Keeper bounty accounts are invalid")]
+    InvalidKeeperBountyAccounts,
+    #[msg("Rebalance token accounts must belong to the crate and bucket")]
+    InvalidRebalanceTokenAccounts,
+    #[msg("Swap pair does not cover the collateral being rebalanced")]
+    SwapPairMismatch,
+    #[msg("Swap venue accounts are invalid")]
+    InvalidSwapVenueAccounts,
+    #[msg("Swap program is not registered for this swap pair")]
+    UnregisteredSwapProgram,
+    #[msg("Swap pool is not registered for this swap pair")]
+    UnregisteredSwapPool,
+    #[msg("Collateral decimals differ, a decimal wrapper is required")]
+    DecimalWrapperRequired,
+    #[msg("Decimal wrapper accounts do not match the collateral wrapper config")]
+    InvalidDecimalWrapperAccounts,
+    #[msg("Scaling an amount between decimals overflowed")]
+    DecimalScalingOverflow,
+    #[msg("Rebalance limits are invalid")]
+    InvalidRebalanceLimits,
+    #[msg("Too few slots have passed since the last rebalance")]
+    RebalanceCooldownActive,
+    #[msg("Rebalance exceeds the bucket's turnover limit for the trailing window")]
+    RebalanceTurnoverExceeded,
+    #[msg("There is no pending authority transfer")]
+    NoPendingAuthority,
+    #[msg("Pending authority is invalid")]
+    InvalidPendingAuthority,
+    #[msg("Timelock is enabled, admin changes must be enqueued as a proposal")]
+    TimelockEnabled,
+    #[msg("Timelock is disabled, admin changes can be applied directly")]
+    TimelockDisabled,
+    #[msg("Timelock delay exceeds the max delay")]
+    TimelockDelayTooLong,
+    #[msg("Proposal cannot be executed before its eta slot")]
+    ProposalNotReady,
+    #[msg("Only the bucket authority or guardian can cancel a proposal")]
+    UnauthorizedProposalCanceller,
+    #[msg("Operation is paused")]
+    Paused,
+    #[msg("Pause flags are invalid")]
+    InvalidPauseFlags,
+    #[msg("Only the bucket authority or guardian can pause")]
+    UnauthorizedPauser,
+    #[msg("Only the bucket authority or the guardian can set the guardian")]
+    UnauthorizedGuardianUpdate,
+    #[msg("Bucket is decommissioned")]
+    BucketDecommissioned,
+    #[msg("Bucket must be decommissioned before it can be closed")]
+    BucketNotDecommissioned,
+    #[msg("Bucket still holds collateral or has reserve supply")]
+    BucketNotEmpty,
+    #[msg("Bucket still has collateral configs, swap pairs or collateral wrappers")]
+    BucketHasChildAccounts,
+    #[msg("Account cannot grow by more than 10KB in a single instruction")]
+    AccountResizeTooLarge,
+    #[msg("Bucket account is already at the current version")]
+    BucketAlreadyMigrated,
+    #[msg("Bucket account is already at its full size")]
+    BucketAlreadySized,
+    #[msg("Bucket account data is invalid")]
+    InvalidBucketAccount,
+    #[msg("Collateral is not accepting deposits")]
+    CollateralNotAcceptingDeposits,
+    #[msg("Collateral is frozen")]
+    CollateralFrozen,
+    #[msg("Deposit exceeds the collateral's cap")]
+    CollateralCapExceeded,
+    #[msg("Collateral config does not match the collateral")]
+    InvalidCollateralConfig,
+    #[msg("Collateral config can only be closed once the collateral is removed")]
+    CollateralStillAuthorized,
+    #[msg("Oracle source is not supported")]
+    UnsupportedOracleSource,
+    #[msg("Accounts do not match the proposal's action")]
+    InvalidProposalAccounts,
+    #[msg("Collateral config can only be closed once the crate holds none of the collateral")]
+    CollateralConfigHasBalance,
+    #[msg("Allocation band exceeds max bps")]
+    InvalidAllocationBand,
+    #[msg("Collateral is within its allocation band")]
+    CollateralWithinBand,
+    #[msg("Deposit would push the collateral above its allocation band")]
+    AllocationBandExceeded,
+    #[msg("Allocation ramp must end after it starts")]
+    InvalidAllocationRamp,
+    #[msg("No allocation ramp in progress")]
+    NoActiveAllocationRamp,
+    #[msg("Collateral mints must be unique")]
+    DuplicateCollateral,
+    #[msg("Oracle exponent is not supported")]
+    OracleExponentUnsupported,
+    #[msg("Issue amount does not fit in a token amount")]
+    IssueAmountOverflow,
+    #[msg("Wrong number of rebalance accounts")]
+    InvalidRebalanceAccountCount,
+    #[msg("Wrong number of redeem accounts")]
+    InvalidRedeemAccountCount,
+    #[msg("A NAV snapshot was already taken in this slot")]
+    NavSnapshotAlreadyTaken,
 }
 
-
 #[macro_export]
 macro_rules! math_error {
     () => {{
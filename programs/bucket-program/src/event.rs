@@ -0,0 +1,350 @@
+// every event starts with a version byte set to EVENT_VERSION. event layouts are stable: fields
+// are only ever appended, and any change to an existing field bumps EVENT_VERSION, so that
+// indexers can decode events from every program version.
+
+use {
+    crate::state::{
+        bucket::{AllocationChange, Collateral, KeeperBounty},
+        collateral_config::{AllocationBand, CollateralStatus},
+        oracle::OracleSource,
+        proposal::AdminAction,
+        rebalance_state::RebalanceLimits,
+        swap_pair::SwapVenue,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct BucketCreated {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub crate_token: Pubkey,
+    pub crate_mint: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct BucketMigrated {
+    pub version: u8,
+    pub bucket: Pubkey,
+    /// layout version the bucket was migrated to
+    pub bucket_version: u8,
+}
+
+#[event]
+pub struct BucketResized {
+    pub version: u8,
+    pub bucket: Pubkey,
+    /// account size after the instruction, in bytes
+    pub space: u64,
+}
+
+#[event]
+pub struct BucketDecommissioned {
+    pub version: u8,
+    pub bucket: Pubkey,
+}
+
+#[event]
+pub struct BucketClosed {
+    pub version: u8,
+    pub bucket: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferAccepted {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct RebalanceAuthorityUpdated {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub previous_rebalance_authority: Pubkey,
+    pub rebalance_authority: Pubkey,
+}
+
+#[event]
+pub struct TimelockConfigured {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub delay_slots: u64,
+    pub guardian: Pubkey,
+}
+
+#[event]
+pub struct GuardianSet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub guardian: Pubkey,
+    pub setter: Pubkey,
+}
+
+#[event]
+pub struct ProposalEnqueued {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub eta_slot: u64,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub action: AdminAction,
+}
+
+#[event]
+pub struct ProposalCancelled {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub proposal_id: u64,
+    pub canceller: Pubkey,
+}
+
+#[event]
+pub struct PauseFlagsSet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    /// collateral the flags apply to, or none for the whole bucket
+    pub mint: Option<Pubkey>,
+    pub flags: u8,
+    pub paused: bool,
+}
+
+/// authorizing collateral rescales the allocations of the existing collateral, see AllocationsSet
+/// for the resulting allocations
+#[event]
+pub struct CollateralAuthorized {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub allocation: u32,
+}
+
+#[event]
+pub struct CollateralRemoved {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct CollateralSetUpdated {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub added: Vec<Collateral>,
+    pub removed: Vec<Pubkey>,
+    pub reallocated: Vec<AllocationChange>,
+}
+
+/// the bucket's allocations after any change to them
+#[event]
+pub struct AllocationsSet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub allocations: Vec<Collateral>,
+}
+
+#[event]
+pub struct AllocationRampStarted {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    pub start_allocations: Vec<u32>,
+    pub end_allocations: Vec<u32>,
+}
+
+#[event]
+pub struct AllocationRampStopped {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub slot: u64,
+}
+
+#[event]
+pub struct AllocationBandsReported {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub out_of_band: Vec<Pubkey>,
+}
+
+#[event]
+pub struct CollateralConfigAdded {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub oracle_source: OracleSource,
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct CollateralConfigUpdated {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub oracle_source: OracleSource,
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct CollateralConfigClosed {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct AllocationBandSet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub band: AllocationBand,
+}
+
+#[event]
+pub struct CollateralStatusSet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub status: CollateralStatus,
+}
+
+#[event]
+pub struct CollateralWrapperAdded {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub wrapper_mint: Pubkey,
+}
+
+#[event]
+pub struct CollateralWrapperRemoved {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint: Pubkey,
+    pub wrapper_mint: Pubkey,
+}
+
+#[event]
+pub struct SwapPairAdded {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub venue: SwapVenue,
+    pub pool: Pubkey,
+}
+
+#[event]
+pub struct SwapPairRemoved {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+}
+
+#[event]
+pub struct KeeperBountySet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub keeper_bounty: KeeperBounty,
+}
+
+#[event]
+pub struct RebalanceLimitsSet {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub limits: RebalanceLimits,
+}
+
+#[event]
+pub struct Deposited {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub depositor: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// oracle price used to issue the reserve, in TARGET_ORACLE_PRECISION
+    pub price: i128,
+    pub reserve_issued: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct RedeemedShare {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct Redeemed {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub withdrawer: Pubkey,
+    pub reserve_burned: u64,
+    pub shares: Vec<RedeemedShare>,
+}
+
+#[event]
+pub struct Rebalanced {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub caller: Pubkey,
+    pub mint_in: Pubkey,
+    pub mint_out: Pubkey,
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// pool of every leg of the swap, in order
+    pub pools: Vec<Pubkey>,
+    /// keeper bounty paid, in units of the bounty's source token
+    pub keeper_bounty: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq)]
+pub struct CollateralWeight {
+    pub mint: Pubkey,
+    /// share of the bucket's total value, in ppm
+    pub weight: u32,
+    /// target allocation, in ppm
+    pub target_weight: u32,
+}
+
+#[event]
+pub struct NavSnapshotTaken {
+    pub version: u8,
+    pub bucket: Pubkey,
+    pub slot: u64,
+    /// value of the bucket's collateral, in TARGET_ORACLE_PRECISION
+    pub total_value: u128,
+    pub reserve_supply: u64,
+    /// value backing one whole reserve token, in TARGET_ORACLE_PRECISION
+    pub nav_per_token: u128,
+    pub collateralization_bps: u64,
+    /// in the bucket's collateral order
+    pub weights: Vec<CollateralWeight>,
+}
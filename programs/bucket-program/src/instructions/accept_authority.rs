@@ -0,0 +1,19 @@
+use {
+    crate::{constant::EVENT_VERSION, context::AcceptAuthority, event::AuthorityTransferAccepted},
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AcceptAuthority>) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    let previous_authority = bucket.authority;
+    bucket.accept_authority(ctx.accounts.pending_authority.key())?;
+
+    emit!(AuthorityTransferAccepted {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        previous_authority,
+        authority: bucket.authority,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::AddCollateralConfig,
+        event::CollateralConfigAdded,
+        state::{
+            bucket::ChildAccount,
+            collateral_config::{CollateralCaps, FeeOverrides},
+            oracle::OracleSource,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(
+    ctx: Context<AddCollateralConfig>,
+    bump: u8,
+    oracle_source: OracleSource,
+    oracle: Pubkey,
+    caps: CollateralCaps,
+    fee_overrides: FeeOverrides,
+) -> ProgramResult {
+    ctx.accounts
+        .bucket
+        .load_mut()?
+        .record_child_account_opened(ChildAccount::CollateralConfig)?;
+    ctx.accounts.collateral_config.init(
+        bump,
+        ctx.accounts.bucket.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.mint.decimals,
+        oracle_source,
+        oracle,
+        caps,
+        fee_overrides,
+    )?;
+
+    emit!(CollateralConfigAdded {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint: ctx.accounts.mint.key(),
+        oracle_source,
+        oracle,
+    });
+
+    Ok(())
+}
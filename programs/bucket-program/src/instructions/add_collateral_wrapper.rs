@@ -0,0 +1,32 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::AddCollateralWrapper, event::CollateralWrapperAdded,
+        state::bucket::ChildAccount,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AddCollateralWrapper>, bump: u8) -> ProgramResult {
+    ctx.accounts
+        .bucket
+        .load_mut()?
+        .record_child_account_opened(ChildAccount::CollateralWrapper)?;
+    ctx.accounts.collateral_wrapper.init(
+        bump,
+        ctx.accounts.bucket.key(),
+        ctx.accounts.mint.key(),
+        ctx.accounts.wrapper_program.key(),
+        ctx.accounts.wrapper.key(),
+        ctx.accounts.wrapper_mint.key(),
+        ctx.accounts.wrapper_underlying_tokens.key(),
+    );
+
+    emit!(CollateralWrapperAdded {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint: ctx.accounts.mint.key(),
+        wrapper_mint: ctx.accounts.wrapper_mint.key(),
+    });
+
+    Ok(())
+}
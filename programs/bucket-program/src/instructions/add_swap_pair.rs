@@ -0,0 +1,41 @@
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::AddSwapPair,
+        event::SwapPairAdded,
+        state::{
+            bucket::ChildAccount,
+            swap_pair::{SwapPair, SwapVenue},
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AddSwapPair>, bump: u8, venue: SwapVenue) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    SwapPair::verify_venue(venue, ctx.accounts.swap_program.key())?;
+    bucket.record_child_account_opened(ChildAccount::SwapPair)?;
+
+    ctx.accounts.swap_pair.init(
+        bump,
+        ctx.accounts.bucket.key(),
+        ctx.accounts.mint_a.key(),
+        ctx.accounts.mint_b.key(),
+        venue,
+        ctx.accounts.swap_program.key(),
+        ctx.accounts.swap.key(),
+    );
+
+    let swap_pair = &ctx.accounts.swap_pair;
+    emit!(SwapPairAdded {
+        version: EVENT_VERSION,
+        bucket: swap_pair.bucket,
+        mint_a: swap_pair.mint_a,
+        mint_b: swap_pair.mint_b,
+        venue,
+        pool: swap_pair.swap,
+    });
+
+    Ok(())
+}
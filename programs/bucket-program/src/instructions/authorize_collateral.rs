@@ -1,7 +1,28 @@
-use {crate::context::AuthorizedUpdate, anchor_lang::prelude::*};
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::AuthorizedUpdate,
+        event::{AllocationsSet, CollateralAuthorized},
+    },
+    anchor_lang::prelude::*,
+};
 
-pub fn handle(ctx: Context<AuthorizedUpdate>, mint: Pubkey, allocation: u16) -> ProgramResult {
-    ctx.accounts.bucket.add_collateral(mint, allocation)?;
+pub fn handle(ctx: Context<AuthorizedUpdate>, mint: Pubkey, allocation: u32) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    bucket.add_collateral(mint, allocation)?;
+
+    emit!(CollateralAuthorized {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint,
+        allocation,
+    });
+    emit!(AllocationsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        allocations: bucket.collateral().to_vec(),
+    });
 
     Ok(())
 }
@@ -0,0 +1,21 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::AuthorizedUpdate, event::AuthorityTransferCancelled,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    let pending_authority = bucket.pending_authority;
+    bucket.cancel_authority_transfer()?;
+
+    emit!(AuthorityTransferCancelled {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        authority: bucket.authority,
+        pending_authority,
+    });
+
+    Ok(())
+}
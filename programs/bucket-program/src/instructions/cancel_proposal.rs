@@ -0,0 +1,28 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::CancelProposal, error::ErrorCode,
+        event::ProposalCancelled,
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<CancelProposal>) -> ProgramResult {
+    let bucket = ctx.accounts.bucket.load()?;
+    let canceller = ctx.accounts.canceller.key();
+    invariant!(
+        canceller == bucket.authority
+            || (bucket.guardian != Pubkey::default() && canceller == bucket.guardian),
+        ErrorCode::UnauthorizedProposalCanceller
+    );
+
+    msg!("cancelling proposal {}", ctx.accounts.proposal.id);
+    emit!(ProposalCancelled {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        proposal_id: ctx.accounts.proposal.id,
+        canceller,
+    });
+
+    Ok(())
+}
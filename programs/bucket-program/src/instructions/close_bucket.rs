@@ -0,0 +1,95 @@
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::CloseBucket,
+        error::ErrorCode,
+        event::BucketClosed,
+        state::{bucket::ChildAccount, collateral_config::CollateralConfig},
+        util::close_account_if_initialized,
+    },
+    anchor_lang::{prelude::*, AccountsClose},
+    anchor_spl::{associated_token::get_associated_token_address, token::TokenAccount},
+    vipers::invariant,
+};
+
+/// number of remaining accounts close_bucket takes for each authorized collateral
+pub const ACCOUNTS_PER_CLOSE_ASSET: usize = 2;
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, CloseBucket<'info>>) -> ProgramResult {
+    let bucket_key = ctx.accounts.bucket.key();
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    invariant!(
+        bucket.is_decommissioned(),
+        ErrorCode::BucketNotDecommissioned
+    );
+    invariant!(
+        ctx.accounts.crate_mint.supply == 0,
+        ErrorCode::BucketNotEmpty
+    );
+
+    // remaining accounts are the crate's ATA and the collateral config of each authorized
+    // collateral, in the same order as the bucket's collateral, i.e. sorted by mint. every ATA must
+    // be empty. closing a config needs a live bucket, so the configs are closed here.
+    let collateral = bucket.collateral().to_vec();
+    invariant!(
+        ctx.remaining_accounts.len() == collateral.len() * ACCOUNTS_PER_CLOSE_ASSET,
+        ErrorCode::CollateralValuationAccountsMismatch
+    );
+    let authority_info = ctx.accounts.authority.to_account_info();
+    for (accounts, collateral) in ctx
+        .remaining_accounts
+        .chunks(ACCOUNTS_PER_CLOSE_ASSET)
+        .zip(collateral.iter())
+    {
+        let (crate_collateral_info, collateral_config_info) = (&accounts[0], &accounts[1]);
+        invariant!(
+            crate_collateral_info.key()
+                == get_associated_token_address(ctx.accounts.crate_token.key, &collateral.mint),
+            ErrorCode::CollateralValuationAccountsMismatch
+        );
+        // a crate ATA that was never created holds nothing
+        if !crate_collateral_info.data_is_empty() {
+            let crate_collateral: Account<TokenAccount> = Account::try_from(crate_collateral_info)?;
+            invariant!(crate_collateral.amount == 0, ErrorCode::BucketNotEmpty);
+        }
+
+        // authorized collateral does not need a config. if a config is left out, the config count
+        // below catches it.
+        if collateral_config_info.owner == &crate::ID && !collateral_config_info.data_is_empty() {
+            let collateral_config: Account<CollateralConfig> =
+                Account::try_from(collateral_config_info)?;
+            invariant!(
+                collateral_config.bucket == bucket_key && collateral_config.mint == collateral.mint,
+                ErrorCode::InvalidCollateralConfig
+            );
+            collateral_config.close(authority_info.clone())?;
+            bucket.record_child_account_closed(ChildAccount::CollateralConfig)?;
+        }
+    }
+
+    // configs of collateral that is no longer authorized can only be closed once the crate holds
+    // none of it, so no collateral is stranded once they are all closed. swap pairs and collateral
+    // wrappers need a live bucket to be removed as well.
+    invariant!(
+        !bucket.has_child_accounts(),
+        ErrorCode::BucketHasChildAccounts
+    );
+
+    // the NAV snapshot history and the allocation ramp only exist once they were first used
+    close_account_if_initialized(
+        &ctx.accounts.nav_snapshot.to_account_info(),
+        &authority_info,
+    )?;
+    close_account_if_initialized(
+        &ctx.accounts.allocation_ramp.to_account_info(),
+        &authority_info,
+    )?;
+
+    msg!("closing bucket {}", bucket_key);
+    emit!(BucketClosed {
+        version: EVENT_VERSION,
+        bucket: bucket_key,
+    });
+
+    Ok(())
+}
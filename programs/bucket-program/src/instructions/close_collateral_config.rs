@@ -0,0 +1,39 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::CloseCollateralConfig, error::ErrorCode,
+        event::CollateralConfigClosed, state::bucket::ChildAccount, util::is_collateral_authorized,
+    },
+    anchor_lang::prelude::*,
+    anchor_spl::token::TokenAccount,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<CloseCollateralConfig>) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    let mint = ctx.accounts.collateral_config.mint;
+    invariant!(
+        !is_collateral_authorized(bucket.collateral(), mint),
+        ErrorCode::CollateralStillAuthorized
+    );
+
+    // a crate ATA that was never created holds nothing
+    let crate_collateral_info = ctx.accounts.crate_collateral.to_account_info();
+    if !crate_collateral_info.data_is_empty() {
+        let crate_collateral: Account<TokenAccount> = Account::try_from(&crate_collateral_info)?;
+        invariant!(
+            crate_collateral.amount == 0,
+            ErrorCode::CollateralConfigHasBalance
+        );
+    }
+
+    bucket.record_child_account_closed(ChildAccount::CollateralConfig)?;
+
+    msg!("closing collateral config for mint {}", mint);
+    emit!(CollateralConfigClosed {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint,
+    });
+
+    Ok(())
+}
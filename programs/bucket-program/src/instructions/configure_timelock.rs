@@ -0,0 +1,20 @@
+use {
+    crate::{constant::EVENT_VERSION, context::AuthorizedUpdate, event::TimelockConfigured},
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>, delay_slots: u64, guardian: Pubkey) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    // once enabled, the timelock can only be changed or disabled through a proposal
+    bucket.require_no_timelock()?;
+    bucket.configure_timelock(delay_slots, guardian)?;
+
+    emit!(TimelockConfigured {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        delay_slots,
+        guardian,
+    });
+
+    Ok(())
+}
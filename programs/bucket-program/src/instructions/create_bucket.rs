@@ -1,7 +1,10 @@
 use {
+    crate::{
+        constant::EVENT_VERSION, context::CreateBucket, event::BucketCreated,
+        state::bucket::BUCKET_ACCOUNT_SPACE, util::resize_account,
+    },
     anchor_lang::prelude::*,
     crate_token::cpi::new_crate,
-    crate::context::CreateBucket,
 };
 
 pub fn handle(
@@ -10,22 +13,44 @@ pub fn handle(
     crate_bump: u8,
     issue_authority_bump: u8,
     withdraw_authority_bump: u8,
+    rebalance_state_bump: u8,
+    bucket_stats_bump: u8,
 ) -> ProgramResult {
-    new_crate(
-        ctx.accounts
-            .into_new_crate_context(),
-        crate_bump,
-    )?;
+    new_crate(ctx.accounts.into_new_crate_context(), crate_bump)?;
 
     ctx.accounts.issue_authority.init(issue_authority_bump);
-    ctx.accounts.withdraw_authority.init(withdraw_authority_bump);
+    ctx.accounts
+        .withdraw_authority
+        .init(withdraw_authority_bump);
+    ctx.accounts
+        .rebalance_state
+        .init(rebalance_state_bump, ctx.accounts.bucket.key());
+    ctx.accounts
+        .bucket_stats
+        .init(bucket_stats_bump, ctx.accounts.bucket.key());
 
-    ctx.accounts.bucket.init(
+    // resize_bucket creates the account with as much space as a single instruction can allocate.
+    // grow it the rest of the way before initializing it.
+    resize_account(
+        &ctx.accounts.bucket.to_account_info(),
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BUCKET_ACCOUNT_SPACE,
+    )?;
+    ctx.accounts.bucket.load_init()?.init(
         bucket_bump,
         ctx.accounts.crate_mint.key(),
         ctx.accounts.crate_token.key(),
-        ctx.accounts.payer.key()
+        ctx.accounts.payer.key(),
     );
 
+    emit!(BucketCreated {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        crate_token: ctx.accounts.crate_token.key(),
+        crate_mint: ctx.accounts.crate_mint.key(),
+        authority: ctx.accounts.payer.key(),
+    });
+
     Ok(())
 }
@@ -0,0 +1,15 @@
+use {
+    crate::{constant::EVENT_VERSION, context::AuthorizedUpdate, event::BucketDecommissioned},
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>) -> ProgramResult {
+    ctx.accounts.bucket.load_mut()?.decommission()?;
+
+    emit!(BucketDecommissioned {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+    });
+
+    Ok(())
+}
@@ -1,47 +1,63 @@
 use {
     crate::{
-        constant::{ISSUE_SEED, TARGET_ORACLE_PRECISION},
+        constant::{EVENT_VERSION, ISSUE_SEED, PAUSE_DEPOSITS, TARGET_ORACLE_PRECISION},
         context::Deposit,
         error::ErrorCode,
-        state::oracle::{get_oracle_price, OraclePriceData},
-        util::is_collateral_authorized,
+        event::Deposited,
+        math::{
+            casting::{cast, cast_to_u128},
+            decimal::{Decimal, Rounding},
+        },
+        math_error,
+        state::{
+            allocation_ramp::load_target_collateral,
+            oracle::{get_oracle_price, OraclePriceData},
+        },
+        util::{
+            collateral_values_from_account_infos, compute_band_values, compute_collateral_value,
+            get_collateral_idx, is_collateral_authorized, sum_collateral_values,
+        },
     },
     anchor_lang::prelude::*,
     anchor_spl::token::transfer,
     crate_token::cpi::issue,
-    std::{cmp::min, convert::TryInto},
+    std::cmp::min,
     vipers::invariant,
 };
 
-pub fn handle(ctx: Context<Deposit>, deposit_amount: u64) -> ProgramResult {
-    invariant!(
-        is_collateral_authorized(
-            &ctx.accounts.common.bucket.collateral,
-            ctx.accounts.depositor_collateral.mint.key()
-        ),
-        ErrorCode::WrongCollateralError
-    );
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+    deposit_amount: u64,
+) -> ProgramResult {
+    verify_deposit_collateral(ctx.accounts)?;
 
     let clock = Clock::get()?;
+    ctx.accounts.collateral_config.record_deposit(
+        deposit_amount,
+        ctx.accounts.crate_collateral.amount,
+        clock.slot,
+    )?;
+
+    if ctx
+        .accounts
+        .collateral_config
+        .band
+        .reject_deposits_above_band
+    {
+        verify_deposit_within_band(&ctx, deposit_amount, clock.slot)?;
+    }
+
     transfer(ctx.accounts.into_transfer_token_context(), deposit_amount)?;
 
-    // todo: verify oracle address. couple possible approaches. one could be a PDA-per-mint
-    // with the pyth and switchboard oracle price feed addresses. regardless of approach, this
-    // is a major attack vector we need to account for.
-    let oracle_price_data: OraclePriceData =
-        get_oracle_price(&ctx.accounts.oracle, clock.slot, TARGET_ORACLE_PRECISION)?;
-
-    let oracle_price = oracle_price_data.price;
-    let price_per_coin = min(oracle_price, 10_i128.pow(TARGET_ORACLE_PRECISION));
-    let price_per_bucket_usd = 1;
-    let deposit_sum = (deposit_amount as i128)
-        .checked_mul(price_per_coin)
-        .unwrap();
-    let issue_amount = deposit_sum
-        .checked_div(price_per_bucket_usd)
-        .unwrap()
-        .checked_div(10_i128.pow(TARGET_ORACLE_PRECISION))
-        .unwrap();
+    // the oracle is verified against the collateral config in the deposit context
+    let oracle_price_data: OraclePriceData = get_oracle_price(
+        &ctx.accounts.oracle,
+        ctx.accounts.collateral_config.oracle_source,
+        clock.slot,
+        TARGET_ORACLE_PRECISION,
+    )?;
+
+    let issue_amount = compute_issue_amount(deposit_amount, oracle_price_data.price)?;
 
     let bucket = ctx.accounts.common.bucket.key();
     let issue_authority_signer_seeds: &[&[&[u8]]] = &[&[
@@ -54,8 +70,108 @@ pub fn handle(ctx: Context<Deposit>, deposit_amount: u64) -> ProgramResult {
         ctx.accounts
             .into_issue_reserve_context()
             .with_signer(issue_authority_signer_seeds),
-        issue_amount.try_into().unwrap(),
+        issue_amount,
     )?;
+    ctx.accounts
+        .bucket_stats
+        .record_issue(issue_amount, clock.slot)?;
+
+    emit!(Deposited {
+        version: EVENT_VERSION,
+        bucket,
+        depositor: ctx.accounts.depositor.key(),
+        mint: ctx.accounts.collateral_mint.key(),
+        amount: deposit_amount,
+        price: oracle_price_data.price,
+        reserve_issued: issue_amount,
+    });
+
+    Ok(())
+}
+
+/// make sure the bucket accepts deposits of the depositor's collateral
+pub fn verify_deposit_collateral(accounts: &Deposit) -> ProgramResult {
+    let bucket = accounts.common.bucket.load()?;
+    bucket.require_not_decommissioned()?;
+    bucket.require_not_paused(PAUSE_DEPOSITS, Some(accounts.depositor_collateral.mint))?;
+
+    invariant!(
+        is_collateral_authorized(bucket.collateral(), accounts.depositor_collateral.mint),
+        ErrorCode::WrongCollateralError
+    );
+
+    Ok(())
+}
+
+/// reserve tokens issued for a deposit of deposit_amount collateral at the given oracle price
+pub fn compute_issue_amount(deposit_amount: u64, price: i128) -> Result<u64, ProgramError> {
+    // collateral is never valued above $1, and the depositor receives whole reserve tokens only,
+    // so the issue amount is rounded down
+    let price_per_coin = min(
+        Decimal::from_scaled(
+            cast_to_u128(price)?,
+            TARGET_ORACLE_PRECISION,
+            Rounding::Down,
+        )?,
+        Decimal::one(),
+    );
+    let issue_amount: u64 = cast(
+        Decimal::from_scaled(deposit_amount as u128, 0, Rounding::Down)?
+            .checked_mul(price_per_coin, Rounding::Down)?
+            .to_scaled(0, Rounding::Down)?,
+    )
+    .map_err(|_| ErrorCode::IssueAmountOverflow)?;
+
+    Ok(issue_amount)
+}
+
+/// value the bucket's collateral with the remaining accounts, i.e. a (mint, crate ATA, oracle,
+/// collateral config) group for each authorized collateral, and make sure the deposit does not push
+/// the deposited collateral above its allocation band. must run before the deposit is transferred.
+pub fn verify_deposit_within_band<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Deposit<'info>>,
+    deposit_amount: u64,
+    clock_slot: u64,
+) -> ProgramResult {
+    let mint = ctx.accounts.collateral_mint.key();
+    let bucket = ctx.accounts.common.bucket.load()?;
+    let collateral = load_target_collateral(&bucket, &ctx.accounts.allocation_ramp, clock_slot)?;
+    let collateral_values = collateral_values_from_account_infos(
+        ctx.remaining_accounts,
+        &collateral,
+        &ctx.accounts.common.bucket.key(),
+        ctx.accounts.common.crate_token.key,
+        clock_slot,
+    )?;
+
+    let idx = get_collateral_idx(&collateral, mint)?;
+    let collateral_value = &collateral_values[idx];
+    // round the deposit's value up, so that a deposit cannot sneak past the band by rounding
+    let deposit_value = compute_collateral_value(
+        deposit_amount,
+        collateral_value.decimals,
+        collateral_value.price,
+        Rounding::Up,
+    )?;
+    let total_value = sum_collateral_values(&collateral_values)?
+        .checked_add(deposit_value)
+        .ok_or_else(math_error!())?;
+    let value = collateral_value
+        .value
+        .checked_add(deposit_value)
+        .ok_or_else(math_error!())?;
+
+    let (_, max_value) = compute_band_values(
+        total_value,
+        collateral[idx].allocation,
+        &collateral_value.band,
+    )?;
+    msg!(
+        "collateral value after deposit = {}, max value in band = {}",
+        value,
+        max_value
+    );
+    invariant!(value <= max_value, ErrorCode::AllocationBandExceeded);
 
     Ok(())
 }
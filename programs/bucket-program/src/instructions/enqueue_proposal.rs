@@ -0,0 +1,51 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::EnqueueProposal, error::ErrorCode,
+        event::ProposalEnqueued, math_error, state::proposal::AdminAction,
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<EnqueueProposal>, bump: u8, action: AdminAction) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    invariant!(bucket.is_timelocked(), ErrorCode::TimelockDisabled);
+
+    let eta_slot = Clock::get()?
+        .slot
+        .checked_add(bucket.timelock.delay_slots)
+        .ok_or_else(math_error!())?;
+
+    msg!(
+        "enqueueing proposal {} executable at slot {}: {:?}",
+        bucket.timelock.proposal_count,
+        eta_slot,
+        action
+    );
+
+    emit!(ProposalEnqueued {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        proposal_id: bucket.timelock.proposal_count,
+        proposer: ctx.accounts.authority.key(),
+        eta_slot,
+        action: action.clone(),
+    });
+
+    ctx.accounts.proposal.init(
+        bump,
+        ctx.accounts.bucket.key(),
+        bucket.timelock.proposal_count,
+        ctx.accounts.authority.key(),
+        eta_slot,
+        action,
+    );
+
+    bucket.timelock.proposal_count = bucket
+        .timelock
+        .proposal_count
+        .checked_add(1)
+        .ok_or_else(math_error!())?;
+
+    Ok(())
+}
@@ -0,0 +1,205 @@
+use {
+    crate::{
+        constant::{EVENT_VERSION, SWAP_PAIR_SEED},
+        context::{
+            ExecuteProposal, ProposalAddSwapPair, ProposalCollateralConfig, ProposalRemoveSwapPair,
+        },
+        error::ErrorCode,
+        event::{
+            AllocationBandSet, CollateralConfigUpdated, CollateralSetUpdated, ProposalExecuted,
+            SwapPairAdded, SwapPairRemoved,
+        },
+        state::{
+            bucket::ChildAccount,
+            proposal::AdminAction,
+            swap_pair::{SwapPair, SWAP_PAIR_ACCOUNT_SPACE},
+        },
+        util::create_pda_account,
+    },
+    anchor_lang::{prelude::*, solana_program::account_info::next_account_infos, AccountsClose},
+    vipers::invariant,
+};
+
+pub fn handle<'info>(ctx: Context<'_, '_, '_, 'info, ExecuteProposal<'info>>) -> ProgramResult {
+    let proposal = &ctx.accounts.proposal;
+    invariant!(
+        Clock::get()?.slot >= proposal.eta_slot,
+        ErrorCode::ProposalNotReady
+    );
+
+    msg!("executing proposal {}: {:?}", proposal.id, proposal.action);
+
+    let bucket_key = ctx.accounts.bucket.key();
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    match &proposal.action {
+        // collateral set updates are applied here so that the diff can be emitted, same as when
+        // the update is applied directly
+        AdminAction::UpdateCollateralSet { collateral } => {
+            let diff = bucket.update_collateral_set(collateral)?;
+            emit!(CollateralSetUpdated {
+                version: EVENT_VERSION,
+                bucket: bucket_key,
+                added: diff.added,
+                removed: diff.removed,
+                reallocated: diff.reallocated,
+            });
+        }
+        // the remaining actions change accounts other than the bucket, which are passed as
+        // remaining accounts
+        AdminAction::UpdateCollateralConfig {
+            mint,
+            oracle_source,
+            oracle,
+            caps,
+            fee_overrides,
+        } => {
+            let mut accounts =
+                proposal_collateral_config(ctx.remaining_accounts, bucket_key, *mint)?;
+            accounts
+                .collateral_config
+                .update(*oracle_source, *oracle, *caps, *fee_overrides)?;
+            accounts.exit(&crate::ID)?;
+
+            emit!(CollateralConfigUpdated {
+                version: EVENT_VERSION,
+                bucket: bucket_key,
+                mint: *mint,
+                oracle_source: *oracle_source,
+                oracle: *oracle,
+            });
+        }
+        AdminAction::SetAllocationBand { mint, band } => {
+            let mut accounts =
+                proposal_collateral_config(ctx.remaining_accounts, bucket_key, *mint)?;
+            accounts.collateral_config.set_band(*band)?;
+            accounts.exit(&crate::ID)?;
+
+            emit!(AllocationBandSet {
+                version: EVENT_VERSION,
+                bucket: bucket_key,
+                mint: *mint,
+                band: *band,
+            });
+        }
+        AdminAction::AddSwapPair {
+            bump,
+            mint_a,
+            mint_b,
+            venue,
+            swap_program,
+            swap,
+        } => {
+            invariant!(mint_a < mint_b, ErrorCode::InvalidProposalAccounts);
+            SwapPair::verify_venue(*venue, *swap_program)?;
+
+            let accounts: ProposalAddSwapPair = Accounts::try_accounts(
+                &crate::ID,
+                &mut next_account_infos(&mut ctx.remaining_accounts.iter(), 4)?,
+                &[],
+            )?;
+            invariant!(
+                accounts.swap_program.key() == *swap_program && accounts.swap.key() == *swap,
+                ErrorCode::InvalidProposalAccounts
+            );
+
+            let seeds: &[&[u8]] = &[
+                SWAP_PAIR_SEED.as_bytes(),
+                bucket_key.as_ref(),
+                mint_a.as_ref(),
+                mint_b.as_ref(),
+                &[*bump],
+            ];
+            let swap_pair_address = Pubkey::create_program_address(seeds, &crate::ID)
+                .map_err(|_| ErrorCode::InvalidProposalAccounts)?;
+            invariant!(
+                accounts.swap_pair.key() == swap_pair_address,
+                ErrorCode::InvalidProposalAccounts
+            );
+
+            bucket.record_child_account_opened(ChildAccount::SwapPair)?;
+            let swap_pair_info = accounts.swap_pair.to_account_info();
+            create_pda_account(
+                &swap_pair_info,
+                &ctx.accounts.executor.to_account_info(),
+                &accounts.system_program.to_account_info(),
+                SWAP_PAIR_ACCOUNT_SPACE,
+                seeds,
+            )?;
+
+            let mut swap_pair = SwapPair::default();
+            swap_pair.init(
+                *bump,
+                bucket_key,
+                *mint_a,
+                *mint_b,
+                *venue,
+                *swap_program,
+                *swap,
+            );
+            let mut data = swap_pair_info.try_borrow_mut_data()?;
+            swap_pair.try_serialize(&mut &mut data[..])?;
+
+            emit!(SwapPairAdded {
+                version: EVENT_VERSION,
+                bucket: bucket_key,
+                mint_a: *mint_a,
+                mint_b: *mint_b,
+                venue: *venue,
+                pool: *swap,
+            });
+        }
+        AdminAction::RemoveSwapPair { mint_a, mint_b } => {
+            let accounts: ProposalRemoveSwapPair = Accounts::try_accounts(
+                &crate::ID,
+                &mut next_account_infos(&mut ctx.remaining_accounts.iter(), 1)?,
+                &[],
+            )?;
+            let swap_pair = &accounts.swap_pair;
+            invariant!(
+                swap_pair.bucket == bucket_key
+                    && swap_pair.mint_a == *mint_a
+                    && swap_pair.mint_b == *mint_b,
+                ErrorCode::InvalidProposalAccounts
+            );
+            swap_pair.close(ctx.accounts.proposer.to_account_info())?;
+            bucket.record_child_account_closed(ChildAccount::SwapPair)?;
+
+            emit!(SwapPairRemoved {
+                version: EVENT_VERSION,
+                bucket: bucket_key,
+                mint_a: *mint_a,
+                mint_b: *mint_b,
+            });
+        }
+        action => bucket.apply_admin_action(action)?,
+    }
+
+    emit!(ProposalExecuted {
+        version: EVENT_VERSION,
+        bucket: bucket_key,
+        proposal_id: proposal.id,
+        executor: ctx.accounts.executor.key(),
+        action: proposal.action.clone(),
+    });
+
+    Ok(())
+}
+
+/// load the collateral config an UpdateCollateralConfig or SetAllocationBand proposal applies to
+fn proposal_collateral_config<'info>(
+    remaining_accounts: &[AccountInfo<'info>],
+    bucket: Pubkey,
+    mint: Pubkey,
+) -> std::result::Result<ProposalCollateralConfig<'info>, ProgramError> {
+    let accounts: ProposalCollateralConfig = Accounts::try_accounts(
+        &crate::ID,
+        &mut next_account_infos(&mut remaining_accounts.iter(), 1)?,
+        &[],
+    )?;
+    invariant!(
+        accounts.collateral_config.bucket == bucket && accounts.collateral_config.mint == mint,
+        ErrorCode::InvalidProposalAccounts
+    );
+
+    Ok(accounts)
+}
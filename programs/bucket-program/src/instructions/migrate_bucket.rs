@@ -0,0 +1,91 @@
+use {
+    crate::{
+        constant::{BUCKET_VERSION, EVENT_VERSION},
+        context::MigrateBucket,
+        error::ErrorCode,
+        event::BucketMigrated,
+        state::bucket::{Bucket, BucketV0, BUCKET_ACCOUNT_SPACE},
+        util::resize_account,
+    },
+    anchor_lang::{prelude::*, Discriminator},
+    std::mem::size_of,
+    vipers::invariant,
+};
+
+pub fn handle(
+    ctx: Context<MigrateBucket>,
+    rebalance_state_bump: u8,
+    bucket_stats_bump: u8,
+) -> ProgramResult {
+    let bucket_info = ctx.accounts.bucket.to_account_info();
+
+    let bucket: BucketV0 = {
+        let data = bucket_info.try_borrow_data()?;
+        invariant!(
+            data.len() > 8 && data[..8] == Bucket::discriminator(),
+            ErrorCode::InvalidBucketAccount
+        );
+
+        // resize_bucket may already have grown a v0 bucket to the current size, so the size alone
+        // does not tell the layouts apart. a current bucket starts with its version byte and stores
+        // its crate token at a fixed offset.
+        if data.len() == BUCKET_ACCOUNT_SPACE {
+            let current: &Bucket = bytemuck::from_bytes(&data[8..]);
+            invariant!(
+                current.version != BUCKET_VERSION
+                    || current.crate_token != ctx.accounts.crate_token.key(),
+                ErrorCode::BucketAlreadyMigrated
+            );
+        }
+
+        let bucket =
+            BucketV0::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::InvalidBucketAccount)?;
+        invariant!(
+            bucket.authority == ctx.accounts.authority.key(),
+            ErrorCode::InvalidBucketAccount
+        );
+
+        bucket
+    };
+
+    msg!(
+        "migrating bucket {} with {} collateral to version {}",
+        bucket_info.key,
+        bucket.collateral.len(),
+        BUCKET_VERSION
+    );
+
+    // buckets with little collateral need more than 10KB, in which case resize_bucket grows them
+    // part of the way first.
+    resize_account(
+        &bucket_info,
+        &ctx.accounts.authority.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        BUCKET_ACCOUNT_SPACE,
+    )?;
+
+    // the zero-copy bucket is too large for the stack, so it is written in place. realloc does not
+    // zero the existing bytes, so the old layout is cleared first.
+    {
+        let mut data = bucket_info.try_borrow_mut_data()?;
+        data[8..].fill(0);
+        let zero_copy_bucket: &mut Bucket =
+            bytemuck::from_bytes_mut(&mut data[8..8 + size_of::<Bucket>()]);
+        zero_copy_bucket.migrate_from(&bucket)?;
+    }
+
+    ctx.accounts
+        .rebalance_state
+        .init(rebalance_state_bump, bucket_info.key());
+    ctx.accounts
+        .bucket_stats
+        .init(bucket_stats_bump, bucket_info.key());
+
+    emit!(BucketMigrated {
+        version: EVENT_VERSION,
+        bucket: bucket_info.key(),
+        bucket_version: BUCKET_VERSION,
+    });
+
+    Ok(())
+}
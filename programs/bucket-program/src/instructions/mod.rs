@@ -1,10 +1,48 @@
+// program paths must return an error instead of panicking
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
 // bucket ops instructions
 pub mod authorize_collateral;
 pub mod create_bucket;
 pub mod deposit;
+pub mod quote_deposit;
+pub mod quote_rebalance;
+pub mod quote_redeem;
 pub mod redeem;
 pub mod remove_collateral;
 // admin instructions
+pub mod accept_authority;
+pub mod add_collateral_config;
+pub mod add_collateral_wrapper;
+pub mod add_swap_pair;
+pub mod cancel_authority_transfer;
+pub mod cancel_proposal;
+pub mod close_bucket;
+pub mod close_collateral_config;
+pub mod configure_timelock;
+pub mod decommission_bucket;
+pub mod enqueue_proposal;
+pub mod execute_proposal;
+pub mod migrate_bucket;
+pub mod pause;
+pub mod propose_authority;
+pub mod ramp_allocations;
 pub mod rebalance;
+pub mod rebalance_to_target;
+pub mod rebalance_two_hop;
+pub mod remove_collateral_wrapper;
+pub mod remove_swap_pair;
+pub mod report_allocation_bands;
+pub mod resize_bucket;
+pub mod set_allocation_band;
 pub mod set_collateral_allocations;
+pub mod set_collateral_status;
+pub mod set_guardian;
+pub mod set_keeper_bounty;
+pub mod set_rebalance_limits;
+pub mod snapshot_nav;
+pub mod stop_allocation_ramp;
+pub mod unpause;
+pub mod update_collateral_config;
+pub mod update_collateral_set;
 pub mod update_rebalance_authority;
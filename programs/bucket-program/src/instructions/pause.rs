@@ -0,0 +1,27 @@
+use {
+    crate::{constant::EVENT_VERSION, context::Pause, error::ErrorCode, event::PauseFlagsSet},
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<Pause>, flags: u8, mint: Option<Pubkey>) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    let pauser = ctx.accounts.pauser.key();
+    invariant!(
+        pauser == bucket.authority
+            || (bucket.guardian != Pubkey::default() && pauser == bucket.guardian),
+        ErrorCode::UnauthorizedPauser
+    );
+
+    bucket.set_pause_flags(flags, mint, true)?;
+
+    emit!(PauseFlagsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint,
+        flags,
+        paused: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,18 @@
+use {
+    crate::{constant::EVENT_VERSION, context::AuthorizedUpdate, event::AuthorityTransferProposed},
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>, pending_authority: Pubkey) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.propose_authority(pending_authority)?;
+
+    emit!(AuthorityTransferProposed {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        authority: bucket.authority,
+        pending_authority,
+    });
+
+    Ok(())
+}
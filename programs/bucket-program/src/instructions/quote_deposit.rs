@@ -0,0 +1,110 @@
+use {
+    crate::{
+        constant::TARGET_ORACLE_PRECISION,
+        context::Deposit,
+        instructions::deposit::{
+            compute_issue_amount, verify_deposit_collateral, verify_deposit_within_band,
+        },
+        math::decimal::Rounding,
+        math_error,
+        state::{allocation_ramp::load_target_collateral, oracle::get_oracle_price},
+        util::{
+            collateral_values_from_account_infos, compute_collateral_value, compute_weights_bps,
+        },
+    },
+    anchor_lang::{prelude::*, solana_program::program::set_return_data},
+};
+
+/// result of a deposit, as computed by quote_deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct DepositQuote {
+    /// collateral deposited
+    pub amount: u64,
+    /// oracle price of the collateral, in TARGET_ORACLE_PRECISION units
+    pub price: i128,
+    /// fee charged on the deposit, in reserve tokens. the bucket charges no deposit fee yet.
+    pub fee: u64,
+    /// reserve tokens issued to the depositor
+    pub reserve_issued: u64,
+    /// collateral weights after the deposit in bps, in the bucket's collateral order. empty if the
+    /// collateral valuation accounts are not supplied.
+    pub weights_bps: Vec<u16>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+    deposit_amount: u64,
+) -> ProgramResult {
+    // run the same checks as deposit, without transferring or issuing any tokens
+    verify_deposit_collateral(ctx.accounts)?;
+    ctx.accounts
+        .collateral_config
+        .verify_deposit(deposit_amount, ctx.accounts.crate_collateral.amount)?;
+
+    let clock = Clock::get()?;
+    if ctx
+        .accounts
+        .collateral_config
+        .band
+        .reject_deposits_above_band
+    {
+        verify_deposit_within_band(&ctx, deposit_amount, clock.slot)?;
+    }
+
+    let oracle_price_data = get_oracle_price(
+        &ctx.accounts.oracle,
+        ctx.accounts.collateral_config.oracle_source,
+        clock.slot,
+        TARGET_ORACLE_PRECISION,
+    )?;
+    let reserve_issued = compute_issue_amount(deposit_amount, oracle_price_data.price)?;
+
+    let weights_bps = if ctx.remaining_accounts.is_empty() {
+        Vec::new()
+    } else {
+        let collateral_values = collateral_values_from_account_infos(
+            ctx.remaining_accounts,
+            &load_target_collateral(
+                &ctx.accounts.common.bucket.load()?,
+                &ctx.accounts.allocation_ramp,
+                clock.slot,
+            )?,
+            &ctx.accounts.common.bucket.key(),
+            ctx.accounts.common.crate_token.key,
+            clock.slot,
+        )?;
+
+        let mint = ctx.accounts.collateral_mint.key();
+        let mut values: Vec<u128> = Vec::with_capacity(collateral_values.len());
+        for el in collateral_values.iter() {
+            if el.mint == mint {
+                let deposit_value = compute_collateral_value(
+                    deposit_amount,
+                    el.decimals,
+                    el.price,
+                    Rounding::Down,
+                )?;
+                values.push(
+                    el.value
+                        .checked_add(deposit_value)
+                        .ok_or_else(math_error!())?,
+                );
+            } else {
+                values.push(el.value);
+            }
+        }
+
+        compute_weights_bps(&values)?
+    };
+
+    let quote = DepositQuote {
+        amount: deposit_amount,
+        price: oracle_price_data.price,
+        fee: 0,
+        reserve_issued,
+        weights_bps,
+    };
+    set_return_data(&quote.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+use {
+    crate::{
+        context::{KeeperBountyAsset, Rebalance},
+        error::ErrorCode,
+        instructions::{
+            rebalance::{
+                compute_keeper_bounty, split_collateral_valuation_accounts,
+                swap_route_from_account_infos, verify_rebalance_not_paused,
+                verify_rebalance_out_of_band, ACCOUNTS_PER_KEEPER_BOUNTY,
+            },
+            rebalance_to_target::{compute_rebalance_swap, verify_rebalance_to_target_route},
+        },
+        math::decimal::Rounding,
+        state::{allocation_ramp::load_target_collateral, bucket::BountySource},
+        util::{
+            collateral_values_from_account_infos, compute_collateral_value, compute_weights_bps,
+            CollateralValue,
+        },
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{account_info::next_account_infos, program::set_return_data},
+    },
+    vipers::invariant,
+};
+
+/// result of a rebalance_to_target, as computed by quote_rebalance
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct RebalanceQuote {
+    pub mint_in: Pubkey,
+    pub mint_out: Pubkey,
+    /// oracle prices of the source and destination collateral, in TARGET_ORACLE_PRECISION units
+    pub price_in: i128,
+    pub price_out: i128,
+    /// collateral swapped out of the bucket
+    pub amount_in: u64,
+    /// collateral the swap is expected to return at oracle prices
+    pub expected_amount_out: u64,
+    /// smallest swap output the rebalance accepts
+    pub minimum_amount_out: u64,
+    /// keeper bounty paid on the expected amount out, in the bounty's token. zero if the keeper
+    /// bounty accounts are not supplied.
+    pub keeper_bounty: u64,
+    /// collateral weights after the swap in bps, in the bucket's collateral order
+    pub weights_bps: Vec<u16>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
+    max_amount_in: u64,
+) -> ProgramResult {
+    // run the same checks as rebalance_to_target, without swapping or paying a bounty
+    let (route, remaining_accounts) =
+        swap_route_from_account_infos(ctx.accounts, ctx.remaining_accounts)?;
+    verify_rebalance_not_paused(
+        ctx.accounts,
+        route.asset.token_a.key(),
+        route.asset.token_b.key(),
+    )?;
+    let (valuation_accounts, bounty_accounts) =
+        split_collateral_valuation_accounts(ctx.accounts, remaining_accounts)?;
+
+    let slot = Clock::get()?.slot;
+    let collateral_values: Vec<CollateralValue> = collateral_values_from_account_infos(
+        valuation_accounts,
+        &load_target_collateral(
+            &ctx.accounts.bucket.load()?,
+            &ctx.accounts.allocation_ramp,
+            slot,
+        )?,
+        &ctx.accounts.bucket.key(),
+        ctx.accounts.crate_token.key,
+        slot,
+    )?;
+
+    let swap = compute_rebalance_swap(&collateral_values, max_amount_in)?;
+    let source = &collateral_values[swap.source_idx];
+    let dest = &collateral_values[swap.dest_idx];
+    verify_rebalance_to_target_route(&route, source, dest)?;
+    verify_rebalance_out_of_band(&collateral_values, source.mint, dest.mint)?;
+
+    let keeper_bounty_config = ctx.accounts.bucket.load()?.keeper_bounty();
+    let keeper_bounty = if bounty_accounts.is_empty() || keeper_bounty_config.bps == 0 {
+        0
+    } else {
+        invariant!(
+            bounty_accounts.len() == ACCOUNTS_PER_KEEPER_BOUNTY,
+            ErrorCode::InvalidKeeperBountyAccounts
+        );
+        let bounty: KeeperBountyAsset = Accounts::try_accounts(
+            &crate::ID,
+            &mut next_account_infos(&mut bounty_accounts.iter(), ACCOUNTS_PER_KEEPER_BOUNTY)?,
+            &[],
+        )?;
+
+        compute_keeper_bounty(
+            &keeper_bounty_config,
+            swap.expected_amount_out,
+            dest.decimals,
+            bounty.crate_mint.decimals,
+        )?
+    };
+
+    // a bounty paid from the swap output leaves the bucket with the destination collateral
+    let dest_amount_retained = match keeper_bounty_config.source {
+        BountySource::SwapOutput => swap.expected_amount_out.saturating_sub(keeper_bounty),
+        BountySource::Reserve => swap.expected_amount_out,
+    };
+    let mut values: Vec<u128> = Vec::with_capacity(collateral_values.len());
+    for (idx, el) in collateral_values.iter().enumerate() {
+        let amount = if idx == swap.source_idx {
+            el.amount.saturating_sub(swap.amount_in)
+        } else if idx == swap.dest_idx {
+            el.amount.saturating_add(dest_amount_retained)
+        } else {
+            el.amount
+        };
+        values.push(compute_collateral_value(
+            amount,
+            el.decimals,
+            el.price,
+            Rounding::Down,
+        )?);
+    }
+
+    let quote = RebalanceQuote {
+        mint_in: source.mint,
+        mint_out: dest.mint,
+        price_in: source.price,
+        price_out: dest.price,
+        amount_in: swap.amount_in,
+        expected_amount_out: swap.expected_amount_out,
+        minimum_amount_out: swap.minimum_amount_out,
+        keeper_bounty,
+        weights_bps: compute_weights_bps(&values)?,
+    };
+    set_return_data(&quote.try_to_vec()?);
+
+    Ok(())
+}
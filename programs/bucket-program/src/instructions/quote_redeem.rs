@@ -0,0 +1,71 @@
+use {
+    crate::{
+        context::{Redeem, RedeemAsset},
+        error::ErrorCode,
+        event::RedeemedShare,
+        instructions::redeem::{
+            compute_redeem_share, verify_redeem, verify_redeem_asset, ACCOUNTS_PER_REDEEM_ASSET,
+        },
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{account_info::next_account_infos, program::set_return_data},
+    },
+    vipers::{invariant, unwrap_int},
+};
+
+/// result of a redeem, as computed by quote_redeem. redeems pay out every collateral pro-rata, so
+/// they leave the bucket's collateral weights unchanged and no weights are returned.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct RedeemQuote {
+    /// reserve tokens burned
+    pub reserve_burned: u64,
+    /// fee charged on the redeem, in reserve tokens. the bucket charges no redeem fee yet.
+    pub fee: u64,
+    /// collateral paid out, in the order of the remaining accounts
+    pub shares: Vec<RedeemedShare>,
+}
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+    redeem_amount: u64,
+) -> ProgramResult {
+    // run the same checks as redeem, without burning or withdrawing any tokens
+    verify_redeem(ctx.accounts)?;
+
+    let num_remaining_accounts = ctx.remaining_accounts.len();
+    invariant!(
+        num_remaining_accounts % ACCOUNTS_PER_REDEEM_ASSET == 0,
+        ErrorCode::InvalidRedeemAccountCount
+    );
+
+    let remaining_accounts_iter = &mut ctx.remaining_accounts.iter();
+    let num_tokens = unwrap_int!(num_remaining_accounts.checked_div(ACCOUNTS_PER_REDEEM_ASSET));
+    let mut shares: Vec<RedeemedShare> = Vec::with_capacity(num_tokens);
+    for _i in 0..num_tokens {
+        let asset: RedeemAsset = Accounts::try_accounts(
+            &crate::ID,
+            &mut next_account_infos(remaining_accounts_iter, ACCOUNTS_PER_REDEEM_ASSET)?,
+            &[],
+        )?;
+        verify_redeem_asset(ctx.accounts, &asset)?;
+
+        shares.push(RedeemedShare {
+            mint: asset.collateral_mint.key(),
+            amount: compute_redeem_share(
+                asset.crate_collateral.amount,
+                redeem_amount,
+                ctx.accounts.common.crate_mint.supply,
+            )?,
+        });
+    }
+
+    let quote = RedeemQuote {
+        reserve_burned: redeem_amount,
+        fee: 0,
+        shares,
+    };
+    set_return_data(&quote.try_to_vec()?);
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::RampAllocations,
+        event::{AllocationRampStarted, AllocationsSet},
+        state::bucket::Collateral,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(
+    ctx: Context<RampAllocations>,
+    bump: u8,
+    allocations: Vec<Collateral>,
+    end_slot: u64,
+) -> ProgramResult {
+    let slot = Clock::get()?.slot;
+    let bucket_key = ctx.accounts.bucket.key();
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+
+    let ramp = &mut ctx.accounts.allocation_ramp;
+    if ramp.bucket == Pubkey::default() {
+        ramp.init(bump, bucket_key);
+    }
+
+    // ramping while a previous ramp is in progress starts from the allocations currently in
+    // effect, so that targets never jump
+    let start_allocations: Vec<u32> = ramp
+        .effective_collateral(bucket.collateral(), slot)?
+        .iter()
+        .map(|el| el.allocation)
+        .collect();
+
+    bucket.set_collateral_allocations(&allocations)?;
+    let end_allocations: Vec<u32> = bucket.collateral().iter().map(|el| el.allocation).collect();
+
+    msg!(
+        "ramping allocations from slot {} to slot {}",
+        slot,
+        end_slot
+    );
+    ramp.start(start_allocations, end_allocations, slot, end_slot)?;
+
+    emit!(AllocationRampStarted {
+        version: EVENT_VERSION,
+        bucket: bucket_key,
+        start_slot: ramp.start_slot,
+        end_slot: ramp.end_slot,
+        start_allocations: ramp.start_allocations.clone(),
+        end_allocations: ramp.end_allocations.clone(),
+    });
+    emit!(AllocationsSet {
+        version: EVENT_VERSION,
+        bucket: bucket_key,
+        allocations: bucket.collateral().to_vec(),
+    });
+
+    Ok(())
+}
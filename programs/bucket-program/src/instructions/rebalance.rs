@@ -1,15 +1,39 @@
 use {
     crate::{
-        constant::{BUCKET_SEED, MAX_BASIS_POINTS, WITHDRAW_SEED, MAX_SLIPPAGE_BPS},
-        context::{Rebalance, RebalanceAsset},
+        constant::{
+            BUCKET_SEED, EVENT_VERSION, ISSUE_SEED, MAX_AUTHORITY_SLIPPAGE_BPS, MAX_BASIS_POINTS,
+            MAX_PERMISSIONLESS_SLIPPAGE_BPS, PAUSE_REBALANCES, TARGET_ORACLE_PRECISION,
+            WITHDRAW_SEED,
+        },
+        context::{
+            CollateralOracleAsset, DecimalWrapperAsset, KeeperBountyAsset, Rebalance,
+            RebalanceAsset,
+        },
         error::ErrorCode,
+        event::Rebalanced,
+        math::{
+            casting::cast,
+            decimal::{mul_div, Rounding},
+        },
         math_error,
-        state::bucket::Collateral,
-        util::{is_collateral_authorized, scale_amount_for_decimals},
+        state::{
+            allocation_ramp::load_target_collateral,
+            bucket::{BountySource, Collateral, KeeperBounty},
+            collateral_config::CollateralConfig,
+            oracle::get_oracle_price,
+        },
+        util::{
+            apply_bps, collateral_values_from_account_infos, compute_collateral_amount,
+            compute_collateral_value, is_collateral_authorized, scale_amount_for_decimals,
+            sum_collateral_deviations, sum_collateral_values, update_target_values,
+            CollateralValue, ACCOUNTS_PER_COLLATERAL_VALUATION,
+        },
+        venue::{self, decimal_wrapper},
     },
     anchor_lang::{prelude::*, solana_program::account_info::next_account_infos},
     anchor_spl::token::transfer,
-    crate_token::cpi::withdraw,
+    crate_token::cpi::{issue, withdraw},
+    std::cmp::{max, min},
     vipers::{invariant, unwrap_int},
 };
 
@@ -18,36 +42,95 @@ pub fn handle<'info>(
     amount_in: u64,
     minimum_amount_out: u64,
 ) -> ProgramResult {
-    let rebalance_assets: Vec<RebalanceAsset> =
-        rebalance_asset_from_account_infos(ctx.remaining_accounts)?;
-
     // prematurely return if there are no rebalance operations to perform
-    if rebalance_assets.len() == 0 {
+    if ctx.remaining_accounts.len() == 0 {
         return Ok(());
     }
 
-    // for now due to compute units, verify that we are only attempting 1 rebalance operation.
-    invariant!(
-        rebalance_assets.len() == 1,
-        "too many token accounts, expected accounts for 1 rebalance operation"
-    );
-
-    let rebalance_asset = &rebalance_assets[0];
+    // remaining accounts describe the swap route, followed by the accounts used to value the
+    // bucket's authorized collateral, the source collateral's oracle if it is no longer authorized
+    // and optionally the keeper bounty accounts. for now due to compute units, we only parse
+    // accounts for 1 rebalance operation.
+    let (mut route, remaining_accounts) =
+        swap_route_from_account_infos(ctx.accounts, ctx.remaining_accounts)?;
+    verify_rebalance_not_paused(
+        ctx.accounts,
+        route.asset.token_a.key(),
+        route.asset.token_b.key(),
+    )?;
+    let (valuation_accounts, oracle_accounts) =
+        split_collateral_valuation_accounts(ctx.accounts, remaining_accounts)?;
+    let clock = Clock::get()?;
+    let collateral_values: Vec<CollateralValue> = collateral_values_from_account_infos(
+        valuation_accounts,
+        &load_target_collateral(
+            &ctx.accounts.bucket.load()?,
+            &ctx.accounts.allocation_ramp,
+            clock.slot,
+        )?,
+        &ctx.accounts.bucket.key(),
+        ctx.accounts.crate_token.key,
+        clock.slot,
+    )?;
+    let rebalance_asset = &route.asset;
+    let source_mint = rebalance_asset.token_a.key();
+    let dest_mint = rebalance_asset.token_b.key();
 
     let caller_is_rebalance_authority =
-        ctx.accounts.payer.key() == ctx.accounts.bucket.rebalance_authority.key();
+        ctx.accounts.payer.key() == ctx.accounts.bucket.load()?.rebalance_authority;
     verify_collateral_for_caller(
         caller_is_rebalance_authority,
-        &rebalance_asset,
-        &ctx.accounts.bucket.collateral,
+        rebalance_asset.crate_source_ata.mint,
+        rebalance_asset.crate_dest_ata.mint,
+        ctx.accounts.bucket.load()?.collateral(),
     )?;
+    verify_rebalance_out_of_band(&collateral_values, source_mint, dest_mint)?;
 
-    let swap_amounts = get_swap_amounts_for_caller(
-        caller_is_rebalance_authority,
-        &rebalance_asset,
-        amount_in,
-        minimum_amount_out,
-    )?;
+    // collateral that is no longer authorized is not part of the valuation accounts, so its oracle
+    // and collateral config are supplied right after them.
+    let source_is_authorized =
+        is_collateral_authorized(ctx.accounts.bucket.load()?.collateral(), source_mint);
+    let (source, bounty_accounts) = if source_is_authorized {
+        let source = collateral_values
+            .iter()
+            .find(|el| el.mint == source_mint)
+            .ok_or(ErrorCode::CollateralValuationAccountsMismatch)?;
+
+        (source.into(), oracle_accounts)
+    } else {
+        collateral_leg_from_account_infos(
+            ctx.accounts,
+            source_mint,
+            rebalance_asset.token_a.decimals,
+            oracle_accounts,
+            clock.slot,
+        )?
+    };
+    let dest: SwapLeg = collateral_values
+        .iter()
+        .find(|el| el.mint == dest_mint)
+        .ok_or(ErrorCode::CollateralValuationAccountsMismatch)?
+        .into();
+
+    let (swap_amounts, max_slippage_bps) = if caller_is_rebalance_authority {
+        // the authority sizes the swap, but the swap must still return the oracle value of
+        // amount_in, less MAX_AUTHORITY_SLIPPAGE_BPS.
+        let OracleAmountsOut {
+            minimum_amount_out: oracle_minimum_amount_out,
+            ..
+        } = compute_oracle_amounts_out(amount_in, source, dest, MAX_AUTHORITY_SLIPPAGE_BPS)?;
+        let swap_amounts = ExchangeAmount {
+            amount_in,
+            amount_out: max(minimum_amount_out, oracle_minimum_amount_out),
+        };
+
+        (swap_amounts, MAX_AUTHORITY_SLIPPAGE_BPS)
+    } else {
+        let swap_amounts =
+            compute_stray_collateral_swap(rebalance_asset.crate_source_ata.amount, (source, dest))?;
+
+        (swap_amounts, MAX_PERMISSIONLESS_SLIPPAGE_BPS)
+    };
 
     msg!(
         "computed swap values: in = {}, out = {}",
@@ -55,62 +138,684 @@ pub fn handle<'info>(
         swap_amounts.amount_out
     );
 
-    let bucket = ctx.accounts.bucket.key();
+    let amount_out = swap_through_bucket(
+        ctx.accounts,
+        &mut route,
+        swap_amounts.amount_in,
+        swap_amounts.amount_out,
+    )?;
+    verify_swap_value_kept(
+        swap_amounts.amount_in,
+        source,
+        amount_out,
+        dest,
+        max_slippage_bps,
+    )?;
+
+    // only swaps that clear out collateral that is no longer authorized are eligible for a bounty.
+    // the program cannot tell whether an authority swap between authorized collateral is useful.
+    let bounty_from_swap_output = if source_is_authorized {
+        0
+    } else {
+        compute_bounty_from_swap_output(
+            ctx.accounts,
+            bounty_accounts,
+            amount_out,
+            route.asset.token_b.decimals,
+        )?
+    };
+    verify_rebalance_reduced_deviation(
+        &collateral_values,
+        source_mint,
+        source,
+        swap_amounts.amount_in,
+        dest_mint,
+        unwrap_int!(amount_out.checked_sub(bounty_from_swap_output)),
+    )?;
+
+    record_rebalance_turnover(
+        ctx.accounts,
+        &collateral_values,
+        source,
+        swap_amounts.amount_in,
+        clock.slot,
+    )?;
+    record_rebalance_stats(
+        valuation_accounts,
+        &collateral_values,
+        source_mint,
+        swap_amounts.amount_in,
+        dest_mint,
+        amount_out,
+        clock.slot,
+    )?;
+
+    let keeper_bounty = if source_is_authorized {
+        0
+    } else {
+        pay_keeper_bounty(
+            ctx.accounts,
+            &route.asset,
+            bounty_accounts,
+            amount_out,
+            clock.slot,
+        )?
+    };
+    ctx.accounts.bucket_stats.record_activity(clock.slot);
+
+    emit!(Rebalanced {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        caller: ctx.accounts.payer.key(),
+        mint_in: source_mint,
+        mint_out: dest_mint,
+        amount_in: swap_amounts.amount_in,
+        amount_out,
+        pools: vec![ctx.accounts.swap_pair.swap],
+        keeper_bounty,
+    });
+
+    Ok(())
+}
+
+/// accounts needed to route a single swap: the rebalance asset, the decimal wrapper for the
+/// collateral with fewer decimals if the collateral decimals differ, and the venue's accounts.
+pub struct SwapRoute<'a, 'info> {
+    pub asset: RebalanceAsset<'info>,
+    pub wrapper: Option<DecimalWrapperAsset<'info>>,
+    pub venue_accounts: &'a [AccountInfo<'info>],
+}
+
+impl<'a, 'info> SwapRoute<'a, 'info> {
+    /// true if the source collateral is wrapped before the swap. otherwise, the destination
+    /// collateral is unwrapped after the swap.
+    pub fn wraps_source(&self) -> bool {
+        self.asset.token_a.decimals < self.asset.token_b.decimals
+    }
+}
+
+/// parse a swap route from the front of the remaining accounts, i.e. the 6 rebalance asset
+/// accounts, the 6 decimal wrapper accounts if the collateral decimals differ, and the swap
+/// venue's accounts. returns the route and any accounts after it.
+pub fn swap_route_from_account_infos<'a, 'info>(
+    accounts: &Rebalance<'info>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(SwapRoute<'a, 'info>, &'a [AccountInfo<'info>]), ProgramError> {
+    invariant!(
+        remaining_accounts.len() >= ACCOUNTS_PER_REBALANCE_ASSET,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+    let (rebalance_accounts, remaining_accounts) =
+        remaining_accounts.split_at(ACCOUNTS_PER_REBALANCE_ASSET);
+    let asset = rebalance_asset_from_account_infos(rebalance_accounts)?.remove(0);
+    // the rebalance asset is parsed from the remaining accounts, so its token accounts cannot be
+    // pinned to the crate and bucket by anchor constraints. without this, a caller could pass their
+    // own token account as the crate's destination and receive the swap output.
+    invariant!(
+        asset.crate_source_ata.owner == accounts.crate_token.key()
+            && asset.crate_dest_ata.owner == accounts.crate_token.key()
+            && asset.bucket_source_ata.owner == accounts.bucket.key()
+            && asset.bucket_dest_ata.owner == accounts.bucket.key(),
+        ErrorCode::InvalidRebalanceTokenAccounts
+    );
+
+    let (wrapper, remaining_accounts) = if asset.token_a.decimals != asset.token_b.decimals {
+        invariant!(
+            remaining_accounts.len() >= ACCOUNTS_PER_DECIMAL_WRAPPER,
+            ErrorCode::DecimalWrapperRequired
+        );
+        let (wrapper_accounts, remaining_accounts) =
+            remaining_accounts.split_at(ACCOUNTS_PER_DECIMAL_WRAPPER);
+        let wrapper: DecimalWrapperAsset = Accounts::try_accounts(
+            &crate::ID,
+            &mut next_account_infos(&mut wrapper_accounts.iter(), ACCOUNTS_PER_DECIMAL_WRAPPER)?,
+            &[],
+        )?;
+        verify_decimal_wrapper(accounts, &asset, &wrapper)?;
+
+        (Some(wrapper), remaining_accounts)
+    } else {
+        (None, remaining_accounts)
+    };
+
+    let num_venue_accounts = accounts.swap_pair.venue.num_accounts();
+    invariant!(
+        remaining_accounts.len() >= num_venue_accounts,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+    let (venue_accounts, remaining_accounts) = remaining_accounts.split_at(num_venue_accounts);
+
+    Ok((
+        SwapRoute {
+            asset,
+            wrapper,
+            venue_accounts,
+        },
+        remaining_accounts,
+    ))
+}
+
+/// a rebalance must leave the bucket closer to its targets than it found it. the source and
+/// destination balances change by the amounts swapped, every other balance is unchanged, and the
+/// targets are recomputed from the new total value at the same oracle prices. collateral that is
+/// no longer authorized has a target of zero, so spending it reduces deviation by its value.
+pub fn verify_rebalance_reduced_deviation(
+    collateral_values: &Vec<CollateralValue>,
+    source_mint: Pubkey,
+    source: SwapLeg,
+    amount_in: u64,
+    dest_mint: Pubkey,
+    amount_out: u64,
+) -> ProgramResult {
+    let mut deviation_before = sum_collateral_deviations(collateral_values)?;
+    let mut collateral_values_after = collateral_values.clone();
+
+    match collateral_values_after
+        .iter_mut()
+        .find(|el| el.mint == source_mint)
+    {
+        Some(el) => el.set_amount(unwrap_int!(el.amount.checked_sub(amount_in)))?,
+        None => {
+            deviation_before = deviation_before
+                .checked_add(compute_collateral_value(
+                    amount_in,
+                    source.decimals,
+                    source.price,
+                    Rounding::Down,
+                )?)
+                .ok_or_else(math_error!())?
+        }
+    }
+    let dest = collateral_values_after
+        .iter_mut()
+        .find(|el| el.mint == dest_mint)
+        .ok_or(ErrorCode::CollateralValuationAccountsMismatch)?;
+    dest.set_amount(unwrap_int!(dest.amount.checked_add(amount_out)))?;
+
+    update_target_values(&mut collateral_values_after)?;
+    let deviation_after = sum_collateral_deviations(&collateral_values_after)?;
+
+    msg!(
+        "total deviation before = {}, after = {}",
+        deviation_before,
+        deviation_after
+    );
+    invariant!(
+        deviation_after < deviation_before,
+        ErrorCode::RebalanceIncreasedDeviation
+    );
+
+    Ok(())
+}
+
+/// rebalances can be paused for the whole bucket, or for a single collateral on either side of
+/// the swap.
+pub fn verify_rebalance_not_paused<'info>(
+    accounts: &Rebalance<'info>,
+    source_mint: Pubkey,
+    dest_mint: Pubkey,
+) -> ProgramResult {
+    let bucket = accounts.bucket.load()?;
+    bucket.require_not_paused(PAUSE_REBALANCES, Some(source_mint))?;
+    bucket.require_not_paused(PAUSE_REBALANCES, Some(dest_mint))?;
+
+    Ok(())
+}
+
+/// split the (mint, crate ATA, oracle, collateral config) accounts for each of the bucket's
+/// authorized collateral off the front of the remaining accounts. returns the valuation accounts
+/// and any accounts after them.
+pub fn split_collateral_valuation_accounts<'a, 'info>(
+    accounts: &Rebalance<'info>,
+    remaining_accounts: &'a [AccountInfo<'info>],
+) -> Result<(&'a [AccountInfo<'info>], &'a [AccountInfo<'info>]), ProgramError> {
+    let num_valuation_accounts =
+        accounts.bucket.load()?.collateral_len as usize * ACCOUNTS_PER_COLLATERAL_VALUATION;
+    invariant!(
+        remaining_accounts.len() >= num_valuation_accounts,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+
+    Ok(remaining_accounts.split_at(num_valuation_accounts))
+}
+
+/// rebalances between authorized collateral must move collateral that is out of its allocation
+/// band, i.e. the source must be above its band or the destination below its band. collateral that
+/// is no longer authorized has no band, so swapping it out is always allowed.
+pub fn verify_rebalance_out_of_band(
+    collateral_values: &Vec<CollateralValue>,
+    source_mint: Pubkey,
+    dest_mint: Pubkey,
+) -> ProgramResult {
+    let source = match collateral_values.iter().find(|el| el.mint == source_mint) {
+        Some(source) => source,
+        None => return Ok(()),
+    };
+    let dest_is_below_band = collateral_values
+        .iter()
+        .find(|el| el.mint == dest_mint)
+        .map_or(false, |dest| dest.is_below_band());
+
+    invariant!(
+        source.is_above_band() || dest_is_below_band,
+        ErrorCode::CollateralWithinBand
+    );
+
+    Ok(())
+}
+
+/// value the swap input with the source collateral's oracle price and record it against the
+/// bucket's rebalance limits. the input is valued rather than the output, so that a swap filled at
+/// a bad price through a thin or manipulated pool still uses up the window for everything it sold.
+/// the rebalance authority's cooldown is tracked apart from everyone else's.
+pub fn record_rebalance_turnover<'info>(
+    accounts: &mut Rebalance<'info>,
+    collateral_values: &Vec<CollateralValue>,
+    source: SwapLeg,
+    amount_in: u64,
+    slot: u64,
+) -> ProgramResult {
+    let caller_is_rebalance_authority =
+        accounts.payer.key() == accounts.bucket.load()?.rebalance_authority;
+    accounts.rebalance_state.record_rebalance(
+        slot,
+        // round the swapped value up so that turnover is never undercounted
+        compute_collateral_value(amount_in, source.decimals, source.price, Rounding::Up)?,
+        sum_collateral_values(collateral_values)?,
+        caller_is_rebalance_authority,
+    )
+}
+
+/// record the swap in the collateral configs of the source and destination collateral. the configs
+/// are the ones supplied with the valuation accounts, so they must be writable. collateral that is
+/// not authorized, e.g. a source that is being cleared out, is not valued and is skipped.
+pub fn record_rebalance_stats<'info>(
+    valuation_accounts: &[AccountInfo<'info>],
+    collateral_values: &Vec<CollateralValue>,
+    source_mint: Pubkey,
+    amount_in: u64,
+    dest_mint: Pubkey,
+    amount_out: u64,
+    slot: u64,
+) -> ProgramResult {
+    if let Some(mut collateral_config) =
+        load_valuation_collateral_config(valuation_accounts, collateral_values, source_mint)?
+    {
+        collateral_config.record_swap_out(amount_in, slot)?;
+        collateral_config.exit(&crate::ID)?;
+    }
+    if let Some(mut collateral_config) =
+        load_valuation_collateral_config(valuation_accounts, collateral_values, dest_mint)?
+    {
+        collateral_config.record_swap_in(amount_out, slot)?;
+        collateral_config.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// the collateral config of mint from the valuation accounts, if mint is valued. the config was
+/// already verified against the bucket and mint when the collateral was valued.
+fn load_valuation_collateral_config<'a, 'info>(
+    valuation_accounts: &'a [AccountInfo<'info>],
+    collateral_values: &Vec<CollateralValue>,
+    mint: Pubkey,
+) -> Result<Option<Account<'info, CollateralConfig>>, ProgramError> {
+    let idx = match collateral_values.iter().position(|el| el.mint == mint) {
+        Some(idx) => idx,
+        None => return Ok(None),
+    };
+
+    // the config is the last account of each valuation group
+    let account_info = valuation_accounts
+        .get((idx + 1) * ACCOUNTS_PER_COLLATERAL_VALUATION - 1)
+        .ok_or(ErrorCode::CollateralValuationAccountsMismatch)?;
+
+    Ok(Some(Account::try_from(account_info)?))
+}
+
+/// the collateral with fewer decimals is wrapped up to the other collateral's decimals. verify
+/// that the supplied wrapper accounts match the wrapper config the authority recorded for it.
+fn verify_decimal_wrapper<'info>(
+    accounts: &Rebalance<'info>,
+    asset: &RebalanceAsset<'info>,
+    wrapper: &DecimalWrapperAsset<'info>,
+) -> ProgramResult {
+    let (wrapped_mint, target_decimals) = if asset.token_a.decimals < asset.token_b.decimals {
+        (asset.token_a.key(), asset.token_b.decimals)
+    } else {
+        (asset.token_b.key(), asset.token_a.decimals)
+    };
+
+    let collateral_wrapper = &wrapper.collateral_wrapper;
+    invariant!(
+        collateral_wrapper.bucket == accounts.bucket.key()
+            && collateral_wrapper.mint == wrapped_mint
+            && collateral_wrapper.wrapper_program == wrapper.wrapper_program.key()
+            && collateral_wrapper.wrapper == wrapper.wrapper.key()
+            && collateral_wrapper.wrapper_mint == wrapper.wrapper_mint.key()
+            && collateral_wrapper.wrapper_underlying_tokens
+                == wrapper.wrapper_underlying_tokens.key()
+            && wrapper.wrapper_mint.decimals == target_decimals
+            && wrapper.bucket_wrapped_ata.owner == accounts.bucket.key(),
+        ErrorCode::InvalidDecimalWrapperAccounts
+    );
+
+    Ok(())
+}
+
+/// move tokens out of the crate, swap them on the swap pair's venue and move the proceeds back into
+/// the crate. if the collateral decimals differ, the swap is wrapped in a decimal wrapper deposit
+/// or withdrawal, so that the venue only ever sees tokens with matching decimals. returns the number
+/// of destination tokens received from the swap.
+pub fn swap_through_bucket<'a, 'info>(
+    accounts: &Rebalance<'info>,
+    route: &mut SwapRoute<'a, 'info>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> Result<u64, ProgramError> {
+    // the authority whitelists a venue per collateral pair. refuse to swap pairs that the swap
+    // pair account does not cover.
+    invariant!(
+        accounts
+            .swap_pair
+            .matches(route.asset.token_a.key(), route.asset.token_b.key()),
+        ErrorCode::SwapPairMismatch
+    );
+
+    let bucket = accounts.bucket.key();
     let withdraw_authority_signer_seeds: &[&[&[u8]]] = &[&[
         WITHDRAW_SEED.as_bytes(),
         bucket.as_ref(),
-        &[ctx.accounts.withdraw_authority.bump],
+        &[accounts.withdraw_authority.bump],
     ]];
     withdraw(
-        ctx.accounts
-            .into_withdraw_collateral_context(&rebalance_asset)
+        accounts
+            .into_withdraw_collateral_context(
+                &route.asset.crate_source_ata.to_account_info(),
+                &route.asset.bucket_source_ata.to_account_info(),
+            )
             .with_signer(withdraw_authority_signer_seeds),
-        swap_amounts.amount_in,
+        amount_in,
     )?;
 
+    // copy the bump out of the bucket, the bucket cannot stay borrowed while it signs for CPIs
+    let bucket_bump = accounts.bucket.load()?.bump;
     let bucket_signer_seeds: &[&[&[u8]]] = &[&[
         BUCKET_SEED.as_bytes(),
-        ctx.accounts.crate_token.key.as_ref(),
-        &[ctx.accounts.bucket.bump],
+        accounts.crate_token.key.as_ref(),
+        &[bucket_bump],
     ]];
 
-    // (todo): verify tokens have same decimals since the Saber stable swap invariant formula
-    // does not adjust for the number of decimal places that the underlying token has. more details:
-    // https://docs.saber.so/docs/developing/decimal-wrappers. further, saber provides additional
-    // contracts for interacting with saber: https://github.com/saber-hq/saber-periphery.
-    stable_swap_anchor::swap(
-        ctx.accounts
-            .into_saber_swap_context(&rebalance_asset)
-            .with_signer(bucket_signer_seeds),
-        swap_amounts.amount_in,
-        swap_amounts.amount_out,
-    )?;
+    let bucket_source_ata = route.asset.bucket_source_ata.to_account_info();
+    let bucket_dest_ata = route.asset.bucket_dest_ata.to_account_info();
+    let dest_amount_before_swap = route.asset.bucket_dest_ata.amount;
+    match &route.wrapper {
+        None => venue::swap(
+            accounts,
+            &accounts.swap_pair,
+            &accounts.swap_program,
+            &bucket_source_ata,
+            &bucket_dest_ata,
+            route.venue_accounts,
+            amount_in,
+            minimum_amount_out,
+            bucket_signer_seeds,
+        )?,
+        Some(wrapper) => {
+            let bucket_wrapped_ata = wrapper.bucket_wrapped_ata.to_account_info();
+            let wrapped_decimals = wrapper.wrapper_mint.decimals;
+
+            if route.wraps_source() {
+                // wrap the source collateral up to the destination's decimals, then swap the
+                // wrapped tokens for the destination collateral.
+                decimal_wrapper::wrap(
+                    accounts,
+                    wrapper,
+                    &bucket_source_ata,
+                    amount_in,
+                    bucket_signer_seeds,
+                )?;
+
+                let wrapped_amount_in = scale_amount_for_decimals(
+                    amount_in,
+                    route.asset.token_a.decimals,
+                    wrapped_decimals,
+                )?;
+                venue::swap(
+                    accounts,
+                    &accounts.swap_pair,
+                    &accounts.swap_program,
+                    &bucket_wrapped_ata,
+                    &bucket_dest_ata,
+                    route.venue_accounts,
+                    wrapped_amount_in,
+                    minimum_amount_out,
+                    bucket_signer_seeds,
+                )?;
+            } else {
+                // swap the source collateral for the wrapped destination, then unwrap down to the
+                // destination's decimals. the minimum amount out is denominated in wrapped tokens.
+                let wrapped_minimum_amount_out = scale_amount_for_decimals(
+                    minimum_amount_out,
+                    route.asset.token_b.decimals,
+                    wrapped_decimals,
+                )?;
+                venue::swap(
+                    accounts,
+                    &accounts.swap_pair,
+                    &accounts.swap_program,
+                    &bucket_source_ata,
+                    &bucket_wrapped_ata,
+                    route.venue_accounts,
+                    amount_in,
+                    wrapped_minimum_amount_out,
+                    bucket_signer_seeds,
+                )?;
+
+                decimal_wrapper::unwrap_all(
+                    accounts,
+                    wrapper,
+                    &bucket_dest_ata,
+                    bucket_signer_seeds,
+                )?;
+            }
+        }
+    }
+
+    // reload the bucket's destination ATA so that we transfer everything the swap produced back
+    // into the crate, not just the minimum amount out.
+    route.asset.bucket_dest_ata.reload()?;
+    let amount_out = unwrap_int!(route
+        .asset
+        .bucket_dest_ata
+        .amount
+        .checked_sub(dest_amount_before_swap));
 
-    // after swap, transfer the minimum_amount_out tokens. this should be safe to do so since
-    // garunteed the minimum number of tokens specified. otherwise, we could use the `amount`
-    // on the account struct. however, we would first need to reload the account context.
     transfer(
-        ctx.accounts
-            .into_transfer_token_context(&rebalance_asset)
+        accounts
+            .into_transfer_token_context(
+                &route.asset.bucket_dest_ata.to_account_info(),
+                &route.asset.crate_dest_ata.to_account_info(),
+            )
             .with_signer(bucket_signer_seeds),
-        swap_amounts.amount_out,
+        amount_out,
     )?;
 
-    Ok(())
+    Ok(amount_out)
 }
 
+/// amount of the destination collateral that pay_keeper_bounty will take back out of the crate.
+/// this is zero unless the bounty is paid from the swap output.
+pub fn compute_bounty_from_swap_output<'info>(
+    accounts: &Rebalance<'info>,
+    bounty_accounts: &[AccountInfo<'info>],
+    amount_out: u64,
+    out_decimals: u8,
+) -> Result<u64, ProgramError> {
+    let keeper_bounty = accounts.bucket.load()?.keeper_bounty();
+    if bounty_accounts.len() == 0
+        || keeper_bounty.bps == 0
+        || keeper_bounty.source != BountySource::SwapOutput
+    {
+        return Ok(0);
+    }
+
+    compute_keeper_bounty(&keeper_bounty, amount_out, out_decimals, out_decimals)
+}
+
+/// pay the caller a bounty for a rebalance that moved the bucket toward its targets or cleared out
+/// collateral that is no longer authorized. the bounty is only paid if the bucket has a bounty
+/// configured and the caller supplied bounty accounts. returns the amount paid.
+///
+/// depending on the bucket's bounty source, the bounty is either withdrawn from the crate ATA that
+/// received the swap output, or issued as new reserve tokens. in the latter case, the bounty is
+/// scaled to the reserve's decimals under the assumption that collateral trades near $1.
+pub fn pay_keeper_bounty<'info>(
+    accounts: &mut Rebalance<'info>,
+    rebalance_asset: &RebalanceAsset<'info>,
+    bounty_accounts: &[AccountInfo<'info>],
+    amount_out: u64,
+    slot: u64,
+) -> Result<u64, ProgramError> {
+    let keeper_bounty = accounts.bucket.load()?.keeper_bounty();
+    if bounty_accounts.len() == 0 || keeper_bounty.bps == 0 {
+        return Ok(0);
+    }
+
+    invariant!(
+        bounty_accounts.len() == ACCOUNTS_PER_KEEPER_BOUNTY,
+        ErrorCode::InvalidKeeperBountyAccounts
+    );
+    let bounty: KeeperBountyAsset = Accounts::try_accounts(
+        &crate::ID,
+        &mut next_account_infos(&mut bounty_accounts.iter(), ACCOUNTS_PER_KEEPER_BOUNTY)?,
+        &[],
+    )?;
+
+    let bucket = accounts.bucket.key();
+    let crate_mint = accounts.bucket.load()?.crate_mint;
+    let issue_authority = Pubkey::create_program_address(
+        &[
+            ISSUE_SEED.as_bytes(),
+            bucket.as_ref(),
+            &[bounty.issue_authority.bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_| ErrorCode::InvalidKeeperBountyAccounts)?;
+    invariant!(
+        bounty.bounty_destination.owner == accounts.payer.key()
+            && bounty.issue_authority.key() == issue_authority
+            && bounty.crate_mint.key() == crate_mint,
+        ErrorCode::InvalidKeeperBountyAccounts
+    );
+
+    // a bounty paid from the swap output is paid in the destination collateral
+    let bounty_mint = match keeper_bounty.source {
+        BountySource::SwapOutput => rebalance_asset.token_b.key(),
+        BountySource::Reserve => crate_mint,
+    };
+    invariant!(
+        bounty.bounty_destination.mint == bounty_mint,
+        ErrorCode::InvalidKeeperBountyAccounts
+    );
+
+    let bounty_amount = compute_keeper_bounty(
+        &keeper_bounty,
+        amount_out,
+        rebalance_asset.token_b.decimals,
+        bounty.crate_mint.decimals,
+    )?;
+
+    if bounty_amount == 0 {
+        return Ok(0);
+    }
+
+    msg!(
+        "paying keeper bounty of {} to {}",
+        bounty_amount,
+        bounty.bounty_destination.key()
+    );
+
+    match keeper_bounty.source {
+        BountySource::SwapOutput => {
+            let withdraw_authority_signer_seeds: &[&[&[u8]]] = &[&[
+                WITHDRAW_SEED.as_bytes(),
+                bucket.as_ref(),
+                &[accounts.withdraw_authority.bump],
+            ]];
+            withdraw(
+                accounts
+                    .into_withdraw_bounty_context(rebalance_asset, &bounty)
+                    .with_signer(withdraw_authority_signer_seeds),
+                bounty_amount,
+            )?;
+        }
+        BountySource::Reserve => {
+            let issue_authority_signer_seeds: &[&[&[u8]]] = &[&[
+                ISSUE_SEED.as_bytes(),
+                bucket.as_ref(),
+                &[bounty.issue_authority.bump],
+            ]];
+            issue(
+                accounts
+                    .into_issue_bounty_context(&bounty)
+                    .with_signer(issue_authority_signer_seeds),
+                bounty_amount,
+            )?;
+            accounts.bucket_stats.record_issue(bounty_amount, slot)?;
+        }
+    }
+
+    Ok(bounty_amount)
+}
+
+/// keeper bounty for a swap that returned amount_out of a collateral with out_decimals. a bounty
+/// paid in reserve tokens is scaled to the reserve's decimals. rounded down in the bucket's favor.
+pub fn compute_keeper_bounty(
+    keeper_bounty: &KeeperBounty,
+    amount_out: u64,
+    out_decimals: u8,
+    reserve_decimals: u8,
+) -> Result<u64, ProgramError> {
+    let bounty_in_output_token: u64 = cast(apply_bps(
+        amount_out as u128,
+        keeper_bounty.bps,
+        Rounding::Down,
+    )?)?;
+
+    let bounty_amount = match keeper_bounty.source {
+        BountySource::SwapOutput => bounty_in_output_token,
+        BountySource::Reserve => {
+            scale_amount_for_decimals(bounty_in_output_token, out_decimals, reserve_decimals)?
+        }
+    };
+
+    Ok(min(bounty_amount, keeper_bounty.max_amount))
+}
+
+/// number of accounts needed to parse a [KeeperBountyAsset]
+pub const ACCOUNTS_PER_KEEPER_BOUNTY: usize = 3;
+
+/// number of accounts needed to parse a [DecimalWrapperAsset]
+pub const ACCOUNTS_PER_DECIMAL_WRAPPER: usize = 6;
+
+/// number of accounts needed to parse a single [RebalanceAsset]
+pub const ACCOUNTS_PER_REBALANCE_ASSET: usize = 6;
+
 /// parse remaining accounts array into RebalanceAsset structs and add to a vec
-fn rebalance_asset_from_account_infos<'a, 'info>(
+pub fn rebalance_asset_from_account_infos<'a, 'info>(
     remaining_accounts: &'a [AccountInfo<'info>],
 ) -> Result<Vec<RebalanceAsset<'info>>, ProgramError> {
     let mut rebalance_assets: Vec<RebalanceAsset> = Vec::new();
-    let accounts_per_rebalance_operation: usize = 6;
+    let accounts_per_rebalance_operation: usize = ACCOUNTS_PER_REBALANCE_ASSET;
 
     // remaining accounts are ATAs to assist in the collateral fanout distribution
     let num_remaining_accounts: usize = remaining_accounts.len();
     invariant!(
         num_remaining_accounts % accounts_per_rebalance_operation == 0,
-        "invalid number token accounts"
+        ErrorCode::InvalidRebalanceAccountCount
     );
 
     let remaining_accounts_iter = &mut remaining_accounts.iter();
@@ -136,10 +841,11 @@ fn rebalance_asset_from_account_infos<'a, 'info>(
 ///   the destination must be authorized to prevent the user from swapping to an arbitrary mint.
 /// - if the caller **is** the authority, the source mint can be authorized but doesn't have to be.
 ///   similar to above, the destination must be authorized.
-fn verify_collateral_for_caller<'info>(
+pub fn verify_collateral_for_caller(
     caller_is_rebalance_authority: bool,
-    rebalance_asset: &RebalanceAsset<'info>,
-    collateral: &Vec<Collateral>,
+    source_mint: Pubkey,
+    dest_mint: Pubkey,
+    collateral: &[Collateral],
 ) -> ProgramResult {
     msg!(
         "rebalance authority invoked instruction: {}",
@@ -150,10 +856,8 @@ fn verify_collateral_for_caller<'info>(
     // ATAs to bucket ATAs. so, if the bucket ATAs are for a different token  mint, the  operations
     // will fail. there is also no need to check the ATA's mint vs the token's mint account because we use
     // an anchor constraint to validate this.
-    let source_mint_authorized =
-        is_collateral_authorized(collateral, rebalance_asset.crate_source_ata.mint);
-    let dest_mint_authorized =
-        is_collateral_authorized(collateral, rebalance_asset.crate_dest_ata.mint);
+    let source_mint_authorized = is_collateral_authorized(collateral, source_mint);
+    let dest_mint_authorized = is_collateral_authorized(collateral, dest_mint);
 
     let mut authorized_invariant_condition: bool = !source_mint_authorized && dest_mint_authorized;
     if caller_is_rebalance_authority {
@@ -168,82 +872,155 @@ fn verify_collateral_for_caller<'info>(
     Ok(())
 }
 
-fn get_swap_amounts_for_caller<'info>(
-    caller_is_rebalance_authority: bool,
-    rebalance_asset: &RebalanceAsset<'info>,
-    requested_amount_in: u64,
-    requested_amount_out: u64,
+/// number of accounts needed to parse a [CollateralOracleAsset]
+pub const ACCOUNTS_PER_COLLATERAL_ORACLE: usize = 2;
+
+/// mints on a swap route that are not part of the valuation accounts, i.e. collateral that is no
+/// longer authorized or a two hop route's intermediate mint, are priced from an oracle and
+/// collateral config supplied after the valuation accounts. returns the mint's leg and the accounts
+/// after the oracle accounts.
+pub fn collateral_leg_from_account_infos<'a, 'info>(
+    accounts: &Rebalance<'info>,
+    mint: Pubkey,
+    decimals: u8,
+    remaining_accounts: &'a [AccountInfo<'info>],
+    slot: u64,
+) -> Result<(SwapLeg, &'a [AccountInfo<'info>]), ProgramError> {
+    invariant!(
+        remaining_accounts.len() >= ACCOUNTS_PER_COLLATERAL_ORACLE,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+    let (oracle_accounts, remaining_accounts) =
+        remaining_accounts.split_at(ACCOUNTS_PER_COLLATERAL_ORACLE);
+    let asset: CollateralOracleAsset = Accounts::try_accounts(
+        &crate::ID,
+        &mut next_account_infos(&mut oracle_accounts.iter(), ACCOUNTS_PER_COLLATERAL_ORACLE)?,
+        &[],
+    )?;
+    invariant!(
+        asset.collateral_config.bucket == accounts.bucket.key()
+            && asset.collateral_config.mint == mint
+            && asset.collateral_config.oracle == asset.oracle.key(),
+        ErrorCode::InvalidCollateralConfig
+    );
+
+    let leg = SwapLeg {
+        decimals,
+        price: get_oracle_price(
+            &asset.oracle,
+            asset.collateral_config.oracle_source,
+            slot,
+            TARGET_ORACLE_PRECISION,
+        )?
+        .price,
+    };
+
+    Ok((leg, remaining_accounts))
+}
+
+/// a stray collateral cleanup swaps the crate's whole balance of the source collateral for at
+/// least its oracle value in the destination collateral, less MAX_PERMISSIONLESS_SLIPPAGE_BPS.
+pub fn compute_stray_collateral_swap(
+    source_amount: u64,
+    (source, dest): (SwapLeg, SwapLeg),
 ) -> Result<ExchangeAmount, ProgramError> {
-    if caller_is_rebalance_authority {
-        // blindly accept amounts in/out from authority
-        Ok(ExchangeAmount {
-            amount_in: requested_amount_in,
-            amount_out: requested_amount_out,
-        })
-    } else {
-        // we must scale token B's expected amount out based on token A's decimals.
-        // otherwise, the difference in number of tokens received could be orders
-        // of magnitude difference.
-        //
-        // e.g. consider aa token A with 9 decimals and token B
-        // with 6 decimals. if a user tries to swap 1000 token A without scaling,
-        // they woudld get 1 token B.
-        //
-        // as a further note, swapping tokens with different decimals can have
-        // unintended consequences. see saber docs for more details:
-        // https://docs.saber.so/docs/developing/decimal-wrappers
-        let scaled_amount = scale_amount_for_decimals(
-            rebalance_asset.crate_source_ata.amount,
-            rebalance_asset.token_a.decimals,
-            rebalance_asset.token_b.decimals,
-        )
-        .unwrap(); // change to unwrap_or to avoid panics?
-
-        Ok(compute_exchange_amounts(
-            scaled_amount,
-            rebalance_asset.token_b.decimals,
-            MAX_SLIPPAGE_BPS,
-     )?)
+    let OracleAmountsOut {
+        minimum_amount_out, ..
+    } = compute_oracle_amounts_out(source_amount, source, dest, MAX_PERMISSIONLESS_SLIPPAGE_BPS)?;
+
+    Ok(ExchangeAmount {
+        amount_in: source_amount,
+        amount_out: minimum_amount_out,
+    })
+}
+
+/// decimals and oracle price of one side of a swap
+#[derive(Clone, Copy, Debug)]
+pub struct SwapLeg {
+    pub decimals: u8,
+    pub price: i128,
+}
+
+impl From<&CollateralValue> for SwapLeg {
+    fn from(collateral_value: &CollateralValue) -> Self {
+        SwapLeg {
+            decimals: collateral_value.decimals,
+            price: collateral_value.price,
+        }
     }
 }
 
-/// compute an expected amount out given an input amount, token decimals,
-/// and max slippage in basis points. we need this value so that we can pass
-/// it into saber. saber does not accept max slippage in bps/percent.
-fn compute_exchange_amounts<'info>(
-    amount: u64,
-    decimals: u8,
+/// amount of the destination collateral worth amount_in of the source collateral at oracle prices,
+/// and the least a swap may return given max_slippage_bps. basing the minimum on oracle prices
+/// rather than the pool's quote prevents a caller from routing the swap through a pool with a
+/// manipulated price. both are rounded up.
+pub fn compute_oracle_amounts_out(
+    amount_in: u64,
+    source: SwapLeg,
+    dest: SwapLeg,
     max_slippage_bps: u64,
-) -> Result<ExchangeAmount, ProgramError> {
-    let additional_precision_factor = 2;
-
-    let slippage_factor = (MAX_BASIS_POINTS as u64)
-        .checked_sub(max_slippage_bps)
-        .ok_or_else(math_error!())?
-        .checked_mul(
-            10_u64
-                .checked_pow(additional_precision_factor)
-                .ok_or_else(math_error!())?,
-        )
-        .ok_or_else(math_error!())?;
-
-    let amount_out = amount
-        .checked_mul(slippage_factor)
-        .ok_or_else(math_error!())?
-        .checked_div(
-            10_u64
-                .checked_pow(decimals as u32)
-                .ok_or_else(math_error!())?,
-        )
-        .ok_or_else(math_error!())?;
+) -> Result<OracleAmountsOut, ProgramError> {
+    let expected_amount_out = compute_collateral_amount(
+        compute_collateral_value(amount_in, source.decimals, source.price, Rounding::Up)?,
+        dest.decimals,
+        dest.price,
+        Rounding::Up,
+    )?;
+    let minimum_amount_out = mul_div(
+        expected_amount_out as u128,
+        (MAX_BASIS_POINTS as u128)
+            .checked_sub(max_slippage_bps as u128)
+            .ok_or_else(math_error!())?,
+        MAX_BASIS_POINTS as u128,
+        Rounding::Up,
+    )?;
 
-    Ok(ExchangeAmount {
-        amount_in: amount,
-        amount_out,
+    Ok(OracleAmountsOut {
+        expected_amount_out,
+        minimum_amount_out: cast(minimum_amount_out)?,
     })
 }
 
-struct ExchangeAmount {
+pub struct OracleAmountsOut {
+    pub expected_amount_out: u64,
+    pub minimum_amount_out: u64,
+}
+
+/// the oracle value of what the swap returned must be at least the oracle value of what it spent,
+/// less max_slippage_bps. unlike the swap's minimum amount out, this measures the amount that
+/// actually arrived in the crate.
+pub fn verify_swap_value_kept(
     amount_in: u64,
+    source: SwapLeg,
     amount_out: u64,
+    dest: SwapLeg,
+    max_slippage_bps: u64,
+) -> ProgramResult {
+    let value_in =
+        compute_collateral_value(amount_in, source.decimals, source.price, Rounding::Up)?;
+    let min_value_out = mul_div(
+        value_in,
+        (MAX_BASIS_POINTS as u128)
+            .checked_sub(max_slippage_bps as u128)
+            .ok_or_else(math_error!())?,
+        MAX_BASIS_POINTS as u128,
+        Rounding::Up,
+    )?;
+    let value_out =
+        compute_collateral_value(amount_out, dest.decimals, dest.price, Rounding::Down)?;
+
+    msg!(
+        "swap value in = {}, out = {}, minimum out = {}",
+        value_in,
+        value_out,
+        min_value_out
+    );
+    invariant!(value_out >= min_value_out, ErrorCode::SwapValueLost);
+
+    Ok(())
+}
+
+pub struct ExchangeAmount {
+    pub amount_in: u64,
+    pub amount_out: u64,
 }
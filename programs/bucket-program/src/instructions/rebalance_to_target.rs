@@ -0,0 +1,255 @@
+use {
+    crate::{
+        constant::{
+            EVENT_VERSION, MAX_BASIS_POINTS, MAX_PERMISSIONLESS_SLIPPAGE_BPS,
+            MAX_REBALANCE_TO_TARGET_BPS,
+        },
+        context::Rebalance,
+        error::ErrorCode,
+        event::Rebalanced,
+        instructions::rebalance::{
+            compute_bounty_from_swap_output, compute_oracle_amounts_out, pay_keeper_bounty,
+            record_rebalance_stats, record_rebalance_turnover, split_collateral_valuation_accounts,
+            swap_route_from_account_infos, swap_through_bucket, verify_rebalance_not_paused,
+            verify_rebalance_out_of_band, verify_rebalance_reduced_deviation,
+            verify_swap_value_kept, OracleAmountsOut, SwapRoute,
+        },
+        math::decimal::{mul_div, Rounding},
+        state::allocation_ramp::load_target_collateral,
+        util::{
+            collateral_values_from_account_infos, compute_collateral_amount, sum_collateral_values,
+            CollateralValue,
+        },
+    },
+    anchor_lang::prelude::*,
+    std::cmp::min,
+    vipers::{invariant, unwrap_int},
+};
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
+    max_amount_in: u64,
+) -> ProgramResult {
+    // the first accounts describe the swap route, followed by the accounts used to value the
+    // bucket's authorized collateral. any accounts after that are optional keeper bounty accounts.
+    let (mut route, remaining_accounts) =
+        swap_route_from_account_infos(ctx.accounts, ctx.remaining_accounts)?;
+    verify_rebalance_not_paused(
+        ctx.accounts,
+        route.asset.token_a.key(),
+        route.asset.token_b.key(),
+    )?;
+    let (valuation_accounts, bounty_accounts) =
+        split_collateral_valuation_accounts(ctx.accounts, remaining_accounts)?;
+
+    let clock = Clock::get()?;
+    let collateral_values: Vec<CollateralValue> = collateral_values_from_account_infos(
+        valuation_accounts,
+        &load_target_collateral(
+            &ctx.accounts.bucket.load()?,
+            &ctx.accounts.allocation_ramp,
+            clock.slot,
+        )?,
+        &ctx.accounts.bucket.key(),
+        ctx.accounts.crate_token.key,
+        clock.slot,
+    )?;
+
+    let swap = compute_rebalance_swap(&collateral_values, max_amount_in)?;
+    let source = &collateral_values[swap.source_idx];
+    let dest = &collateral_values[swap.dest_idx];
+    verify_rebalance_to_target_route(&route, source, dest)?;
+    verify_rebalance_out_of_band(&collateral_values, source.mint, dest.mint)?;
+    let RebalanceSwap {
+        amount_in,
+        minimum_amount_out,
+        ..
+    } = swap;
+
+    msg!(
+        "rebalancing {} → {}: in = {}, minimum out = {}",
+        source.mint,
+        dest.mint,
+        amount_in,
+        minimum_amount_out
+    );
+
+    let amount_out = swap_through_bucket(ctx.accounts, &mut route, amount_in, minimum_amount_out)?;
+    verify_swap_value_kept(
+        amount_in,
+        source.into(),
+        amount_out,
+        dest.into(),
+        MAX_PERMISSIONLESS_SLIPPAGE_BPS,
+    )?;
+
+    // a bounty paid from the swap output leaves the crate, so the swap must reduce deviation even
+    // after the keeper is paid. check before paying.
+    let bounty_from_swap_output = compute_bounty_from_swap_output(
+        ctx.accounts,
+        bounty_accounts,
+        amount_out,
+        route.asset.token_b.decimals,
+    )?;
+    verify_rebalance_reduced_deviation(
+        &collateral_values,
+        source.mint,
+        source.into(),
+        amount_in,
+        dest.mint,
+        unwrap_int!(amount_out.checked_sub(bounty_from_swap_output)),
+    )?;
+
+    record_rebalance_turnover(
+        ctx.accounts,
+        &collateral_values,
+        source.into(),
+        amount_in,
+        clock.slot,
+    )?;
+    record_rebalance_stats(
+        valuation_accounts,
+        &collateral_values,
+        source.mint,
+        amount_in,
+        dest.mint,
+        amount_out,
+        clock.slot,
+    )?;
+    let keeper_bounty = pay_keeper_bounty(
+        ctx.accounts,
+        &route.asset,
+        bounty_accounts,
+        amount_out,
+        clock.slot,
+    )?;
+    ctx.accounts.bucket_stats.record_activity(clock.slot);
+
+    emit!(Rebalanced {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        caller: ctx.accounts.payer.key(),
+        mint_in: source.mint,
+        mint_out: dest.mint,
+        amount_in,
+        amount_out,
+        pools: vec![ctx.accounts.swap_pair.swap],
+        keeper_bounty,
+    });
+
+    Ok(())
+}
+
+/// the swap rebalance_to_target makes, in base units of the source and destination collateral
+pub struct RebalanceSwap {
+    pub source_idx: usize,
+    pub dest_idx: usize,
+    pub amount_in: u64,
+    pub expected_amount_out: u64,
+    pub minimum_amount_out: u64,
+}
+
+/// size the swap towards the rebalance target, spending at most max_amount_in of the source
+/// collateral.
+pub fn compute_rebalance_swap(
+    collateral_values: &Vec<CollateralValue>,
+    max_amount_in: u64,
+) -> Result<RebalanceSwap, ProgramError> {
+    let target = compute_rebalance_target(collateral_values)?;
+    let source = &collateral_values[target.source_idx];
+    let dest = &collateral_values[target.dest_idx];
+
+    let amount_in = min(
+        min(
+            compute_collateral_amount(target.value, source.decimals, source.price, Rounding::Down)?,
+            max_amount_in,
+        ),
+        source.amount,
+    );
+    invariant!(amount_in > 0, ErrorCode::RebalanceNotRequired);
+
+    let OracleAmountsOut {
+        expected_amount_out,
+        minimum_amount_out,
+    } = compute_oracle_amounts_out(
+        amount_in,
+        source.into(),
+        dest.into(),
+        MAX_PERMISSIONLESS_SLIPPAGE_BPS,
+    )?;
+
+    Ok(RebalanceSwap {
+        source_idx: target.source_idx,
+        dest_idx: target.dest_idx,
+        amount_in,
+        expected_amount_out,
+        minimum_amount_out,
+    })
+}
+
+/// the swap accounts are supplied by the caller. make sure they actually move value from the most
+/// overweight collateral into the most underweight collateral.
+pub fn verify_rebalance_to_target_route(
+    route: &SwapRoute,
+    source: &CollateralValue,
+    dest: &CollateralValue,
+) -> ProgramResult {
+    invariant!(
+        route.asset.token_a.key() == source.mint
+            && route.asset.token_b.key() == dest.mint
+            && route.asset.crate_source_ata.key() == source.crate_collateral
+            && route.asset.crate_dest_ata.key() == dest.crate_collateral,
+        ErrorCode::RebalancePairMismatch
+    );
+
+    Ok(())
+}
+
+/// the collateral pair to swap and the value to move between them, in TARGET_ORACLE_PRECISION units
+pub struct RebalanceTarget {
+    pub source_idx: usize,
+    pub dest_idx: usize,
+    pub value: u128,
+}
+
+/// pick the most overweight collateral as the source and the most underweight collateral as the
+/// destination. the value moved is the smaller of the two gaps so that neither collateral overshoots
+/// its target, and it is further capped at MAX_REBALANCE_TO_TARGET_BPS of the bucket's total value.
+pub fn compute_rebalance_target(
+    collateral_values: &Vec<CollateralValue>,
+) -> Result<RebalanceTarget, ProgramError> {
+    let mut source: Option<(usize, u128)> = None;
+    let mut dest: Option<(usize, u128)> = None;
+
+    for (idx, el) in collateral_values.iter().enumerate() {
+        if el.value > el.target_value {
+            let excess = el.value - el.target_value;
+            if source.map_or(true, |(_, max_excess)| excess > max_excess) {
+                source = Some((idx, excess));
+            }
+        } else if el.value < el.target_value {
+            let deficit = el.target_value - el.value;
+            if dest.map_or(true, |(_, max_deficit)| deficit > max_deficit) {
+                dest = Some((idx, deficit));
+            }
+        }
+    }
+
+    let ((source_idx, excess), (dest_idx, deficit)) = match (source, dest) {
+        (Some(source), Some(dest)) => (source, dest),
+        _ => return Err(ErrorCode::RebalanceNotRequired.into()),
+    };
+
+    let max_value = mul_div(
+        sum_collateral_values(collateral_values)?,
+        MAX_REBALANCE_TO_TARGET_BPS as u128,
+        MAX_BASIS_POINTS as u128,
+        Rounding::Down,
+    )?;
+
+    Ok(RebalanceTarget {
+        source_idx,
+        dest_idx,
+        value: min(min(excess, deficit), max_value),
+    })
+}
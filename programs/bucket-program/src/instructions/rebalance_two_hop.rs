@@ -0,0 +1,333 @@
+use {
+    crate::{
+        constant::{
+            BUCKET_SEED, EVENT_VERSION, MAX_AUTHORITY_SLIPPAGE_BPS,
+            MAX_PERMISSIONLESS_SLIPPAGE_BPS, WITHDRAW_SEED,
+        },
+        context::{Rebalance, TwoHopRebalanceAsset},
+        error::ErrorCode,
+        event::Rebalanced,
+        instructions::rebalance::{
+            collateral_leg_from_account_infos, compute_oracle_amounts_out,
+            compute_stray_collateral_swap, record_rebalance_stats, record_rebalance_turnover,
+            split_collateral_valuation_accounts, verify_collateral_for_caller,
+            verify_rebalance_not_paused, verify_rebalance_out_of_band,
+            verify_rebalance_reduced_deviation, verify_swap_value_kept, ExchangeAmount,
+            OracleAmountsOut, SwapLeg,
+        },
+        state::allocation_ramp::load_target_collateral,
+        util::{collateral_values_from_account_infos, is_collateral_authorized, CollateralValue},
+        venue,
+    },
+    anchor_lang::{prelude::*, solana_program::account_info::next_account_infos},
+    anchor_spl::token::transfer,
+    crate_token::cpi::withdraw,
+    std::cmp::max,
+    vipers::{invariant, unwrap_int},
+};
+
+/// number of accounts needed to parse a [TwoHopRebalanceAsset]
+pub const ACCOUNTS_PER_TWO_HOP_REBALANCE_ASSET: usize = 10;
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
+    amount_in: u64,
+    minimum_amount_out: u64,
+) -> ProgramResult {
+    // remaining accounts are the two hop asset, followed by the venue accounts for the first swap
+    // pair (token_a → intermediate), the venue accounts for the second swap pair
+    // (intermediate → token_b), the accounts used to value the bucket's authorized collateral and
+    // the oracle accounts of the mints that are not valued.
+    invariant!(
+        ctx.remaining_accounts.len() >= ACCOUNTS_PER_TWO_HOP_REBALANCE_ASSET,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+    let (asset_accounts, remaining_accounts) = ctx
+        .remaining_accounts
+        .split_at(ACCOUNTS_PER_TWO_HOP_REBALANCE_ASSET);
+    let mut asset: TwoHopRebalanceAsset = Accounts::try_accounts(
+        &crate::ID,
+        &mut next_account_infos(
+            &mut asset_accounts.iter(),
+            ACCOUNTS_PER_TWO_HOP_REBALANCE_ASSET,
+        )?,
+        &[],
+    )?;
+    verify_rebalance_not_paused(ctx.accounts, asset.token_a.key(), asset.token_b.key())?;
+
+    let num_first_venue_accounts = ctx.accounts.swap_pair.venue.num_accounts();
+    let num_second_venue_accounts = asset.second_swap_pair.venue.num_accounts();
+    invariant!(
+        remaining_accounts.len() >= num_first_venue_accounts + num_second_venue_accounts,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+    let (first_venue_accounts, remaining_accounts) =
+        remaining_accounts.split_at(num_first_venue_accounts);
+    let (second_venue_accounts, remaining_accounts) =
+        remaining_accounts.split_at(num_second_venue_accounts);
+
+    let (valuation_accounts, remaining_accounts) =
+        split_collateral_valuation_accounts(ctx.accounts, remaining_accounts)?;
+    let clock = Clock::get()?;
+    let collateral_values: Vec<CollateralValue> = collateral_values_from_account_infos(
+        valuation_accounts,
+        &load_target_collateral(
+            &ctx.accounts.bucket.load()?,
+            &ctx.accounts.allocation_ramp,
+            clock.slot,
+        )?,
+        &ctx.accounts.bucket.key(),
+        ctx.accounts.crate_token.key,
+        clock.slot,
+    )?;
+
+    verify_two_hop_asset(ctx.accounts, &asset)?;
+
+    // the intermediate mint does not need to be authorized collateral. it is only held by the
+    // bucket for the duration of the instruction.
+    let caller_is_rebalance_authority =
+        ctx.accounts.payer.key() == ctx.accounts.bucket.load()?.rebalance_authority;
+    verify_collateral_for_caller(
+        caller_is_rebalance_authority,
+        asset.token_a.key(),
+        asset.token_b.key(),
+        ctx.accounts.bucket.load()?.collateral(),
+    )?;
+    verify_rebalance_out_of_band(&collateral_values, asset.token_a.key(), asset.token_b.key())?;
+
+    // the intermediate mint's oracle comes right after the valuation accounts, followed by the
+    // source collateral's oracle if it is no longer authorized.
+    let (intermediate, remaining_accounts) = collateral_leg_from_account_infos(
+        ctx.accounts,
+        asset.token_intermediate.key(),
+        asset.token_intermediate.decimals,
+        remaining_accounts,
+        clock.slot,
+    )?;
+    let source_is_authorized = is_collateral_authorized(
+        ctx.accounts.bucket.load()?.collateral(),
+        asset.token_a.key(),
+    );
+    let (source, remaining_accounts) = if source_is_authorized {
+        let source = collateral_values
+            .iter()
+            .find(|el| el.mint == asset.token_a.key())
+            .ok_or(ErrorCode::CollateralValuationAccountsMismatch)?;
+
+        (source.into(), remaining_accounts)
+    } else {
+        collateral_leg_from_account_infos(
+            ctx.accounts,
+            asset.token_a.key(),
+            asset.token_a.decimals,
+            remaining_accounts,
+            clock.slot,
+        )?
+    };
+    let dest: SwapLeg = collateral_values
+        .iter()
+        .find(|el| el.mint == asset.token_b.key())
+        .ok_or(ErrorCode::CollateralValuationAccountsMismatch)?
+        .into();
+    invariant!(
+        remaining_accounts.len() == 0,
+        ErrorCode::InvalidRebalanceAccountCount
+    );
+
+    let (swap_amounts, max_slippage_bps) = if caller_is_rebalance_authority {
+        // the authority sizes the swap, but the route must still return the oracle value of
+        // amount_in, less MAX_AUTHORITY_SLIPPAGE_BPS.
+        let OracleAmountsOut {
+            minimum_amount_out: oracle_minimum_amount_out,
+            ..
+        } = compute_oracle_amounts_out(amount_in, source, dest, MAX_AUTHORITY_SLIPPAGE_BPS)?;
+        let swap_amounts = ExchangeAmount {
+            amount_in,
+            amount_out: max(minimum_amount_out, oracle_minimum_amount_out),
+        };
+
+        (swap_amounts, MAX_AUTHORITY_SLIPPAGE_BPS)
+    } else {
+        let swap_amounts =
+            compute_stray_collateral_swap(asset.crate_source_ata.amount, (source, dest))?;
+
+        (swap_amounts, MAX_PERMISSIONLESS_SLIPPAGE_BPS)
+    };
+    // the first leg must return the oracle value of amount_in in the intermediate mint, less the
+    // same slippage bound as the whole route.
+    let OracleAmountsOut {
+        minimum_amount_out: minimum_intermediate_amount_out,
+        ..
+    } = compute_oracle_amounts_out(
+        swap_amounts.amount_in,
+        source,
+        intermediate,
+        max_slippage_bps,
+    )?;
+
+    msg!(
+        "computed two hop swap values: in = {}, intermediate out = {}, out = {}",
+        swap_amounts.amount_in,
+        minimum_intermediate_amount_out,
+        swap_amounts.amount_out
+    );
+
+    let accounts = ctx.accounts;
+    let bucket = accounts.bucket.key();
+    let withdraw_authority_signer_seeds: &[&[&[u8]]] = &[&[
+        WITHDRAW_SEED.as_bytes(),
+        bucket.as_ref(),
+        &[accounts.withdraw_authority.bump],
+    ]];
+    withdraw(
+        accounts
+            .into_withdraw_collateral_context(
+                &asset.crate_source_ata.to_account_info(),
+                &asset.bucket_source_ata.to_account_info(),
+            )
+            .with_signer(withdraw_authority_signer_seeds),
+        swap_amounts.amount_in,
+    )?;
+
+    let bucket_bump = accounts.bucket.load()?.bump;
+    let bucket_signer_seeds: &[&[&[u8]]] = &[&[
+        BUCKET_SEED.as_bytes(),
+        accounts.crate_token.key.as_ref(),
+        &[bucket_bump],
+    ]];
+
+    // first leg: token_a → intermediate
+    let intermediate_amount_before_swap = asset.bucket_intermediate_ata.amount;
+    venue::swap(
+        accounts,
+        &accounts.swap_pair,
+        &accounts.swap_program,
+        &asset.bucket_source_ata.to_account_info(),
+        &asset.bucket_intermediate_ata.to_account_info(),
+        first_venue_accounts,
+        swap_amounts.amount_in,
+        minimum_intermediate_amount_out,
+        bucket_signer_seeds,
+    )?;
+
+    asset.bucket_intermediate_ata.reload()?;
+    let intermediate_amount = unwrap_int!(asset
+        .bucket_intermediate_ata
+        .amount
+        .checked_sub(intermediate_amount_before_swap));
+
+    // second leg: intermediate → token_b. swap exactly what the first leg produced, so that the
+    // bucket does not keep any intermediate tokens.
+    let dest_amount_before_swap = asset.bucket_dest_ata.amount;
+    venue::swap(
+        accounts,
+        &asset.second_swap_pair,
+        &asset.second_swap_program.to_account_info(),
+        &asset.bucket_intermediate_ata.to_account_info(),
+        &asset.bucket_dest_ata.to_account_info(),
+        second_venue_accounts,
+        intermediate_amount,
+        swap_amounts.amount_out,
+        bucket_signer_seeds,
+    )?;
+
+    asset.bucket_dest_ata.reload()?;
+    let amount_out = unwrap_int!(asset
+        .bucket_dest_ata
+        .amount
+        .checked_sub(dest_amount_before_swap));
+
+    transfer(
+        accounts
+            .into_transfer_token_context(
+                &asset.bucket_dest_ata.to_account_info(),
+                &asset.crate_dest_ata.to_account_info(),
+            )
+            .with_signer(bucket_signer_seeds),
+        amount_out,
+    )?;
+    verify_swap_value_kept(
+        swap_amounts.amount_in,
+        source,
+        amount_out,
+        dest,
+        max_slippage_bps,
+    )?;
+    verify_rebalance_reduced_deviation(
+        &collateral_values,
+        asset.token_a.key(),
+        source,
+        swap_amounts.amount_in,
+        asset.token_b.key(),
+        amount_out,
+    )?;
+
+    record_rebalance_turnover(
+        accounts,
+        &collateral_values,
+        source,
+        swap_amounts.amount_in,
+        clock.slot,
+    )?;
+    record_rebalance_stats(
+        valuation_accounts,
+        &collateral_values,
+        asset.token_a.key(),
+        swap_amounts.amount_in,
+        asset.token_b.key(),
+        amount_out,
+        clock.slot,
+    )?;
+    accounts.bucket_stats.record_activity(clock.slot);
+
+    emit!(Rebalanced {
+        version: EVENT_VERSION,
+        bucket,
+        caller: accounts.payer.key(),
+        mint_in: asset.token_a.key(),
+        mint_out: asset.token_b.key(),
+        amount_in: swap_amounts.amount_in,
+        amount_out,
+        pools: vec![accounts.swap_pair.swap, asset.second_swap_pair.swap],
+        keeper_bounty: 0,
+    });
+
+    Ok(())
+}
+
+/// both legs must use swap pairs the authority whitelisted for this bucket. decimal wrappers are
+/// not supported on two hop routes, so every mint on the route must share the same decimals.
+fn verify_two_hop_asset<'info>(
+    accounts: &Rebalance<'info>,
+    asset: &TwoHopRebalanceAsset<'info>,
+) -> ProgramResult {
+    invariant!(
+        asset.second_swap_pair.bucket == accounts.bucket.key()
+            && accounts
+                .swap_pair
+                .matches(asset.token_a.key(), asset.token_intermediate.key())
+            && asset
+                .second_swap_pair
+                .matches(asset.token_intermediate.key(), asset.token_b.key()),
+        ErrorCode::SwapPairMismatch
+    );
+
+    invariant!(
+        asset.token_a.decimals == asset.token_intermediate.decimals
+            && asset.token_intermediate.decimals == asset.token_b.decimals,
+        ErrorCode::DecimalWrapperRequired
+    );
+
+    // the asset is parsed from the remaining accounts, so its token accounts cannot be pinned to
+    // the crate and bucket by anchor constraints, see swap_route_from_account_infos.
+    invariant!(
+        asset.crate_source_ata.owner == accounts.crate_token.key()
+            && asset.crate_dest_ata.owner == accounts.crate_token.key()
+            && asset.bucket_source_ata.owner == accounts.bucket.key()
+            && asset.bucket_intermediate_ata.owner == accounts.bucket.key()
+            && asset.bucket_dest_ata.owner == accounts.bucket.key(),
+        ErrorCode::InvalidRebalanceTokenAccounts
+    );
+
+    Ok(())
+}
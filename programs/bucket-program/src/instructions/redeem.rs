@@ -1,14 +1,18 @@
 use {
     crate::{
-        constant::WITHDRAW_SEED,
+        constant::{EVENT_VERSION, PAUSE_REDEEMS, WITHDRAW_SEED},
         context::{Redeem, RedeemAsset},
         error::ErrorCode,
-        util::is_collateral_authorized
+        event::{Redeemed, RedeemedShare},
+        math::{
+            casting::cast,
+            decimal::{mul_div, Rounding},
+        },
+        util::is_collateral_authorized,
     },
     anchor_lang::{prelude::*, solana_program::account_info::next_account_infos},
     anchor_spl::token::burn,
     crate_token::cpi::withdraw,
-    num_traits::cast::ToPrimitive,
     vipers::{invariant, unwrap_int},
 };
 
@@ -16,29 +20,24 @@ pub fn handle<'info>(
     ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
     redeem_amount: u64,
 ) -> ProgramResult {
-    require!(
-        ctx.accounts.common.bucket.crate_mint.key() == ctx.accounts.withdrawer_reserve.mint.key(),
-        ErrorCode::WrongBurnError
-    );
+    verify_redeem(ctx.accounts)?;
 
     burn(
         ctx.accounts.into_burn_reserve_token_context(),
         redeem_amount,
     )?;
+    let slot = Clock::get()?.slot;
+    ctx.accounts.bucket_stats.record_burn(redeem_amount, slot)?;
 
-    // remaining accounts are ATAs to assist in the collateral fanout distribution
+    // remaining accounts are ATAs and collateral configs to assist in the collateral fanout
+    // distribution
     let num_remaining_accounts = ctx.remaining_accounts.len();
 
     invariant!(
-        num_remaining_accounts % 5 == 0,
-        "must have even number of tokens"
+        num_remaining_accounts % ACCOUNTS_PER_REDEEM_ASSET == 0,
+        ErrorCode::InvalidRedeemAccountCount
     );
 
-    // prematurely return if there is no collateral to distribute
-    if num_remaining_accounts == 0 {
-        return Ok(());
-    }
-
     let remaining_accounts_iter = &mut ctx.remaining_accounts.iter();
     let bucket = ctx.accounts.common.bucket.key();
     let withdraw_authority_signer_seeds: &[&[&[u8]]] = &[&[
@@ -47,30 +46,32 @@ pub fn handle<'info>(
         &[ctx.accounts.withdraw_authority.bump],
     ]];
 
-    let num_tokens = unwrap_int!(num_remaining_accounts.checked_div(5));
+    let num_tokens = unwrap_int!(num_remaining_accounts.checked_div(ACCOUNTS_PER_REDEEM_ASSET));
+    let mut shares: Vec<RedeemedShare> = Vec::with_capacity(num_tokens);
     for _i in 0..num_tokens {
-        // none of these accounts need to be validated further, since
-        // [crate_token::cpi::withdraw] already handles it.
-        let asset: RedeemAsset = Accounts::try_accounts(
+        // apart from the collateral config, none of these accounts need to be validated further,
+        // since [crate_token::cpi::withdraw] already handles it.
+        let mut asset: RedeemAsset = Accounts::try_accounts(
             &crate::ID,
-            &mut next_account_infos(remaining_accounts_iter, 5)?,
+            &mut next_account_infos(remaining_accounts_iter, ACCOUNTS_PER_REDEEM_ASSET)?,
             &[],
         )?;
+        verify_redeem_asset(ctx.accounts, &asset)?;
 
-        // prevent signer from redeeming mints that are not authorized
-        invariant!(
-            is_collateral_authorized(&ctx.accounts.common.bucket.collateral, asset.collateral_mint.key()),
-            ErrorCode::CollateralDoesNotExistError
-        );
+        let share = compute_redeem_share(
+            asset.crate_collateral.amount,
+            redeem_amount,
+            ctx.accounts.common.crate_mint.supply,
+        )?;
 
-        // compute an equal share of each collateral based on each's supply. over time,
-        // this piece of logic will become increasingly complex to account for select
-        // token fanouts and varying prices of the collateral.
-        let share: u64 = unwrap_int!((asset.crate_collateral.amount as i128)
-            .checked_mul(redeem_amount as i128)
-            .and_then(|num| num.checked_div(ctx.accounts.common.crate_mint.supply.into()))
-            .and_then(|num| num.to_u64()));
+        // accounts parsed from remaining accounts are not written back automatically
+        asset.collateral_config.record_redeem(share, slot)?;
+        asset.collateral_config.exit(&crate::ID)?;
 
+        shares.push(RedeemedShare {
+            mint: asset.collateral_mint.key(),
+            amount: share,
+        });
         withdraw(
             ctx.accounts
                 .into_withdraw_collateral_context(asset)
@@ -79,5 +80,66 @@ pub fn handle<'info>(
         )?;
     }
 
+    emit!(Redeemed {
+        version: EVENT_VERSION,
+        bucket,
+        withdrawer: ctx.accounts.withdrawer.key(),
+        reserve_burned: redeem_amount,
+        shares,
+    });
+
+    Ok(())
+}
+
+/// make sure the bucket accepts redeems of the withdrawer's reserve tokens
+pub fn verify_redeem(accounts: &Redeem) -> ProgramResult {
+    let bucket = accounts.common.bucket.load()?;
+    bucket.require_not_paused(PAUSE_REDEEMS, None)?;
+
+    require!(
+        bucket.crate_mint == accounts.withdrawer_reserve.mint,
+        ErrorCode::WrongBurnError
+    );
+
     Ok(())
 }
+
+/// make sure a [RedeemAsset] parsed from the remaining accounts may be paid out
+pub fn verify_redeem_asset(accounts: &Redeem, asset: &RedeemAsset) -> ProgramResult {
+    let bucket = accounts.common.bucket.load()?;
+    invariant!(
+        asset.collateral_config.bucket == accounts.common.bucket.key()
+            && asset.collateral_config.mint == asset.collateral_mint.key(),
+        ErrorCode::InvalidCollateralConfig
+    );
+
+    // prevent signer from redeeming mints that are not authorized
+    invariant!(
+        is_collateral_authorized(bucket.collateral(), asset.collateral_mint.key()),
+        ErrorCode::CollateralDoesNotExistError
+    );
+    bucket.require_not_paused(PAUSE_REDEEMS, Some(asset.collateral_mint.key()))?;
+
+    asset.collateral_config.verify_redeem()
+}
+
+/// compute an equal share of each collateral based on each's supply. over time, this piece of
+/// logic will become increasingly complex to account for select token fanouts and varying prices
+/// of the collateral. the share is paid out, so it is rounded down.
+pub fn compute_redeem_share(
+    crate_amount: u64,
+    redeem_amount: u64,
+    reserve_supply: u64,
+) -> Result<u64, ProgramError> {
+    let share = mul_div(
+        crate_amount as u128,
+        redeem_amount as u128,
+        reserve_supply as u128,
+        Rounding::Down,
+    )?;
+
+    Ok(cast(share)?)
+}
+
+/// number of accounts needed to parse a single [RedeemAsset]
+pub const ACCOUNTS_PER_REDEEM_ASSET: usize = 6;
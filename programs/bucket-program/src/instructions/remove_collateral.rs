@@ -1,7 +1,27 @@
-use {crate::context::AuthorizedUpdate, anchor_lang::prelude::*};
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::AuthorizedUpdate,
+        event::{AllocationsSet, CollateralRemoved},
+    },
+    anchor_lang::prelude::*,
+};
 
 pub fn handle(ctx: Context<AuthorizedUpdate>, mint: Pubkey) -> ProgramResult {
-    ctx.accounts.bucket.remove_collateral(mint)?;
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    bucket.remove_collateral(mint)?;
+
+    emit!(CollateralRemoved {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint,
+    });
+    emit!(AllocationsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        allocations: bucket.collateral().to_vec(),
+    });
 
     Ok(())
 }
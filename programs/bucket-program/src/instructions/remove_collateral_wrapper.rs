@@ -0,0 +1,29 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::RemoveCollateralWrapper, event::CollateralWrapperRemoved,
+        state::bucket::ChildAccount,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<RemoveCollateralWrapper>) -> ProgramResult {
+    ctx.accounts
+        .bucket
+        .load_mut()?
+        .record_child_account_closed(ChildAccount::CollateralWrapper)?;
+
+    let collateral_wrapper = &ctx.accounts.collateral_wrapper;
+    msg!(
+        "removing decimal wrapper {} for collateral {}",
+        collateral_wrapper.wrapper_mint,
+        collateral_wrapper.mint
+    );
+    emit!(CollateralWrapperRemoved {
+        version: EVENT_VERSION,
+        bucket: collateral_wrapper.bucket,
+        mint: collateral_wrapper.mint,
+        wrapper_mint: collateral_wrapper.wrapper_mint,
+    });
+
+    Ok(())
+}
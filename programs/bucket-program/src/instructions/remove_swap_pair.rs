@@ -0,0 +1,28 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::RemoveSwapPair, event::SwapPairRemoved,
+        state::bucket::ChildAccount,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<RemoveSwapPair>) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    bucket.record_child_account_closed(ChildAccount::SwapPair)?;
+
+    let swap_pair = &ctx.accounts.swap_pair;
+    msg!(
+        "removing swap pair {} <> {}",
+        swap_pair.mint_a,
+        swap_pair.mint_b
+    );
+    emit!(SwapPairRemoved {
+        version: EVENT_VERSION,
+        bucket: swap_pair.bucket,
+        mint_a: swap_pair.mint_a,
+        mint_b: swap_pair.mint_b,
+    });
+
+    Ok(())
+}
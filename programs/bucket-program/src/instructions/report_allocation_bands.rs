@@ -0,0 +1,52 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::ReportAllocationBands, event::AllocationBandsReported,
+        state::allocation_ramp::load_target_collateral, util::collateral_values_from_account_infos,
+    },
+    anchor_lang::{prelude::*, solana_program::program::set_return_data},
+};
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, ReportAllocationBands<'info>>,
+) -> ProgramResult {
+    let slot = Clock::get()?.slot;
+    let collateral_values = collateral_values_from_account_infos(
+        ctx.remaining_accounts,
+        &load_target_collateral(
+            &ctx.accounts.bucket.load()?,
+            &ctx.accounts.allocation_ramp,
+            slot,
+        )?,
+        &ctx.accounts.bucket.key(),
+        ctx.accounts.crate_token.key,
+        slot,
+    )?;
+
+    // return the indices, rather than the mints, of out of band collateral so that the report
+    // fits in the return data limit for any number of collateral
+    let mut out_of_band: Vec<u16> = Vec::new();
+    for (idx, el) in collateral_values.iter().enumerate() {
+        if el.is_out_of_band() {
+            msg!(
+                "collateral {} is out of band: value = {}, band = [{}, {}]",
+                el.mint,
+                el.value,
+                el.min_value,
+                el.max_value
+            );
+            out_of_band.push(idx as u16);
+        }
+    }
+
+    emit!(AllocationBandsReported {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        out_of_band: out_of_band
+            .iter()
+            .map(|idx| collateral_values[*idx as usize].mint)
+            .collect(),
+    });
+    set_return_data(&out_of_band.try_to_vec()?);
+
+    Ok(())
+}
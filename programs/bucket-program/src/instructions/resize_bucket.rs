@@ -0,0 +1,62 @@
+use {
+    crate::{
+        constant::{BUCKET_SEED, EVENT_VERSION},
+        context::ResizeBucket,
+        error::ErrorCode,
+        event::BucketResized,
+        state::bucket::BUCKET_ACCOUNT_SPACE,
+        util::{create_pda_account, resize_account},
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{entrypoint::MAX_PERMITTED_DATA_INCREASE, system_program},
+    },
+    std::cmp::min,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<ResizeBucket>, bucket_bump: u8) -> ProgramResult {
+    let bucket_info = ctx.accounts.bucket.to_account_info();
+    let current_len = bucket_info.data_len();
+    invariant!(
+        current_len < BUCKET_ACCOUNT_SPACE,
+        ErrorCode::BucketAlreadySized
+    );
+
+    // an account can only grow by MAX_PERMITTED_DATA_INCREASE per instruction, and an account
+    // created through a CPI is limited to the same size.
+    let new_len = min(
+        current_len.saturating_add(MAX_PERMITTED_DATA_INCREASE),
+        BUCKET_ACCOUNT_SPACE,
+    );
+
+    if *bucket_info.owner == system_program::ID {
+        let crate_token = ctx.accounts.crate_token.key();
+        create_pda_account(
+            &bucket_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_len,
+            &[BUCKET_SEED.as_bytes(), crate_token.as_ref(), &[bucket_bump]],
+        )?;
+    } else {
+        invariant!(
+            *bucket_info.owner == crate::ID,
+            ErrorCode::InvalidBucketAccount
+        );
+        resize_account(
+            &bucket_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            new_len,
+        )?;
+    }
+
+    emit!(BucketResized {
+        version: EVENT_VERSION,
+        bucket: bucket_info.key(),
+        space: new_len as u64,
+    });
+
+    Ok(())
+}
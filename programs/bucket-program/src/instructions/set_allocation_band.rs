@@ -0,0 +1,23 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::UpdateCollateralConfig, event::AllocationBandSet,
+        state::collateral_config::AllocationBand,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<UpdateCollateralConfig>, band: AllocationBand) -> ProgramResult {
+    ctx.accounts.bucket.load()?.require_no_timelock()?;
+
+    let collateral_config = &mut ctx.accounts.collateral_config;
+    collateral_config.set_band(band)?;
+
+    emit!(AllocationBandSet {
+        version: EVENT_VERSION,
+        bucket: collateral_config.bucket,
+        mint: collateral_config.mint,
+        band,
+    });
+
+    Ok(())
+}
@@ -1,12 +1,21 @@
 use {
-    crate::{context::AuthorizedUpdate, state::bucket::Collateral},
+    crate::{
+        constant::EVENT_VERSION, context::AuthorizedUpdate, event::AllocationsSet,
+        state::bucket::Collateral,
+    },
     anchor_lang::prelude::*,
 };
 
 pub fn handle(ctx: Context<AuthorizedUpdate>, allocations: Vec<Collateral>) -> ProgramResult {
-    ctx.accounts
-        .bucket
-        .set_collateral_allocations(&allocations)?;
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    bucket.set_collateral_allocations(&allocations)?;
+
+    emit!(AllocationsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        allocations: bucket.collateral().to_vec(),
+    });
 
     Ok(())
 }
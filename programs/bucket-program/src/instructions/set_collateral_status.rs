@@ -0,0 +1,26 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::UpdateCollateralConfig, event::CollateralStatusSet,
+        state::collateral_config::CollateralStatus,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<UpdateCollateralConfig>, status: CollateralStatus) -> ProgramResult {
+    msg!(
+        "setting status of collateral {} to {:?}",
+        ctx.accounts.collateral_config.mint,
+        status
+    );
+
+    ctx.accounts.collateral_config.status = status;
+
+    emit!(CollateralStatusSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.collateral_config.bucket,
+        mint: ctx.accounts.collateral_config.mint,
+        status,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use {
+    crate::{constant::EVENT_VERSION, context::SetGuardian, error::ErrorCode, event::GuardianSet},
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<SetGuardian>, guardian: Pubkey) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    let setter = ctx.accounts.setter.key();
+
+    // the guardian can always hand its role over or give it up. the authority can only replace
+    // the guardian through a proposal while the timelock is enabled, since the guardian is what
+    // lets holders cancel the authority's proposals.
+    let setter_is_guardian = bucket.guardian != Pubkey::default() && setter == bucket.guardian;
+    if !setter_is_guardian {
+        invariant!(
+            setter == bucket.authority,
+            ErrorCode::UnauthorizedGuardianUpdate
+        );
+        bucket.require_no_timelock()?;
+    }
+    bucket.set_guardian(guardian)?;
+
+    emit!(GuardianSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        guardian,
+        setter,
+    });
+
+    Ok(())
+}
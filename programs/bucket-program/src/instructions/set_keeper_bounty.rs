@@ -0,0 +1,21 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::AuthorizedUpdate, event::KeeperBountySet,
+        state::bucket::KeeperBounty,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>, keeper_bounty: KeeperBounty) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    bucket.set_keeper_bounty(keeper_bounty)?;
+
+    emit!(KeeperBountySet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        keeper_bounty,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::SetRebalanceLimits, event::RebalanceLimitsSet,
+        state::rebalance_state::RebalanceLimits,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<SetRebalanceLimits>, limits: RebalanceLimits) -> ProgramResult {
+    ctx.accounts.rebalance_state.set_limits(limits)?;
+
+    emit!(RebalanceLimitsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        limits,
+    });
+
+    Ok(())
+}
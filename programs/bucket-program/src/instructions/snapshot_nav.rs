@@ -0,0 +1,114 @@
+use {
+    crate::{
+        constant::{EVENT_VERSION, MAX_ALLOCATION_PPM, MAX_BASIS_POINTS, TARGET_ORACLE_PRECISION},
+        context::SnapshotNav,
+        event::{CollateralWeight, NavSnapshotTaken},
+        math::{
+            casting::cast,
+            decimal::{mul_div, Decimal, Rounding},
+        },
+        state::{allocation_ramp::load_target_collateral, nav_snapshot::Nav},
+        util::{collateral_values_from_account_infos, sum_collateral_values},
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle<'info>(
+    ctx: Context<'_, '_, '_, 'info, SnapshotNav<'info>>,
+    nav_snapshot_bump: u8,
+) -> ProgramResult {
+    let bucket = ctx.accounts.bucket.key();
+    if ctx.accounts.nav_snapshot.bucket == Pubkey::default() {
+        ctx.accounts.nav_snapshot.init(nav_snapshot_bump, bucket);
+    }
+
+    let clock = Clock::get()?;
+    let collateral = load_target_collateral(
+        &ctx.accounts.bucket.load()?,
+        &ctx.accounts.allocation_ramp,
+        clock.slot,
+    )?;
+    let collateral_values = collateral_values_from_account_infos(
+        ctx.remaining_accounts,
+        &collateral,
+        &bucket,
+        ctx.accounts.crate_token.key,
+        clock.slot,
+    )?;
+    let total_value = sum_collateral_values(&collateral_values)?;
+
+    let mut weights: Vec<CollateralWeight> = Vec::with_capacity(collateral_values.len());
+    for (el, target) in collateral_values.iter().zip(collateral.iter()) {
+        let weight = if total_value == 0 {
+            0
+        } else {
+            cast(mul_div(
+                el.value,
+                MAX_ALLOCATION_PPM as u128,
+                total_value,
+                Rounding::Down,
+            )?)?
+        };
+        weights.push(CollateralWeight {
+            mint: el.mint,
+            weight,
+            target_weight: target.allocation,
+        });
+    }
+
+    // the snapshot may be consumed as a price for the reserve token, so the NAV is rounded down
+    let reserve_supply = ctx.accounts.crate_mint.supply;
+    let (nav_per_token, collateralization_bps) = if reserve_supply == 0 {
+        (0, 0)
+    } else {
+        let nav = Decimal::from_scaled(total_value, TARGET_ORACLE_PRECISION, Rounding::Down)?
+            .checked_div(
+                Decimal::from_scaled(
+                    reserve_supply as u128,
+                    ctx.accounts.crate_mint.decimals as u32,
+                    Rounding::Up,
+                )?,
+                Rounding::Down,
+            )?;
+        let collateralization_bps = nav
+            .checked_mul(
+                Decimal::from_scaled(MAX_BASIS_POINTS as u128, 0, Rounding::Down)?,
+                Rounding::Down,
+            )?
+            .to_scaled(0, Rounding::Down)?;
+
+        (
+            nav.to_scaled(TARGET_ORACLE_PRECISION, Rounding::Down)?,
+            cast(collateralization_bps)?,
+        )
+    };
+
+    msg!(
+        "bucket value = {}, NAV per token = {}, collateralization = {} bps",
+        total_value,
+        nav_per_token,
+        collateralization_bps
+    );
+
+    ctx.accounts.nav_snapshot.record(Nav {
+        slot: clock.slot,
+        unix_timestamp: clock.unix_timestamp,
+        total_value,
+        reserve_supply,
+        nav_per_token,
+        collateralization_bps,
+    })?;
+
+    emit!(NavSnapshotTaken {
+        version: EVENT_VERSION,
+        bucket,
+        slot: clock.slot,
+        total_value,
+        reserve_supply,
+        nav_per_token,
+        collateralization_bps,
+        weights,
+    });
+
+    Ok(())
+}
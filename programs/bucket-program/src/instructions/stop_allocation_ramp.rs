@@ -0,0 +1,38 @@
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::StopAllocationRamp,
+        error::ErrorCode,
+        event::{AllocationRampStopped, AllocationsSet},
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+pub fn handle(ctx: Context<StopAllocationRamp>) -> ProgramResult {
+    let slot = Clock::get()?.slot;
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    let ramp = &mut ctx.accounts.allocation_ramp;
+    invariant!(
+        ramp.is_active(bucket.collateral(), slot),
+        ErrorCode::NoActiveAllocationRamp
+    );
+
+    // the allocations in effect at this slot become the bucket's allocations
+    let allocations = ramp.effective_collateral(bucket.collateral(), slot)?;
+    bucket.set_collateral_allocations(&allocations)?;
+    ramp.stop(slot);
+
+    emit!(AllocationRampStopped {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        slot,
+    });
+    emit!(AllocationsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        allocations: bucket.collateral().to_vec(),
+    });
+
+    Ok(())
+}
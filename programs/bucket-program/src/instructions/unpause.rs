@@ -0,0 +1,21 @@
+use {
+    crate::{constant::EVENT_VERSION, context::AuthorizedUpdate, event::PauseFlagsSet},
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>, flags: u8, mint: Option<Pubkey>) -> ProgramResult {
+    ctx.accounts
+        .bucket
+        .load_mut()?
+        .set_pause_flags(flags, mint, false)?;
+
+    emit!(PauseFlagsSet {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        mint,
+        flags,
+        paused: false,
+    });
+
+    Ok(())
+}
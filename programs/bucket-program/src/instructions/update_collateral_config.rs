@@ -0,0 +1,36 @@
+use {
+    crate::{
+        constant::EVENT_VERSION,
+        context::UpdateCollateralConfig,
+        event::CollateralConfigUpdated,
+        state::{
+            collateral_config::{CollateralCaps, FeeOverrides},
+            oracle::OracleSource,
+        },
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(
+    ctx: Context<UpdateCollateralConfig>,
+    oracle_source: OracleSource,
+    oracle: Pubkey,
+    caps: CollateralCaps,
+    fee_overrides: FeeOverrides,
+) -> ProgramResult {
+    ctx.accounts.bucket.load()?.require_no_timelock()?;
+
+    ctx.accounts
+        .collateral_config
+        .update(oracle_source, oracle, caps, fee_overrides)?;
+
+    emit!(CollateralConfigUpdated {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.collateral_config.bucket,
+        mint: ctx.accounts.collateral_config.mint,
+        oracle_source,
+        oracle,
+    });
+
+    Ok(())
+}
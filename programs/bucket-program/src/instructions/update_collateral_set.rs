@@ -0,0 +1,23 @@
+use {
+    crate::{
+        constant::EVENT_VERSION, context::AuthorizedUpdate, event::CollateralSetUpdated,
+        state::bucket::Collateral,
+    },
+    anchor_lang::prelude::*,
+};
+
+pub fn handle(ctx: Context<AuthorizedUpdate>, collateral: Vec<Collateral>) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    let diff = bucket.update_collateral_set(&collateral)?;
+
+    emit!(CollateralSetUpdated {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        added: diff.added,
+        removed: diff.removed,
+        reallocated: diff.reallocated,
+    });
+
+    Ok(())
+}
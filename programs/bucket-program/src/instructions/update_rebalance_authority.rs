@@ -1,15 +1,20 @@
 use {
-    crate::context::AuthorizedUpdate,
+    crate::{constant::EVENT_VERSION, context::AuthorizedUpdate, event::RebalanceAuthorityUpdated},
     anchor_lang::prelude::*,
 };
 
-pub fn handle(
-	ctx: Context<AuthorizedUpdate>,
-	rebalance_authority: Pubkey
-) -> ProgramResult {
-    ctx.accounts
-        .bucket
-        .update_rebalance_authority(rebalance_authority)?;
+pub fn handle(ctx: Context<AuthorizedUpdate>, rebalance_authority: Pubkey) -> ProgramResult {
+    let mut bucket = ctx.accounts.bucket.load_mut()?;
+    bucket.require_no_timelock()?;
+    let previous_rebalance_authority = bucket.rebalance_authority;
+    bucket.update_rebalance_authority(rebalance_authority)?;
+
+    emit!(RebalanceAuthorityUpdated {
+        version: EVENT_VERSION,
+        bucket: ctx.accounts.bucket.key(),
+        previous_rebalance_authority,
+        rebalance_authority,
+    });
 
     Ok(())
 }
@@ -3,12 +3,21 @@ use anchor_lang::prelude::*;
 mod constant;
 mod context;
 mod error;
+mod event;
 mod instructions;
 mod math;
 mod state;
 mod util;
+mod venue;
 use context::*;
-use state::bucket::Collateral;
+use state::{
+    bucket::{Collateral, KeeperBounty},
+    collateral_config::{AllocationBand, CollateralCaps, CollateralStatus, FeeOverrides},
+    oracle::OracleSource,
+    proposal::AdminAction,
+    rebalance_state::RebalanceLimits,
+    swap_pair::SwapVenue,
+};
 
 declare_id!("9tFeTGcc6saCgvZqQbqKq76vqgbJsoavjFiMDKRo7v9c");
 
@@ -17,12 +26,15 @@ pub mod bucket_program {
     use super::*;
 
     /// this instruction initializes the bucket PDA required for the rest of the instructions in this file.
+    /// the bucket account must already exist, see resize_bucket.
     pub fn create_bucket(
         ctx: Context<CreateBucket>,
         bucket_bump: u8,
         crate_bump: u8,
         issue_authority_bump: u8,
         withdraw_authority_bump: u8,
+        rebalance_state_bump: u8,
+        bucket_stats_bump: u8,
     ) -> ProgramResult {
         instructions::create_bucket::handle(
             ctx,
@@ -30,6 +42,8 @@ pub mod bucket_program {
             crate_bump,
             issue_authority_bump,
             withdraw_authority_bump,
+            rebalance_state_bump,
+            bucket_stats_bump,
         )?;
 
         Ok(())
@@ -49,17 +63,108 @@ pub mod bucket_program {
         Ok(())
     }
 
+    /// this instruction proposes a new bucket authority. the bucket authority does not change until
+    /// the proposed authority accepts, which prevents handing the bucket over to an address that
+    /// cannot sign, e.g. a mistyped multisig. proposing again replaces the pending proposal.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn propose_authority(
+        ctx: Context<AuthorizedUpdate>,
+        pending_authority: Pubkey,
+    ) -> ProgramResult {
+        instructions::propose_authority::handle(ctx, pending_authority)?;
+
+        Ok(())
+    }
+
+    /// this instruction cancels a pending authority transfer.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn cancel_authority_transfer(ctx: Context<AuthorizedUpdate>) -> ProgramResult {
+        instructions::cancel_authority_transfer::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction completes a pending authority transfer. the signer becomes the bucket's
+    /// authority. the rebalance authority is left unchanged.
+    ///
+    /// instruction privilege: only the pending authority can call this instruction
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> ProgramResult {
+        instructions::accept_authority::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction grows the bucket PDA toward BUCKET_ACCOUNT_SPACE by at most 10KB, creating
+    /// it first if it does not exist. the bucket is larger than an account created through a CPI can
+    /// be, so new buckets call this right before create_bucket, and buckets at an older layout call
+    /// it before migrate_bucket as needed. the payer covers the extra rent.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn resize_bucket(ctx: Context<ResizeBucket>, bucket_bump: u8) -> ProgramResult {
+        instructions::resize_bucket::handle(ctx, bucket_bump)?;
+
+        Ok(())
+    }
+
+    /// this instruction upgrades a bucket account created before the version byte was introduced to
+    /// the current zero-copy layout, resizing the account to the fixed bucket size. fields that did
+    /// not exist in the old layout are set to their defaults, collateral is sorted by mint and
+    /// allocations are converted from bps to parts per million. it also creates the rebalance state
+    /// and bucket stats PDAs, which older buckets do not have. the account can grow by at most 10KB
+    /// here, so small buckets are grown with resize_bucket first.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn migrate_bucket(
+        ctx: Context<MigrateBucket>,
+        rebalance_state_bump: u8,
+        bucket_stats_bump: u8,
+    ) -> ProgramResult {
+        instructions::migrate_bucket::handle(ctx, rebalance_state_bump, bucket_stats_bump)?;
+
+        Ok(())
+    }
+
+    /// this instruction starts winding down a bucket. deposits are disabled permanently, while
+    /// redeems and rebalances keep working so that holders can exit. once every reserve token is
+    /// redeemed, the authority can call close_bucket.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn decommission_bucket(ctx: Context<AuthorizedUpdate>) -> ProgramResult {
+        instructions::decommission_bucket::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction closes a decommissioned bucket along with its issue authority, withdraw
+    /// authority, rebalance state, bucket stats, NAV snapshot and allocation ramp PDAs, returning
+    /// their rent to the authority. the reserve supply must be zero and the remaining accounts must
+    /// be the crate's empty ATA and the collateral config of each authorized collateral, which is
+    /// closed as well. swap pairs, collateral wrappers and the configs of collateral that is no
+    /// longer authorized must be removed before, since they can no longer be removed afterwards.
+    /// any later instruction that loads the closed bucket fails with anchor's AccountNotInitialized
+    /// error.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn close_bucket<'info>(
+        ctx: Context<'_, '_, '_, 'info, CloseBucket<'info>>,
+    ) -> ProgramResult {
+        instructions::close_bucket::handle(ctx)?;
+
+        Ok(())
+    }
+
     /// this instruction authorizes a new collateral mint for a given bucket. after this operation,
     /// anyone will be able to deposit tokens of this mint and receive the relative amount of
-    /// reserve in return. due to the nature of integer math, it's possible the actual allocation
-    /// of this mint will be off by a few basis points. in that case, the bucket authority can call
-    /// the set_collateral_allocations instruction to absolutely set allocations.
+    /// reserve in return. allocations are in parts per million. the new mint gets exactly the given
+    /// allocation, and the existing collateral is scaled down proportionally to make room for it.
     ///
     /// instruction privilege: only bucket authority can call this instruction
     pub fn authorize_collateral(
         ctx: Context<AuthorizedUpdate>,
         mint: Pubkey,
-        allocation: u16,
+        allocation: u32,
     ) -> ProgramResult {
         instructions::authorize_collateral::handle(ctx, mint, allocation)?;
 
@@ -85,9 +190,10 @@ pub mod bucket_program {
     }
 
     /// this instruction absolutely sets per-collateral allocations, e.g. the authority wants
-    /// to set mintA to 60%, mintB to 30%, and mintC to 10%. this instruction does not allow
-    /// the authority to add or remove collateral. bulk modify operations are error prone, and
-    /// we prefer to reduce the possibility for a risky change.
+    /// to set mintA to 60%, mintB to 30%, and mintC to 10%, i.e. 600_000, 300_000 and 100_000 parts
+    /// per million. this instruction does not allow the authority to add or remove collateral.
+    /// bulk modify operations are error prone, and we prefer to reduce the possibility for a risky
+    /// change.
     ///
     /// instruction privilege: only bucket authority can call this instruction
     pub fn set_collateral_allocations(
@@ -99,9 +205,349 @@ pub mod bucket_program {
         Ok(())
     }
 
+    /// this instruction replaces the bucket's authorized collateral and their allocations with the
+    /// given set in one step, e.g. to authorize several collateral at once. mints that are not in
+    /// the set are removed, new mints are authorized and the remaining mints take the given
+    /// allocations. the mints must be unique and the allocations must sum to exactly 100%. unlike
+    /// authorize_collateral and remove_collateral, no allocation is redistributed, so the bucket
+    /// ends up with exactly the given set. an event describes what changed.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn update_collateral_set(
+        ctx: Context<AuthorizedUpdate>,
+        collateral: Vec<Collateral>,
+    ) -> ProgramResult {
+        instructions::update_collateral_set::handle(ctx, collateral)?;
+
+        Ok(())
+    }
+
+    /// this instruction moves per-collateral allocations to the given allocations gradually,
+    /// instead of in one step. the target allocations interpolate linearly from the allocations in
+    /// effect now to the given allocations at end_slot. this gives keepers time to rebalance in
+    /// smaller steps. like set_collateral_allocations, the given allocations must cover every
+    /// authorized collateral and sum to 100%.
+    ///
+    /// any other change to the bucket's allocations, e.g. authorizing or removing collateral,
+    /// supersedes the ramp.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn ramp_allocations(
+        ctx: Context<RampAllocations>,
+        bump: u8,
+        allocations: Vec<Collateral>,
+        end_slot: u64,
+    ) -> ProgramResult {
+        instructions::ramp_allocations::handle(ctx, bump, allocations, end_slot)?;
+
+        Ok(())
+    }
+
+    /// this instruction stops an allocation ramp early. the allocations in effect at the current
+    /// slot become the bucket's allocations.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn stop_allocation_ramp(ctx: Context<StopAllocationRamp>) -> ProgramResult {
+        instructions::stop_allocation_ramp::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction configures the bucket's admin timelock. with a non-zero delay, the
+    /// authorize_collateral, remove_collateral, set_collateral_allocations, update_collateral_set
+    /// and update_rebalance_authority instructions are disabled, and the same changes must instead
+    /// be enqueued as proposals that can only be executed after the delay. the guardian can cancel
+    /// queued proposals. once enabled, the timelock itself can only be changed through a proposal.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn configure_timelock(
+        ctx: Context<AuthorizedUpdate>,
+        delay_slots: u64,
+        guardian: Pubkey,
+    ) -> ProgramResult {
+        instructions::configure_timelock::handle(ctx, delay_slots, guardian)?;
+
+        Ok(())
+    }
+
+    /// this instruction sets the bucket's guardian. the current guardian can always hand the role
+    /// over, or give it up by setting the default pubkey. the bucket authority can only set the
+    /// guardian while the timelock is disabled, and must otherwise enqueue a SetGuardian proposal.
+    ///
+    /// instruction privilege: only bucket authority or guardian can call this instruction
+    pub fn set_guardian(ctx: Context<SetGuardian>, guardian: Pubkey) -> ProgramResult {
+        instructions::set_guardian::handle(ctx, guardian)?;
+
+        Ok(())
+    }
+
+    /// this instruction enqueues an admin change in a proposal PDA. the proposal can be executed
+    /// by anyone once the bucket's timelock delay has passed. this gives holders time to exit
+    /// before, e.g., a risky collateral is authorized.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn enqueue_proposal(
+        ctx: Context<EnqueueProposal>,
+        bump: u8,
+        action: AdminAction,
+    ) -> ProgramResult {
+        instructions::enqueue_proposal::handle(ctx, bump, action)?;
+
+        Ok(())
+    }
+
+    /// this instruction applies a queued admin change once its eta slot has passed and returns the
+    /// proposal's rent to the proposer. changes to accounts other than the bucket take them as
+    /// remaining accounts: the collateral config for UpdateCollateralConfig and SetAllocationBand,
+    /// (swap_pair, swap_program, swap, system_program) for AddSwapPair, with the executor paying the
+    /// pair's rent, and the swap pair for RemoveSwapPair.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> ProgramResult {
+        instructions::execute_proposal::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction cancels a queued admin change and returns the proposal's rent to the
+    /// proposer.
+    ///
+    /// instruction privilege: only bucket authority or guardian can call this instruction
+    pub fn cancel_proposal(ctx: Context<CancelProposal>) -> ProgramResult {
+        instructions::cancel_proposal::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction whitelists a collateral pair for rebalancing and records the venue, program and
+    /// pool that swaps between the pair must be routed through, e.g. a saber stable swap. rebalance
+    /// refuses any program or pool that is not registered for the pair, since the bucket signs swaps
+    /// with its PDA seeds. mint_a must be the mint with the lower pubkey, so that a single swap pair
+    /// covers both swap directions.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn add_swap_pair(ctx: Context<AddSwapPair>, bump: u8, venue: SwapVenue) -> ProgramResult {
+        instructions::add_swap_pair::handle(ctx, bump, venue)?;
+
+        Ok(())
+    }
+
+    /// this instruction removes a collateral pair from the rebalance whitelist and returns the swap
+    /// pair account's rent to the authority. to change a pair's venue, remove and re-add the pair.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn remove_swap_pair(ctx: Context<RemoveSwapPair>) -> ProgramResult {
+        instructions::remove_swap_pair::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction records the saber decimal wrapper to use for a collateral mint. saber's stable
+    /// swap invariant ignores decimals, so when rebalancing between collateral with different decimals,
+    /// the collateral with fewer decimals is wrapped up to the other collateral's decimals before the
+    /// swap, or unwrapped after it. the wrapper mint must have more decimals than the collateral.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn add_collateral_wrapper(ctx: Context<AddCollateralWrapper>, bump: u8) -> ProgramResult {
+        instructions::add_collateral_wrapper::handle(ctx, bump)?;
+
+        Ok(())
+    }
+
+    /// this instruction removes a collateral mint's decimal wrapper config and returns the account's
+    /// rent to the authority. rebalances between mixed-decimal collateral involving this mint will fail
+    /// until a new wrapper is added.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn remove_collateral_wrapper(ctx: Context<RemoveCollateralWrapper>) -> ProgramResult {
+        instructions::remove_collateral_wrapper::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction creates the config PDA for a collateral mint. the config records the oracle
+    /// deposits of the mint are priced with, deposit caps and fee overrides. deposits and redeems of
+    /// a mint require its config, so the authority should add one alongside authorize_collateral.
+    /// new configs start out active.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn add_collateral_config(
+        ctx: Context<AddCollateralConfig>,
+        bump: u8,
+        oracle_source: OracleSource,
+        oracle: Pubkey,
+        caps: CollateralCaps,
+        fee_overrides: FeeOverrides,
+    ) -> ProgramResult {
+        instructions::add_collateral_config::handle(
+            ctx,
+            bump,
+            oracle_source,
+            oracle,
+            caps,
+            fee_overrides,
+        )?;
+
+        Ok(())
+    }
+
+    /// this instruction replaces the oracle, caps and fee overrides of a collateral config. the
+    /// config's status and stats are left unchanged.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn update_collateral_config(
+        ctx: Context<UpdateCollateralConfig>,
+        oracle_source: OracleSource,
+        oracle: Pubkey,
+        caps: CollateralCaps,
+        fee_overrides: FeeOverrides,
+    ) -> ProgramResult {
+        instructions::update_collateral_config::handle(
+            ctx,
+            oracle_source,
+            oracle,
+            caps,
+            fee_overrides,
+        )?;
+
+        Ok(())
+    }
+
+    /// this instruction sets the status of a collateral. active collateral can be deposited and
+    /// redeemed, sunsetting collateral can only be redeemed, and frozen collateral can be neither.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn set_collateral_status(
+        ctx: Context<UpdateCollateralConfig>,
+        status: CollateralStatus,
+    ) -> ProgramResult {
+        instructions::set_collateral_status::handle(ctx, status)?;
+
+        Ok(())
+    }
+
+    /// this instruction sets the tolerance band around a collateral's target allocation. rebalances
+    /// between authorized collateral are only permitted when the source is above its band or the
+    /// destination is below its band. optionally, deposits that would push the collateral above its
+    /// band are rejected, in which case deposits must supply the collateral valuation accounts.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn set_allocation_band(
+        ctx: Context<UpdateCollateralConfig>,
+        band: AllocationBand,
+    ) -> ProgramResult {
+        instructions::set_allocation_band::handle(ctx, band)?;
+
+        Ok(())
+    }
+
+    /// this instruction reports which collateral is outside its allocation band. it does not write
+    /// any state. the indices of out of band collateral, in the bucket's collateral order, are
+    /// returned as borsh serialized Vec<u16> return data, so clients can simulate the instruction.
+    ///
+    /// remaining accounts are a (mint, crate ATA, oracle, collateral config) group for each
+    /// authorized collateral, sorted by mint.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn report_allocation_bands<'info>(
+        ctx: Context<'_, '_, '_, 'info, ReportAllocationBands<'info>>,
+    ) -> ProgramResult {
+        instructions::report_allocation_bands::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction values the bucket's collateral at oracle prices and records the bucket's
+    /// total value, NAV per reserve token and collateralization ratio in the bucket's NAV snapshot
+    /// history, which keeps the last NAV_SNAPSHOT_HISTORY_LEN snapshots. the snapshot, along with
+    /// each collateral's actual and target weight, is also emitted as an event. other programs can
+    /// read the latest snapshot as a price for the reserve token. at most one snapshot is taken per
+    /// slot. the first caller pays for the snapshot account.
+    ///
+    /// remaining accounts are a (mint, crate ATA, oracle, collateral config) group for each
+    /// authorized collateral, sorted by mint.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn snapshot_nav<'info>(
+        ctx: Context<'_, '_, '_, 'info, SnapshotNav<'info>>,
+        nav_snapshot_bump: u8,
+    ) -> ProgramResult {
+        instructions::snapshot_nav::handle(ctx, nav_snapshot_bump)?;
+
+        Ok(())
+    }
+
+    /// this instruction closes the config of a collateral that is no longer authorized and returns
+    /// the account's rent to the authority. the crate must not hold any of the collateral, since
+    /// stray collateral can only be cleared out with the config's oracle.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn close_collateral_config(ctx: Context<CloseCollateralConfig>) -> ProgramResult {
+        instructions::close_collateral_config::handle(ctx)?;
+
+        Ok(())
+    }
+
+    /// this instruction configures the bounty paid to keepers for useful rebalances, i.e. any
+    /// rebalance_to_target call and any rebalance that swaps collateral which is no longer authorized
+    /// into authorized collateral. the bounty is a percentage of the swap output, capped at
+    /// max_amount, and is paid either from the swap output or as newly issued reserve tokens.
+    /// setting bps to zero disables the bounty.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn set_keeper_bounty(
+        ctx: Context<AuthorizedUpdate>,
+        keeper_bounty: KeeperBounty,
+    ) -> ProgramResult {
+        instructions::set_keeper_bounty::handle(ctx, keeper_bounty)?;
+
+        Ok(())
+    }
+
+    /// this instruction configures the bucket's rebalance limits, i.e. the minimum number of slots
+    /// between rebalances and the max value that can be swapped in any trailing turnover window, in
+    /// bps of the bucket's total collateral value. these limits apply to every rebalance instruction
+    /// and bound the damage a compromised rebalance authority can do. the rebalance authority's
+    /// cooldown is tracked apart from other callers'.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn set_rebalance_limits(
+        ctx: Context<SetRebalanceLimits>,
+        limits: RebalanceLimits,
+    ) -> ProgramResult {
+        instructions::set_rebalance_limits::handle(ctx, limits)?;
+
+        Ok(())
+    }
+
+    /// this instruction pauses deposits, redeems and/or rebalances, given as PAUSE_* bitflags, for the
+    /// whole bucket or for a single collateral if mint is given. the guardian can pause but can never
+    /// unpause, change allocations or move funds.
+    ///
+    /// instruction privilege: only bucket authority or guardian can call this instruction
+    pub fn pause(ctx: Context<Pause>, flags: u8, mint: Option<Pubkey>) -> ProgramResult {
+        instructions::pause::handle(ctx, flags, mint)?;
+
+        Ok(())
+    }
+
+    /// this instruction clears PAUSE_* bitflags for the whole bucket or for a single collateral if
+    /// mint is given.
+    ///
+    /// instruction privilege: only bucket authority can call this instruction
+    pub fn unpause(
+        ctx: Context<AuthorizedUpdate>,
+        flags: u8,
+        mint: Option<Pubkey>,
+    ) -> ProgramResult {
+        instructions::unpause::handle(ctx, flags, mint)?;
+
+        Ok(())
+    }
+
     /// this instruction will, at the discretion of the rebalance authority, attempt to rebalance the collaterals'
-    /// allocation by using saber stable swaps to move toward the desired target allocation. currently, each rebalance
-    /// instruction is limited to 1 swap at a time.
+    /// allocation by swapping on the venue whitelisted for the collateral pair, e.g. saber stable swaps, to move
+    /// toward the desired target allocation. currently, each rebalance instruction is limited to 1 swap at a time.
     ///
     /// all collateral & reserve tokens are actually in ATAs belonging to the underlying crate PDA — not the bucket.
     /// so, there are a few extra operations before we can perform the actual token swap. steps are as follows:
@@ -110,7 +556,27 @@ pub mod bucket_program {
     /// 2. swap tokens from one bucket ATA to another,
     /// 3. transfer tokens from a bucket ATA into a crate ATA.
     ///
-    /// instruction privilege: only the rebalance authority can invoke this instruction
+    /// if the collateral decimals differ, the collateral with fewer decimals is routed through its saber
+    /// decimal wrapper, i.e. wrapped before step 2 or unwrapped after it.
+    ///
+    /// remaining accounts are the swap route, i.e. the 6 rebalance asset accounts, the 6 decimal
+    /// wrapper accounts if the collateral decimals differ and the swap venue's accounts, followed by
+    /// a (mint, crate ATA, oracle, collateral config) group for each authorized collateral, in the
+    /// same order as the bucket's collateral. the groups value the swap input, which counts toward
+    /// the bucket's rebalance turnover limit, and verify that the rebalance moves out of band
+    /// collateral. see set_rebalance_limits and set_allocation_band. the collateral configs of the
+    /// swapped collateral must be writable, since the swap is recorded in their stats. if the source
+    /// collateral is no longer authorized, its (oracle, collateral config) follow the valuation
+    /// groups.
+    ///
+    /// the rebalance authority sizes the swap, but it must return at least the oracle value of
+    /// amount_in less MAX_AUTHORITY_SLIPPAGE_BPS. callers other than the rebalance authority can only
+    /// swap collateral that is no longer authorized into authorized collateral, and can append the 3
+    /// keeper bounty accounts. their amounts are ignored: the crate's whole source balance is swapped
+    /// for at least its oracle value less MAX_PERMISSIONLESS_SLIPPAGE_BPS. either way, the swap must
+    /// strictly reduce the bucket's total deviation from its target allocation.
+    ///
+    /// instruction privilege: anyone can call this instruction, but swap rules depend on the caller
     pub fn rebalance<'info>(
         ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
         amount_in: u64,
@@ -121,6 +587,60 @@ pub mod bucket_program {
         Ok(())
     }
 
+    /// this instruction computes the swap needed to move the bucket toward its target allocation
+    /// instead of accepting amounts from the caller. it values every authorized collateral with its
+    /// oracle, picks the most overweight collateral as the source and the most underweight collateral
+    /// as the destination, and sizes the swap to close the smaller of the two gaps. each invocation
+    /// is capped at MAX_REBALANCE_TO_TARGET_BPS of the bucket's total value and by max_amount_in.
+    ///
+    /// remaining accounts are the swap route, i.e. the 6 rebalance asset accounts, the 6 decimal
+    /// wrapper accounts if the collateral decimals differ and the swap venue's accounts, followed by
+    /// a (mint, crate ATA, oracle, collateral config) group for each authorized collateral, in the
+    /// same order as the bucket's collateral, i.e. sorted by mint. callers can append the 3 keeper
+    /// bounty accounts to claim the bucket's keeper bounty. as with rebalance, the collateral configs
+    /// of the swapped collateral must be writable.
+    ///
+    /// the swap must return at least the oracle value of its input less
+    /// MAX_PERMISSIONLESS_SLIPPAGE_BPS, and it must strictly reduce the bucket's total deviation from
+    /// its target allocation, otherwise the instruction fails. this means a caller cannot use this
+    /// instruction to push the bucket away from its targets.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn rebalance_to_target<'info>(
+        ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
+        max_amount_in: u64,
+    ) -> ProgramResult {
+        instructions::rebalance_to_target::handle(ctx, max_amount_in)?;
+
+        Ok(())
+    }
+
+    /// this instruction swaps collateral through an intermediate mint, i.e. token_a → intermediate →
+    /// token_b, for collateral pairs without a direct pool. the first leg uses the swap pair passed in
+    /// the rebalance context and the second leg uses the swap pair passed in the remaining accounts.
+    /// the intermediate tokens are held by a bucket ATA between the two legs, and the intermediate mint
+    /// does not need to be authorized collateral. all mints on the route must have the same decimals.
+    ///
+    /// remaining accounts are the 10 two hop rebalance asset accounts, followed by the first swap
+    /// pair's venue accounts, the second swap pair's venue accounts, a (mint, crate ATA, oracle,
+    /// collateral config) group for each authorized collateral, the intermediate mint's (oracle,
+    /// collateral config) and, if the source is no longer authorized, the source's. the first leg
+    /// must return the oracle value of amount_in in the intermediate mint, less the route's slippage
+    /// bound. as with rebalance, the collateral configs of the source and final destination must be
+    /// writable, and the caller rules, slippage bounds, band and deviation checks are the same. unlike
+    /// rebalance, no keeper bounty is paid.
+    ///
+    /// instruction privilege: anyone can call this instruction, but swap rules depend on the caller
+    pub fn rebalance_two_hop<'info>(
+        ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
+        amount_in: u64,
+        minimum_amount_out: u64,
+    ) -> ProgramResult {
+        instructions::rebalance_two_hop::handle(ctx, amount_in, minimum_amount_out)?;
+
+        Ok(())
+    }
+
     /// this instruction will transfer a certain number of the signer's authorized collateral tokens
     /// to the bucket. in return, it will mint an equivalent number of reserve tokens to the signer
     /// based on the relative value of collateral tokens depossited.
@@ -131,7 +651,10 @@ pub mod bucket_program {
     /// use the reserve token across the Solana ecosystem.
     ///
     /// instruction privilege: anyone can call this instruction
-    pub fn deposit(ctx: Context<Deposit>, deposit_amount: u64) -> ProgramResult {
+    pub fn deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+        deposit_amount: u64,
+    ) -> ProgramResult {
         instructions::deposit::handle(ctx, deposit_amount)?;
 
         Ok(())
@@ -156,4 +679,53 @@ pub mod bucket_program {
 
         Ok(())
     }
+
+    /// this instruction previews a deposit. it takes the same accounts as deposit and runs the same
+    /// checks, but transfers no collateral and issues no reserve tokens. the collateral's oracle
+    /// price, the fee and the reserve tokens the deposit would issue are returned as a borsh
+    /// serialized DepositQuote, so clients can simulate the instruction.
+    ///
+    /// remaining accounts are optional. if a (mint, crate ATA, oracle, collateral config) group is
+    /// supplied for each authorized collateral, sorted by mint, the quote includes the bucket's
+    /// collateral weights after the deposit.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn quote_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, Deposit<'info>>,
+        deposit_amount: u64,
+    ) -> ProgramResult {
+        instructions::quote_deposit::handle(ctx, deposit_amount)?;
+
+        Ok(())
+    }
+
+    /// this instruction previews a redeem. it takes the same accounts as redeem and runs the same
+    /// checks, but burns no reserve tokens and withdraws no collateral. the share of each
+    /// collateral the redeem would pay out is returned as a borsh serialized RedeemQuote.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn quote_redeem<'info>(
+        ctx: Context<'_, '_, '_, 'info, Redeem<'info>>,
+        withdraw_amount: u64,
+    ) -> ProgramResult {
+        instructions::quote_redeem::handle(ctx, withdraw_amount)?;
+
+        Ok(())
+    }
+
+    /// this instruction previews a rebalance_to_target. it takes the same accounts as
+    /// rebalance_to_target and runs the same checks, but makes no swap and pays no keeper bounty.
+    /// the pair, oracle prices, swap amounts, keeper bounty and the bucket's collateral weights
+    /// after the swap are returned as a borsh serialized RebalanceQuote. the bounty is only quoted
+    /// if the keeper bounty accounts are supplied.
+    ///
+    /// instruction privilege: anyone can call this instruction
+    pub fn quote_rebalance<'info>(
+        ctx: Context<'_, '_, '_, 'info, Rebalance<'info>>,
+        max_amount_in: u64,
+    ) -> ProgramResult {
+        instructions::quote_rebalance::handle(ctx, max_amount_in)?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,159 @@
+use {
+    crate::{error::ErrorCode, math::casting::cast, math_error},
+    anchor_lang::prelude::msg,
+    std::cmp::Reverse,
+};
+
+/// split total between weights in proportion to them, using the largest remainder (hamilton)
+/// method. every share is first rounded down to its exact quota, i.e. weight * total / sum of
+/// weights. the units lost to rounding are then handed out one each to the largest remainders. ties
+/// go to the lower index, so the result is deterministic for a given order of weights.
+///
+/// the shares always sum to exactly total. if every weight is zero, total is split evenly, e.g.
+/// when the only collateral with a non-zero allocation is removed. fails if there are no weights.
+pub fn apportion(weights: &[u64], total: u64) -> Result<Vec<u64>, ErrorCode> {
+    if weights.is_empty() {
+        return Err(ErrorCode::MathError);
+    }
+
+    let weight_sum = weights.iter().try_fold(0_u128, |sum, weight| {
+        sum.checked_add(*weight as u128).ok_or_else(math_error!())
+    })?;
+    if weight_sum == 0 {
+        return apportion(&vec![1; weights.len()], total);
+    }
+
+    let mut shares: Vec<u64> = Vec::with_capacity(weights.len());
+    let mut remainders: Vec<(u128, usize)> = Vec::with_capacity(weights.len());
+    let mut allocated: u64 = 0;
+    for (idx, weight) in weights.iter().enumerate() {
+        // weight and total both fit in a u64, so their product cannot overflow a u128
+        let product = (*weight as u128) * (total as u128);
+        let share: u64 = cast(product.checked_div(weight_sum).ok_or_else(math_error!())?)?;
+
+        shares.push(share);
+        remainders.push((
+            product.checked_rem(weight_sum).ok_or_else(math_error!())?,
+            idx,
+        ));
+        allocated = allocated.checked_add(share).ok_or_else(math_error!())?;
+    }
+
+    // every share lost less than one unit to rounding, so fewer than weights.len() units are left
+    let leftover: usize = cast(total.checked_sub(allocated).ok_or_else(math_error!())?)?;
+    remainders.sort_unstable_by_key(|(remainder, idx)| (Reverse(*remainder), *idx));
+    for (_, idx) in remainders.iter().take(leftover) {
+        shares[*idx] = shares[*idx].checked_add(1).ok_or_else(math_error!())?;
+    }
+
+    Ok(shares)
+}
+
+#[cfg(test)]
+mod tests {
+    use {super::*, crate::constant::MAX_ALLOCATION_PPM, crate::constant::MAX_COLLATERAL_ELEMENTS};
+
+    const MAX_PPM: u64 = MAX_ALLOCATION_PPM as u64;
+
+    #[test]
+    fn test_apportion_exact_shares() {
+        assert_eq!(apportion(&[1, 1, 2], 400).unwrap(), vec![100, 100, 200]);
+        assert_eq!(apportion(&[5], 7).unwrap(), vec![7]);
+        assert_eq!(apportion(&[3, 1], 0).unwrap(), vec![0, 0]);
+    }
+
+    #[test]
+    fn test_apportion_largest_remainders() {
+        // 600_000 / 900_000 and 300_000 / 900_000 of 1_000_000 are 666_666.67 and 333_333.33
+        assert_eq!(
+            apportion(&[600_000, 300_000], MAX_PPM).unwrap(),
+            vec![666_667, 333_333]
+        );
+        // 3.33 and 6.67: the unit lost to rounding goes to the larger remainder
+        assert_eq!(apportion(&[1, 2], 10).unwrap(), vec![3, 7]);
+    }
+
+    #[test]
+    fn test_apportion_ties_go_to_lower_index() {
+        assert_eq!(apportion(&[1, 1, 1], 100).unwrap(), vec![34, 33, 33]);
+        assert_eq!(apportion(&[1, 1, 1], 2).unwrap(), vec![1, 1, 0]);
+        assert_eq!(apportion(&[1, 1, 1, 1], 2).unwrap(), vec![1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_apportion_zero_weights() {
+        // zero weights get nothing as long as any weight is non-zero
+        assert_eq!(apportion(&[0, 1, 0, 3], 10).unwrap(), vec![0, 3, 0, 7]);
+        assert_eq!(apportion(&[0, 0, 1], MAX_PPM).unwrap(), vec![0, 0, MAX_PPM]);
+
+        // every weight zero splits the total evenly
+        assert_eq!(
+            apportion(&[0, 0, 0], MAX_PPM).unwrap(),
+            vec![333_334, 333_333, 333_333]
+        );
+        assert_eq!(apportion(&[0], MAX_PPM).unwrap(), vec![MAX_PPM]);
+    }
+
+    #[test]
+    fn test_apportion_no_weights() {
+        assert!(apportion(&[], MAX_PPM).is_err());
+    }
+
+    #[test]
+    fn test_apportion_large_values() {
+        let shares = apportion(&[u64::MAX, u64::MAX, 1], u64::MAX).unwrap();
+        assert_eq!(
+            shares.iter().map(|s| *s as u128).sum::<u128>(),
+            u64::MAX as u128
+        );
+        assert_eq!(shares[0], shares[1]);
+    }
+
+    #[test]
+    fn test_apportion_add_collateral() {
+        // same as Bucket::add_collateral: existing allocations are scaled down to what is left
+        // after the new collateral's allocation
+        for new_allocation in [1, 3_333, MAX_PPM / 2, MAX_PPM - 1] {
+            let mut allocations: Vec<u64> = vec![MAX_PPM];
+            while allocations.len() < MAX_COLLATERAL_ELEMENTS {
+                allocations = apportion(&allocations, MAX_PPM - new_allocation).unwrap();
+                allocations.push(new_allocation);
+
+                assert_eq!(allocations.iter().sum::<u64>(), MAX_PPM);
+            }
+        }
+    }
+
+    #[test]
+    fn test_apportion_remove_collateral() {
+        // same as Bucket::remove_collateral: the remaining allocations are scaled up to the max
+        for len in 2..=MAX_COLLATERAL_ELEMENTS {
+            for removed_idx in [0, len / 2, len - 1] {
+                let weights: Vec<u64> = (0..len as u64).map(|i| i * 7 % 13).collect();
+                let mut allocations = apportion(&weights, MAX_PPM).unwrap();
+                assert_eq!(allocations.iter().sum::<u64>(), MAX_PPM);
+
+                allocations.remove(removed_idx);
+                let allocations = apportion(&allocations, MAX_PPM).unwrap();
+                assert_eq!(allocations.len(), len - 1);
+                assert_eq!(allocations.iter().sum::<u64>(), MAX_PPM);
+            }
+        }
+    }
+
+    #[test]
+    fn test_apportion_keeps_proportions() {
+        // every share is within one unit of its exact quota
+        for len in 1..=MAX_COLLATERAL_ELEMENTS {
+            let weights: Vec<u64> = (1..=len as u64).collect();
+            let weight_sum: u64 = weights.iter().sum();
+            let shares = apportion(&weights, MAX_PPM).unwrap();
+
+            assert_eq!(shares.iter().sum::<u64>(), MAX_PPM);
+            for (weight, share) in weights.iter().zip(shares.iter()) {
+                let quota_floor = weight * MAX_PPM / weight_sum;
+                assert!(*share == quota_floor || *share == quota_floor + 1);
+            }
+        }
+    }
+}
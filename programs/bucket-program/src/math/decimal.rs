@@ -0,0 +1,241 @@
+use {
+    crate::{error::ErrorCode, math_error},
+    anchor_lang::prelude::msg,
+};
+
+/// number of decimal places a [Decimal] keeps
+pub const DECIMAL_PLACES: u32 = 12;
+
+/// 10^DECIMAL_PLACES, i.e. the raw value of one
+const ONE: u128 = 1_000_000_000_000;
+
+/// direction in which a result that cannot be represented exactly is rounded. callers pick the
+/// direction that favours the protocol, e.g. amounts paid out are rounded down and minimum amounts
+/// received are rounded up.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// unsigned fixed-point number with DECIMAL_PLACES decimal places. token amounts and oracle prices
+/// are converted into decimals with from_scaled, combined with the checked operations, and
+/// converted back with to_scaled. every operation that can lose precision takes a [Rounding].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Decimal(u128);
+
+impl Decimal {
+    pub fn zero() -> Self {
+        Decimal(0)
+    }
+
+    pub fn one() -> Self {
+        Decimal(ONE)
+    }
+
+    /// value / 10^decimals, e.g. a token amount in base units and its mint's decimals, or an oracle
+    /// price and its precision
+    pub fn from_scaled(value: u128, decimals: u32, rounding: Rounding) -> Result<Self, ErrorCode> {
+        if decimals <= DECIMAL_PLACES {
+            value
+                .checked_mul(pow10(DECIMAL_PLACES - decimals)?)
+                .map(Decimal)
+                .ok_or_else(math_error!())
+        } else {
+            div_rounded(value, pow10(decimals - DECIMAL_PLACES)?, rounding).map(Decimal)
+        }
+    }
+
+    /// self * 10^decimals as an integer. inverse of from_scaled.
+    pub fn to_scaled(self, decimals: u32, rounding: Rounding) -> Result<u128, ErrorCode> {
+        if decimals <= DECIMAL_PLACES {
+            div_rounded(self.0, pow10(DECIMAL_PLACES - decimals)?, rounding)
+        } else {
+            self.0
+                .checked_mul(pow10(decimals - DECIMAL_PLACES)?)
+                .ok_or_else(math_error!())
+        }
+    }
+
+    pub fn checked_add(self, other: Decimal) -> Result<Self, ErrorCode> {
+        self.0
+            .checked_add(other.0)
+            .map(Decimal)
+            .ok_or_else(math_error!())
+    }
+
+    pub fn checked_sub(self, other: Decimal) -> Result<Self, ErrorCode> {
+        self.0
+            .checked_sub(other.0)
+            .map(Decimal)
+            .ok_or_else(math_error!())
+    }
+
+    pub fn checked_mul(self, other: Decimal, rounding: Rounding) -> Result<Self, ErrorCode> {
+        mul_div(self.0, other.0, ONE, rounding).map(Decimal)
+    }
+
+    pub fn checked_div(self, other: Decimal, rounding: Rounding) -> Result<Self, ErrorCode> {
+        mul_div(self.0, ONE, other.0, rounding).map(Decimal)
+    }
+}
+
+/// a * b / c, rounded in the given direction. the result is exact before rounding, even if a * b
+/// does not fit in a u128, as long as (a % c) * b does.
+pub fn mul_div(a: u128, b: u128, c: u128, rounding: Rounding) -> Result<u128, ErrorCode> {
+    if let Some(product) = a.checked_mul(b) {
+        return div_rounded(product, c, rounding);
+    }
+
+    // a * b / c = (a / c) * b + (a % c) * b / c. the first term is a whole number, so only the
+    // second term needs rounding.
+    let whole = a
+        .checked_div(c)
+        .ok_or_else(math_error!())?
+        .checked_mul(b)
+        .ok_or_else(math_error!())?;
+    let fraction = div_rounded(
+        a.checked_rem(c)
+            .ok_or_else(math_error!())?
+            .checked_mul(b)
+            .ok_or_else(math_error!())?,
+        c,
+        rounding,
+    )?;
+
+    whole.checked_add(fraction).ok_or_else(math_error!())
+}
+
+fn div_rounded(numerator: u128, denominator: u128, rounding: Rounding) -> Result<u128, ErrorCode> {
+    let quotient = numerator
+        .checked_div(denominator)
+        .ok_or_else(math_error!())?;
+
+    match rounding {
+        Rounding::Up if numerator % denominator != 0 => {
+            quotient.checked_add(1).ok_or_else(math_error!())
+        }
+        _ => Ok(quotient),
+    }
+}
+
+fn pow10(exponent: u32) -> Result<u128, ErrorCode> {
+    10_u128.checked_pow(exponent).ok_or_else(math_error!())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scaled_round_trip() {
+        let amount = Decimal::from_scaled(1_500_000, 6, Rounding::Down).unwrap();
+        assert_eq!(amount, Decimal(1_500_000_000_000));
+        assert_eq!(amount.to_scaled(6, Rounding::Down).unwrap(), 1_500_000);
+        assert_eq!(
+            amount.to_scaled(18, Rounding::Down).unwrap(),
+            1_500_000_000_000_000_000
+        );
+
+        assert_eq!(
+            Decimal::from_scaled(1, 0, Rounding::Down).unwrap(),
+            Decimal::one()
+        );
+        assert_eq!(
+            Decimal::from_scaled(ONE, DECIMAL_PLACES, Rounding::Down).unwrap(),
+            Decimal::one()
+        );
+    }
+
+    #[test]
+    fn test_scaled_rounding() {
+        // 1.5 to whole units
+        let amount = Decimal::from_scaled(15, 1, Rounding::Down).unwrap();
+        assert_eq!(amount.to_scaled(0, Rounding::Down).unwrap(), 1);
+        assert_eq!(amount.to_scaled(0, Rounding::Up).unwrap(), 2);
+
+        // more decimals than a decimal keeps
+        assert_eq!(
+            Decimal::from_scaled(1, 18, Rounding::Down).unwrap(),
+            Decimal::zero()
+        );
+        assert_eq!(
+            Decimal::from_scaled(1, 18, Rounding::Up).unwrap(),
+            Decimal(1)
+        );
+        assert_eq!(
+            Decimal::from_scaled(1_000_000, 18, Rounding::Up).unwrap(),
+            Decimal(1)
+        );
+    }
+
+    #[test]
+    fn test_checked_mul_div() {
+        let one_and_a_half = Decimal::from_scaled(15, 1, Rounding::Down).unwrap();
+        assert_eq!(
+            one_and_a_half
+                .checked_mul(one_and_a_half, Rounding::Down)
+                .unwrap()
+                .to_scaled(2, Rounding::Down)
+                .unwrap(),
+            225
+        );
+
+        let three = Decimal::from_scaled(3, 0, Rounding::Down).unwrap();
+        assert_eq!(
+            Decimal::one().checked_div(three, Rounding::Down).unwrap(),
+            Decimal(333_333_333_333)
+        );
+        assert_eq!(
+            Decimal::one().checked_div(three, Rounding::Up).unwrap(),
+            Decimal(333_333_333_334)
+        );
+
+        // the smallest decimal squared is below the smallest decimal
+        assert_eq!(
+            Decimal(1).checked_mul(Decimal(1), Rounding::Down).unwrap(),
+            Decimal::zero()
+        );
+        assert_eq!(
+            Decimal(1).checked_mul(Decimal(1), Rounding::Up).unwrap(),
+            Decimal(1)
+        );
+
+        assert!(Decimal::one()
+            .checked_div(Decimal::zero(), Rounding::Down)
+            .is_err());
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        let two = Decimal::one().checked_add(Decimal::one()).unwrap();
+        assert_eq!(two.checked_sub(Decimal::one()).unwrap(), Decimal::one());
+
+        assert!(Decimal::zero().checked_sub(Decimal::one()).is_err());
+        assert!(Decimal(u128::MAX).checked_add(Decimal::one()).is_err());
+        assert!(Decimal::from_scaled(u128::MAX, 0, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_mul_div() {
+        assert_eq!(mul_div(9, 10, 3, Rounding::Down).unwrap(), 30);
+        assert_eq!(mul_div(9, 10, 3, Rounding::Up).unwrap(), 30);
+        assert_eq!(mul_div(10, 10, 3, Rounding::Down).unwrap(), 33);
+        assert_eq!(mul_div(10, 10, 3, Rounding::Up).unwrap(), 34);
+
+        assert!(mul_div(1, 1, 0, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_mul_div_product_overflow() {
+        // u128::MAX * 2 does not fit in a u128, but u128::MAX * 2 / 4 = 2^127 - 0.5 does
+        assert_eq!(
+            mul_div(u128::MAX, 2, 4, Rounding::Down).unwrap(),
+            (1 << 127) - 1
+        );
+        assert_eq!(mul_div(u128::MAX, 2, 4, Rounding::Up).unwrap(), 1 << 127);
+
+        // the result itself does not fit
+        assert!(mul_div(u128::MAX, 4, 2, Rounding::Down).is_err());
+    }
+}
@@ -1 +1,3 @@
+pub mod apportionment;
 pub mod casting;
+pub mod decimal;
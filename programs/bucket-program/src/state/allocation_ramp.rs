@@ -0,0 +1,163 @@
+use {
+    crate::{
+        constant::{MAX_ALLOCATION_PPM, MAX_COLLATERAL_ELEMENTS},
+        error::ErrorCode,
+        math::{apportionment::apportion, casting::cast},
+        math_error,
+        state::bucket::{Bucket, Collateral},
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+/// moves a bucket's target allocations from start_allocations to end_allocations linearly between
+/// start_slot and end_slot, similar to how stableswap ramps its amp factor. this lets keepers
+/// rebalance towards new targets gradually instead of racing each other on thin pools.
+///
+/// the bucket always stores the end allocations. the allocations are index aligned with the bucket's
+/// collateral, and a ramp is only in effect while the bucket's allocations still equal its end
+/// allocations. any other allocation change, e.g. authorizing or removing collateral, supersedes
+/// the ramp.
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct AllocationRamp {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket this ramp belongs to
+    pub bucket: Pubkey,
+    /// Slot at which the ramp started
+    pub start_slot: u64,
+    /// Slot at which the end allocations are fully in effect
+    pub end_slot: u64,
+    /// Target allocations at start_slot
+    pub start_allocations: Vec<u32>,
+    /// Target allocations from end_slot on
+    pub end_allocations: Vec<u32>,
+}
+
+impl AllocationRamp {
+    pub fn init(&mut self, bump: u8, bucket: Pubkey) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.start_slot = 0;
+        self.end_slot = 0;
+        self.start_allocations = Vec::new();
+        self.end_allocations = Vec::new();
+    }
+
+    pub fn start(
+        &mut self,
+        start_allocations: Vec<u32>,
+        end_allocations: Vec<u32>,
+        start_slot: u64,
+        end_slot: u64,
+    ) -> ProgramResult {
+        invariant!(
+            end_slot > start_slot && start_allocations.len() == end_allocations.len(),
+            ErrorCode::InvalidAllocationRamp
+        );
+
+        self.start_slot = start_slot;
+        self.end_slot = end_slot;
+        self.start_allocations = start_allocations;
+        self.end_allocations = end_allocations;
+
+        Ok(())
+    }
+
+    /// end the ramp at slot. the bucket is expected to store the allocations that were in effect.
+    pub fn stop(&mut self, slot: u64) {
+        self.end_slot = slot;
+        self.start_allocations = Vec::new();
+        self.end_allocations = Vec::new();
+    }
+
+    pub fn is_active(&self, collateral: &[Collateral], slot: u64) -> bool {
+        slot < self.end_slot
+            && self.end_allocations.len() == collateral.len()
+            && self
+                .end_allocations
+                .iter()
+                .zip(collateral.iter())
+                .all(|(allocation, el)| *allocation == el.allocation)
+    }
+
+    /// the bucket's collateral with the target allocations in effect at slot. the interpolated
+    /// allocations are apportioned from MAX_ALLOCATION_PPM, so they sum to it exactly and the
+    /// units lost to rounding go to the collateral closest to their next unit.
+    pub fn effective_collateral(
+        &self,
+        collateral: &[Collateral],
+        slot: u64,
+    ) -> Result<Vec<Collateral>, ErrorCode> {
+        let mut effective_collateral = collateral.to_vec();
+        if !self.is_active(collateral, slot) || effective_collateral.is_empty() {
+            return Ok(effective_collateral);
+        }
+
+        let duration = self
+            .end_slot
+            .checked_sub(self.start_slot)
+            .ok_or_else(math_error!())?;
+        let elapsed = slot.saturating_sub(self.start_slot);
+        let remaining = duration.checked_sub(elapsed).ok_or_else(math_error!())?;
+
+        // allocation = (start * remaining + end * elapsed) / duration. the weights all share the
+        // duration as their denominator, so apportion divides it out.
+        let weights = effective_collateral
+            .iter()
+            .zip(self.start_allocations.iter())
+            .map(|(el, start_allocation)| {
+                (*start_allocation as u64)
+                    .checked_mul(remaining)
+                    .ok_or_else(math_error!())?
+                    .checked_add(
+                        (el.allocation as u64)
+                            .checked_mul(elapsed)
+                            .ok_or_else(math_error!())?,
+                    )
+                    .ok_or_else(math_error!())
+            })
+            .collect::<Result<Vec<u64>, ErrorCode>>()?;
+        let allocations = apportion(&weights, MAX_ALLOCATION_PPM as u64)?;
+
+        for (el, allocation) in effective_collateral.iter_mut().zip(allocations) {
+            el.allocation = cast(allocation)?;
+        }
+
+        Ok(effective_collateral)
+    }
+}
+
+/// the bucket's collateral with the target allocations in effect at slot. the ramp PDA is only
+/// created the first time the authority ramps allocations, so consumers pass it unchecked. an
+/// account that is not initialized means the bucket's allocations are the targets.
+pub fn load_target_collateral(
+    bucket: &Bucket,
+    allocation_ramp: &AccountInfo,
+    slot: u64,
+) -> Result<Vec<Collateral>, ProgramError> {
+    if allocation_ramp.owner != &crate::ID || allocation_ramp.data_is_empty() {
+        return Ok(bucket.collateral().to_vec());
+    }
+
+    let ramp = AllocationRamp::try_deserialize(&mut &allocation_ramp.try_borrow_data()?[..])?;
+
+    Ok(ramp.effective_collateral(bucket.collateral(), slot)?)
+}
+
+pub const ALLOCATION_RAMP_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // start_slot
+    8 +
+    // end_slot
+    8 +
+    // start_allocations
+    4 + 4 * MAX_COLLATERAL_ELEMENTS +
+    // end_allocations
+    4 + 4 * MAX_COLLATERAL_ELEMENTS;
@@ -1,23 +1,60 @@
 use {
     crate::{
-        constant::{MAX_BASIS_POINTS, MAX_COLLATERAL_ELEMENTS},
+        constant::{
+            ALLOCATION_PPM_PER_BPS, BUCKET_VERSION, MAX_ALLOCATION_PPM, MAX_COLLATERAL_ELEMENTS,
+            MAX_KEEPER_BOUNTY_BPS, MAX_TIMELOCK_DELAY_SLOTS, PAUSE_ALL, PAUSE_DEPOSITS,
+        },
         error::ErrorCode,
-        util::{get_collateral_idx, get_divisor, is_collateral_authorized, sum_allocations},
-        math_error
+        math::{apportionment::apportion, casting::cast},
+        math_error,
+        state::proposal::AdminAction,
+        util::{get_collateral_idx, sum_allocations},
     },
     anchor_lang::prelude::*,
-    std::convert::TryInto,
     vipers::invariant,
 };
 
 /// 🪣
 ///
 /// Lets users print $BUCKET or redeem $BUCKET for its underlying.
-#[account]
-#[derive(Debug, Default, PartialEq)]
+///
+/// The bucket is a zero-copy account with room for MAX_COLLATERAL_ELEMENTS collateral. only the
+/// first collateral_len elements are authorized, and they are kept sorted by mint so that
+/// collateral can be looked up with a binary search. fields are ordered so that the struct has no
+/// implicit padding.
+///
+/// every bucket has the same size, so the account is not resized as collateral is authorized.
+/// BUCKET_ACCOUNT_SPACE is larger than an account created through a CPI can be, so resize_bucket
+/// creates the account and grows it 10KB at a time. create_bucket and migrate_bucket grow it the
+/// rest of the way.
+#[account(zero_copy)]
 pub struct Bucket {
+    /// Layout version, see BUCKET_VERSION. bumped whenever the layout changes so that existing
+    /// accounts can be upgraded with migrate_bucket.
+    pub version: u8,
     /// Bump.
     pub bump: u8,
+    /// Keeper bounty in bps of the swap's output amount. zero disables the bounty.
+    pub keeper_bounty_bps: u16,
+    /// Number of authorized collateral, i.e. the used prefix of collateral
+    pub collateral_len: u16,
+    /// PAUSE_* bitflags that apply to the whole bucket
+    pub pause_flags: u8,
+    /// Non-zero once the authority starts winding the bucket down. deposits stay disabled for good.
+    pub decommissioned: u8,
+    /// [BountySource] the keeper bounty is paid in
+    pub keeper_bounty_source: u8,
+    pub padding: u8,
+    /// Number of collateral configs of this bucket that are not closed. see [ChildAccount].
+    pub collateral_config_count: u16,
+    /// Number of swap pairs of this bucket that are not removed
+    pub swap_pair_count: u16,
+    /// Number of collateral wrappers of this bucket that are not removed
+    pub collateral_wrapper_count: u16,
+    /// Upper bound on a single keeper bounty, in base units of the token that is paid out
+    pub keeper_bounty_max_amount: u64,
+    /// Delay applied to admin changes
+    pub timelock: Timelock,
     /// Mint of the [crate_token::CrateToken]. This is the reserve that
     /// is issued when an entity deposits collateral.
     pub crate_mint: Pubkey,
@@ -27,27 +64,337 @@ pub struct Bucket {
     pub authority: Pubkey,
     /// Account that has authority to invoke rebalance instruction
     pub rebalance_authority: Pubkey,
-    /// List storing collateral mints
-    pub collateral: Vec<Collateral>,
+    /// Account the authority has proposed to hand the bucket over to. default pubkey if none.
+    pub pending_authority: Pubkey,
+    /// Account that can cancel queued admin changes. default pubkey if none.
+    pub guardian: Pubkey,
+    /// Collateral mints, sorted by mint. only the first collateral_len elements are in use.
+    pub collateral: [Collateral; 315],
+    /// PAUSE_* bitflags that apply to a single collateral, indexed like collateral
+    pub collateral_pause_flags: [u8; 315],
+    /// pads the struct to a multiple of its alignment, so that it has no implicit padding
+    pub padding_end: [u8; 1],
 }
 
-#[repr(C)]
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+// array lengths in the bucket are literals because the IDL parser cannot resolve constants. this
+// fails to compile if they drift from MAX_COLLATERAL_ELEMENTS.
+const _: [(); MAX_COLLATERAL_ELEMENTS] = [(); 315];
+
+/// per-mint PDAs that belong to a bucket. their close instructions need the bucket, so the bucket
+/// counts the open ones and close_bucket refuses to close it while any are left.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ChildAccount {
+    CollateralConfig,
+    SwapPair,
+    CollateralWrapper,
+}
+
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Default, PartialEq, Debug)]
 pub struct Collateral {
     pub mint: Pubkey,
-    /// use bps for allocation %. requires a u16 (2^16 => 65,536) since max bps is 10000.
-    /// as ref, 100 bps => 1%, 1000 => 10%, 10000 => 100%
-    pub allocation: u16,
+    /// allocation in parts per million. requires a u32 since max ppm is 1_000_000.
+    /// as ref, 10_000 ppm => 1%, 100_000 => 10%, 1_000_000 => 100%
+    pub allocation: u32,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct KeeperBounty {
+    /// bounty in bps of the swap's output amount. zero disables the bounty.
+    pub bps: u16,
+    /// upper bound on a single bounty, in base units of the token that is paid out
+    pub max_amount: u64,
+    /// which token the bounty is paid in
+    pub source: BountySource,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum BountySource {
+    /// withdraw the bounty from the collateral the swap produced
+    SwapOutput,
+    /// issue new reserve tokens through the issue authority
+    Reserve,
+}
+
+#[zero_copy]
+#[derive(AnchorSerialize, AnchorDeserialize, Default, PartialEq, Debug)]
+pub struct Timelock {
+    /// number of slots between enqueueing an admin change and executing it. zero disables the
+    /// timelock, i.e. admin changes take effect immediately.
+    pub delay_slots: u64,
+    /// number of proposals enqueued so far. used as the next proposal's id.
+    pub proposal_count: u64,
+}
+
+/// changes update_collateral_set made to the bucket's collateral
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, PartialEq, Debug)]
+pub struct CollateralSetDiff {
+    /// newly authorized collateral and their allocations
+    pub added: Vec<Collateral>,
+    /// mints that are no longer authorized
+    pub removed: Vec<Pubkey>,
+    /// collateral that stayed authorized with a different allocation
+    pub reallocated: Vec<AllocationChange>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct AllocationChange {
+    pub mint: Pubkey,
+    pub previous_allocation: u32,
+    pub allocation: u32,
+}
+
+impl Default for BountySource {
+    fn default() -> Self {
+        BountySource::SwapOutput
+    }
 }
 
 impl Bucket {
+    /// the account is zero-initialized by load_init, so only non-zero fields need to be set
     pub fn init(&mut self, bump: u8, crate_mint: Pubkey, crate_token: Pubkey, authority: Pubkey) {
+        self.version = BUCKET_VERSION;
         self.bump = bump;
         self.crate_mint = crate_mint;
         self.crate_token = crate_token;
         self.authority = authority;
         self.rebalance_authority = authority;
-        self.collateral = Vec::new();
+    }
+
+    /// authorized collateral, sorted by mint
+    pub fn collateral(&self) -> &[Collateral] {
+        &self.collateral[..self.collateral_len as usize]
+    }
+
+    fn collateral_mut(&mut self) -> &mut [Collateral] {
+        &mut self.collateral[..self.collateral_len as usize]
+    }
+
+    pub fn keeper_bounty(&self) -> KeeperBounty {
+        KeeperBounty {
+            bps: self.keeper_bounty_bps,
+            max_amount: self.keeper_bounty_max_amount,
+            source: if self.keeper_bounty_source == BountySource::Reserve as u8 {
+                BountySource::Reserve
+            } else {
+                BountySource::SwapOutput
+            },
+        }
+    }
+
+    pub fn is_decommissioned(&self) -> bool {
+        self.decommissioned != 0
+    }
+
+    pub fn require_not_decommissioned(&self) -> ProgramResult {
+        invariant!(!self.is_decommissioned(), ErrorCode::BucketDecommissioned);
+
+        Ok(())
+    }
+
+    /// first step of closing a bucket. deposits are disabled permanently, while redeems and
+    /// rebalances keep working so that holders can exit.
+    pub fn decommission(&mut self) -> ProgramResult {
+        self.require_not_decommissioned()?;
+
+        self.decommissioned = 1;
+        self.pause_flags |= PAUSE_DEPOSITS;
+
+        Ok(())
+    }
+
+    /// true if the operations in flags are paused for the whole bucket, or for the given mint if
+    /// it is authorized collateral
+    pub fn is_paused(&self, flags: u8, mint: Option<Pubkey>) -> bool {
+        let collateral_pause_flags = mint
+            .and_then(|mint| get_collateral_idx(self.collateral(), mint).ok())
+            .map_or(0, |idx| self.collateral_pause_flags[idx]);
+
+        (self.pause_flags | collateral_pause_flags) & flags != 0
+    }
+
+    pub fn require_not_paused(&self, flags: u8, mint: Option<Pubkey>) -> ProgramResult {
+        invariant!(!self.is_paused(flags, mint), ErrorCode::Paused);
+
+        Ok(())
+    }
+
+    /// set or clear pause flags on the whole bucket, or on a single collateral if mint is given
+    pub fn set_pause_flags(
+        &mut self,
+        flags: u8,
+        mint: Option<Pubkey>,
+        paused: bool,
+    ) -> ProgramResult {
+        invariant!(
+            flags != 0 && flags & !PAUSE_ALL == 0,
+            ErrorCode::InvalidPauseFlags
+        );
+
+        // deposits into a decommissioned bucket stay disabled for good
+        if !paused && self.is_decommissioned() && mint.is_none() {
+            invariant!(flags & PAUSE_DEPOSITS == 0, ErrorCode::BucketDecommissioned);
+        }
+
+        let pause_flags = match mint {
+            Some(mint) => {
+                let idx = get_collateral_idx(self.collateral(), mint)?;
+                &mut self.collateral_pause_flags[idx]
+            }
+            None => &mut self.pause_flags,
+        };
+
+        if paused {
+            *pause_flags |= flags;
+        } else {
+            *pause_flags &= !flags;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_timelocked(&self) -> bool {
+        self.timelock.delay_slots > 0
+    }
+
+    /// admin changes can only be applied directly while the timelock is disabled. otherwise, they
+    /// must be enqueued as a proposal.
+    pub fn require_no_timelock(&self) -> ProgramResult {
+        invariant!(!self.is_timelocked(), ErrorCode::TimelockEnabled);
+
+        Ok(())
+    }
+
+    pub fn configure_timelock(&mut self, delay_slots: u64, guardian: Pubkey) -> ProgramResult {
+        invariant!(
+            delay_slots <= MAX_TIMELOCK_DELAY_SLOTS,
+            ErrorCode::TimelockDelayTooLong
+        );
+
+        self.timelock.delay_slots = delay_slots;
+        self.set_guardian(guardian)
+    }
+
+    pub fn set_guardian(&mut self, guardian: Pubkey) -> ProgramResult {
+        self.guardian = guardian;
+
+        Ok(())
+    }
+
+    /// apply an admin change, either directly or from an executed proposal
+    pub fn apply_admin_action(&mut self, action: &AdminAction) -> ProgramResult {
+        match action {
+            AdminAction::AuthorizeCollateral { mint, allocation } => {
+                self.add_collateral(*mint, *allocation)
+            }
+            AdminAction::RemoveCollateral { mint } => self.remove_collateral(*mint),
+            AdminAction::SetCollateralAllocations { allocations } => {
+                self.set_collateral_allocations(allocations)
+            }
+            AdminAction::UpdateCollateralSet { collateral } => {
+                self.update_collateral_set(collateral).map(|_| ())
+            }
+            AdminAction::UpdateRebalanceAuthority {
+                rebalance_authority,
+            } => self.update_rebalance_authority(*rebalance_authority),
+            AdminAction::ConfigureTimelock {
+                delay_slots,
+                guardian,
+            } => self.configure_timelock(*delay_slots, *guardian),
+            AdminAction::SetGuardian { guardian } => self.set_guardian(*guardian),
+            AdminAction::SetKeeperBounty { keeper_bounty } => {
+                self.set_keeper_bounty(*keeper_bounty)
+            }
+            // these change accounts other than the bucket. execute_proposal applies them with the
+            // accounts it is passed.
+            AdminAction::UpdateCollateralConfig { .. }
+            | AdminAction::SetAllocationBand { .. }
+            | AdminAction::AddSwapPair { .. }
+            | AdminAction::RemoveSwapPair { .. } => Err(ErrorCode::InvalidProposalAccounts.into()),
+        }
+    }
+
+    /// first step of an authority transfer. the proposed authority must accept before it takes
+    /// over, so the bucket cannot be handed to an address that is unable to sign. proposing again
+    /// replaces any pending proposal.
+    pub fn propose_authority(&mut self, pending_authority: Pubkey) -> ProgramResult {
+        invariant!(
+            pending_authority != Pubkey::default() && pending_authority != self.authority,
+            ErrorCode::InvalidPendingAuthority
+        );
+
+        self.pending_authority = pending_authority;
+
+        Ok(())
+    }
+
+    pub fn cancel_authority_transfer(&mut self) -> ProgramResult {
+        invariant!(
+            self.pending_authority != Pubkey::default(),
+            ErrorCode::NoPendingAuthority
+        );
+
+        self.pending_authority = Pubkey::default();
+
+        Ok(())
+    }
+
+    /// second step of an authority transfer. must be signed by the pending authority.
+    pub fn accept_authority(&mut self, signer: Pubkey) -> ProgramResult {
+        invariant!(
+            self.pending_authority != Pubkey::default(),
+            ErrorCode::NoPendingAuthority
+        );
+        invariant!(
+            self.pending_authority == signer,
+            ErrorCode::InvalidPendingAuthority
+        );
+
+        self.authority = self.pending_authority;
+        self.pending_authority = Pubkey::default();
+
+        Ok(())
+    }
+
+    pub fn set_keeper_bounty(&mut self, keeper_bounty: KeeperBounty) -> ProgramResult {
+        invariant!(
+            keeper_bounty.bps <= MAX_KEEPER_BOUNTY_BPS,
+            ErrorCode::KeeperBountyTooLarge
+        );
+
+        self.keeper_bounty_bps = keeper_bounty.bps;
+        self.keeper_bounty_max_amount = keeper_bounty.max_amount;
+        self.keeper_bounty_source = keeper_bounty.source as u8;
+
+        Ok(())
+    }
+
+    fn child_account_count_mut(&mut self, kind: ChildAccount) -> &mut u16 {
+        match kind {
+            ChildAccount::CollateralConfig => &mut self.collateral_config_count,
+            ChildAccount::SwapPair => &mut self.swap_pair_count,
+            ChildAccount::CollateralWrapper => &mut self.collateral_wrapper_count,
+        }
+    }
+
+    pub fn record_child_account_opened(&mut self, kind: ChildAccount) -> ProgramResult {
+        let count = self.child_account_count_mut(kind);
+        *count = count.checked_add(1).ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    pub fn record_child_account_closed(&mut self, kind: ChildAccount) -> ProgramResult {
+        let count = self.child_account_count_mut(kind);
+        *count = count.checked_sub(1).ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    pub fn has_child_accounts(&self) -> bool {
+        self.collateral_config_count > 0
+            || self.swap_pair_count > 0
+            || self.collateral_wrapper_count > 0
     }
 
     pub fn update_rebalance_authority(&mut self, rebalance_authority: Pubkey) -> ProgramResult {
@@ -56,14 +403,19 @@ impl Bucket {
         Ok(())
     }
 
-    pub fn set_collateral_allocations(&mut self, allocations: &Vec<Collateral>) -> ProgramResult {
+    pub fn set_collateral_allocations(&mut self, allocations: &[Collateral]) -> ProgramResult {
+        // sort a copy of the updated allocations so that each mint can be matched with a binary
+        // search instead of a linear scan
+        let mut allocations = allocations.to_vec();
+        allocations.sort_unstable_by_key(|el| el.mint);
+
         // keep a running sum of the new allocation total. at the end of this function, we will
-        // verify that the allocations sum to the full 10000 bps.
-        let mut running_updated_allocation: u16 = 0;
+        // verify that the allocations sum to the full 1_000_000 ppm.
+        let mut running_updated_allocation: u32 = 0;
 
         // since we only loop through list of currently authorized mints, we ignore any additional mints.
         // error will be caught in the new allocation sum check.
-        for mut collateral in self.collateral.iter_mut() {
+        for collateral in self.collateral_mut().iter_mut() {
             msg!("updating collateral allocation: {}", collateral.mint);
 
             // match the current collateral to the collateral in the updated allocations vec
@@ -82,228 +434,257 @@ impl Bucket {
         msg!("running_updated_allocation: {}", running_updated_allocation);
 
         invariant!(
-            running_updated_allocation == MAX_BASIS_POINTS,
-            ErrorCode::AllocationBpsError
+            running_updated_allocation == MAX_ALLOCATION_PPM,
+            ErrorCode::AllocationPpmError
         );
 
         Ok(())
     }
 
-    pub fn remove_collateral(&mut self, mint: Pubkey) -> ProgramResult {
-        // prevent authority from removing all approved collateral mints
-        invariant!(self.collateral.len() > 1, ErrorCode::MinCollateralError);
+    /// replace the authorized collateral and their allocations with the given set in one step.
+    /// unlike add_collateral and remove_collateral, allocations are not redistributed, so the new
+    /// set must be unique and sum to exactly MAX_ALLOCATION_PPM. collateral that stays authorized
+    /// keeps its pause flags.
+    pub fn update_collateral_set(
+        &mut self,
+        collateral: &[Collateral],
+    ) -> Result<CollateralSetDiff, ProgramError> {
+        let mut collateral = collateral.to_vec();
+        collateral.sort_unstable_by_key(|el| el.mint);
+
+        invariant!(!collateral.is_empty(), ErrorCode::MinCollateralError);
+        invariant!(
+            collateral.len() <= MAX_COLLATERAL_ELEMENTS,
+            ErrorCode::CollateralSizeLimitsExceeded
+        );
+        invariant!(
+            collateral
+                .windows(2)
+                .all(|pair| pair[0].mint != pair[1].mint),
+            ErrorCode::DuplicateCollateral
+        );
 
-        // remove the collateral element from the current vec based on the mint parameter
-        let removed_collateral = self
-            .collateral
-            .remove(get_collateral_idx(&self.collateral, mint)?);
-        msg!(
-            "requesting to remove collateral with mint: {}. actually removed collateral: {:?}",
-            mint,
-            removed_collateral
+        let total_allocation = collateral.iter().try_fold(0_u32, |sum, el| {
+            sum.checked_add(el.allocation).ok_or_else(math_error!())
+        })?;
+        invariant!(
+            total_allocation == MAX_ALLOCATION_PPM,
+            ErrorCode::AllocationPpmError
         );
 
-        let mut running_allocation_sum: u64 = 0;
-        for mut collateral in self.collateral.iter_mut() {
-            let current_allocation: u64 = collateral.allocation as u64;
-            let removed_allocation: u64 = removed_collateral.allocation as u64;
-
-            // we will proportionally redistribute the removed collateral's allocation to the remaining
-            // collateral in the pool. to explore how this works, let's consider an example.
-            //
-            // let's say there are currently 3 collaterals: mintA with 60%, mintB with 30%, and mintC
-            // with 10% of the allocation, respectively. say we want to remove mintC from the collateral.
-            // as stated above, mintC is currently 10% or 1_000 bps of the total allocation. when we remove
-            // mintC, we still want the total allocation to sum to 100% or 10_000 bps. so, we need to
-            // redistribute how much of mintC's allocation is distributed to mintA and mintB. we will use
-            // the following expression to do this:
-            //
-            // mintN_i+1_allocation = mintN_i_allocation + ((mintN_i_allocation * mintM_i_allocation) / (max_basis_points - mintM_i_allocation)),
-            // where mintM_i_allocation is the allocation of the removed collateral.
-            //
-            // let's consider what this means in numeric terms from our example above:
-            // mintA_i+1_allocation = mintA_i_allocation + ((mintA_i_allocation * mintC_i_allocation) / (max_basis_points - mintC_i_allocation))
-            //                      = 6_000 + ((6_000 * 1_000) / (10_000 - 1_000))
-            //                      = 6_000 + (6_000_000 / 9_000)
-            //                      = 666 (666.67 with decimals)
-            //
-            // mintB_i+1_allocation = mintB_i_allocation + ((mintB_i_allocation * mintC_i_allocation) / (max_basis_points - mintC_i_allocation))
-            //                      = 3_000 + ((3_000 * 1_000) / (10_000 - 1_000))
-            //                      = 3_000 + (3_000_000 / 9_000)
-            //                      = 333 (333.33 with decimals)
-            //
-            // together, mintA_i+1_allocation + mintB_i+1_allocation should be equal to the max basis points of 10_000. however,
-            // we can see that a loss of decimal precision will result in a slightly inaccurate result. so, we will also keep track
-            // of these remainders. discussed further in comments below.
-            let change_current_in_allocation = current_allocation
-                .checked_mul(removed_allocation)
-                .ok_or_else(math_error!())?
-                .checked_div(
-                    (MAX_BASIS_POINTS as u64)
-                        .checked_sub(removed_allocation)
-                        .ok_or_else(math_error!())?
-                    )
-                .ok_or_else(math_error!())?;
+        let mut diff = CollateralSetDiff::default();
+        let mut pause_flags: Vec<u8> = Vec::with_capacity(collateral.len());
+        for el in collateral.iter() {
+            match get_collateral_idx(self.collateral(), el.mint) {
+                Ok(idx) => {
+                    let previous_allocation = self.collateral[idx].allocation;
+                    if previous_allocation != el.allocation {
+                        diff.reallocated.push(AllocationChange {
+                            mint: el.mint,
+                            previous_allocation,
+                            allocation: el.allocation,
+                        });
+                    }
+                    pause_flags.push(self.collateral_pause_flags[idx]);
+                }
+                Err(_) => {
+                    diff.added.push(*el);
+                    pause_flags.push(0);
+                }
+            }
+        }
+        for el in self.collateral().iter() {
+            if get_collateral_idx(&collateral, el.mint).is_err() {
+                diff.removed.push(el.mint);
+            }
+        }
 
-            // update the current collateral's allocation
-            collateral.allocation = current_allocation
-                .checked_add(change_current_in_allocation)
-                .ok_or_else(math_error!())?
-                .try_into()
-                .unwrap();
+        // a decommissioned bucket can still drop or reweigh collateral, but not take on new ones
+        if !diff.added.is_empty() {
+            self.require_not_decommissioned()?;
+        }
 
-            msg!(
-                "mint {} with allocation {} is changing by {} to {}",
-                collateral.mint,
-                current_allocation,
-                change_current_in_allocation,
-                collateral.allocation
-            );
+        let len = collateral.len();
+        self.collateral[..len].copy_from_slice(&collateral);
+        self.collateral_pause_flags[..len].copy_from_slice(&pause_flags);
+        self.collateral[len..].fill(Collateral::default());
+        self.collateral_pause_flags[len..].fill(0);
+        self.collateral_len = len as u16;
 
-            // update the running sum of collateral allocations
-            running_allocation_sum = running_allocation_sum
-                .checked_add(collateral.allocation.try_into().unwrap())
-                .ok_or_else(math_error!())?;
-        }
+        msg!(
+            "updated collateral set: {} added, {} removed, {} reallocated",
+            diff.added.len(),
+            diff.removed.len(),
+            diff.reallocated.len()
+        );
 
-        // check if there is a discrepancy between expected max basis points and the updated allocation's sum.
-        // if so, we want to resolve by adding the discrepancy to some collateral's allocation.
-        let bps_discrepancy: u64 = (MAX_BASIS_POINTS as u64)
-            .checked_sub(running_allocation_sum)
-            .ok_or_else(math_error!())?;
+        Ok(diff)
+    }
 
-        if bps_discrepancy > 0 {
-            msg!("adjusting allocation amounts so that sum equals max bps");
+    pub fn remove_collateral(&mut self, mint: Pubkey) -> ProgramResult {
+        // prevent authority from removing all approved collateral mints
+        invariant!(self.collateral_len > 1, ErrorCode::MinCollateralError);
+
+        // remove the collateral element from the current array based on the mint parameter. later
+        // elements are shifted down by one so that the collateral stays sorted.
+        let idx = get_collateral_idx(self.collateral(), mint)?;
+        let len = self.collateral_len as usize;
+        let removed_collateral = self.collateral[idx];
+        self.collateral.copy_within(idx + 1..len, idx);
+        self.collateral_pause_flags.copy_within(idx + 1..len, idx);
+        self.collateral[len - 1] = Collateral::default();
+        self.collateral_pause_flags[len - 1] = 0;
+        self.collateral_len -= 1;
+        msg!(
+            "requesting to remove collateral with mint: {}. actually removed collateral: {:?}",
+            mint,
+            removed_collateral
+        );
 
-            // add remaining allocation discrepancy to first authorized collateral
-            let mut collateral = &mut self.collateral[0];
-            collateral.allocation = collateral
-                .allocation
-                .checked_add(bps_discrepancy.try_into().unwrap())
-                .ok_or_else(math_error!())?;
-        }
+        // redistribute the removed collateral's allocation to the remaining collateral in
+        // proportion to their current allocations. e.g. removing mintC from mintA 60%, mintB 30%
+        // and mintC 10% leaves mintA with 600_000 / 900_000 and mintB with 300_000 / 900_000 of
+        // the full 1_000_000 ppm, i.e. 666_667 and 333_333 ppm. see apportion for how rounding is
+        // resolved.
+        self.apportion_allocations(MAX_ALLOCATION_PPM)?;
 
         invariant!(
-            sum_allocations(&self.collateral)? == MAX_BASIS_POINTS,
-            ErrorCode::AllocationBpsError
+            sum_allocations(self.collateral())? == MAX_ALLOCATION_PPM,
+            ErrorCode::AllocationPpmError
         );
 
         Ok(())
     }
 
-    pub fn add_collateral(&mut self, mint: Pubkey, allocation: u16) -> ProgramResult {
-        invariant!(
-            !is_collateral_authorized(&self.collateral, mint),
-            ErrorCode::CollateralAlreadyAuthorizedError
-        );
-
-        // the acutal constraint is 10_000 collaterals because that would mean each collateral
-        // would have a 1 bps allocation. however, the current vec implementation means that the
-        // solana account size limitation will prevent us from hitting this upper bound. we want
-        // to consider this constraint in the future if the design changes.
+    pub fn add_collateral(&mut self, mint: Pubkey, allocation: u32) -> ProgramResult {
+        self.require_not_decommissioned()?;
+
+        // the new collateral is inserted at the position that keeps the collateral sorted
+        let insert_idx = match self
+            .collateral()
+            .binary_search_by_key(&mint, |collateral| collateral.mint)
+        {
+            Ok(_) => return Err(ErrorCode::CollateralAlreadyAuthorizedError.into()),
+            Err(idx) => idx,
+        };
+
+        // the acutal constraint is 1_000_000 collaterals because that would mean each collateral
+        // would have a 1 ppm allocation. however, the fixed capacity of the bucket account prevents
+        // us from hitting this upper bound.
         invariant!(
-            self.collateral.len() <= MAX_COLLATERAL_ELEMENTS,
+            (self.collateral_len as usize) < MAX_COLLATERAL_ELEMENTS,
             ErrorCode::CollateralSizeLimitsExceeded
         );
 
-        let current_collateral_allocation: u16 = sum_allocations(&self.collateral)?;
-        // if the current collateral allocation is non-zero, new alloc cannot equal max bps.
+        let current_collateral_allocation: u32 = sum_allocations(self.collateral())?;
+        // if the current collateral allocation is non-zero, new alloc cannot equal max ppm.
         // otherwise, at least 1 other authorized collateral's allocation would be set to zero.
-        if current_collateral_allocation > 0 && allocation == MAX_BASIS_POINTS {
-            msg!("Only the first collateral can have max allocation bps");
-            return Err(ErrorCode::AllocationBpsError.into());
+        if current_collateral_allocation > 0 && allocation == MAX_ALLOCATION_PPM {
+            msg!("Only the first collateral can have max allocation ppm");
+            return Err(ErrorCode::AllocationPpmError.into());
         }
 
-        // due to loss of precision, keep track of division operation remainder. this value will later
-        // be used to adjust allocations so that the allocation sum is equal to max basis points.
-        let mut running_remainder: u64 = 0;
-
-        // the updated allocation with the newly proposed collateral. if this value is greater than
-        // max bps, we will proportionally subtract from existing collateral to make room for the
-        // new collateral's allocation.
-        let allocation_with_new_mint: u16 = current_collateral_allocation
-            .checked_add(allocation)
-            .ok_or_else(math_error!())?;
-        if allocation_with_new_mint > MAX_BASIS_POINTS {
-            for mut collateral in self.collateral.iter_mut() {
-                let current_allocation_64: u64 = collateral.allocation as u64;
-
-                let current_and_new_allocation = current_allocation_64
-                    .checked_mul(allocation as u64)
-                    .ok_or_else(math_error!())?;
-
-                running_remainder = running_remainder
-                    .checked_add(
-                        // capture decimals lost when dividing by MAX_BASIS_POINTS
-                        current_and_new_allocation
-                            .checked_rem(MAX_BASIS_POINTS as u64)
-                            .ok_or_else(math_error!())?
-                    )
-                    .ok_or_else(math_error!())?;
-
-                // calculate and set the proportional reduction of current collateral allocation based
-                // on new allocation amount.
-                collateral.allocation = current_allocation_64
-                    .checked_sub(
-                        current_and_new_allocation
-                            .checked_div(MAX_BASIS_POINTS as u64)
-                            .ok_or_else(math_error!())?,
-                    )
-                    .ok_or_else(math_error!())?
-                    .try_into()
-                    .unwrap();
-
-                msg!(
-                    "collateral mint {} has an updated allocation of {}. running remainder now totals = {}",
-                    collateral.mint,
-                    collateral.allocation,
-                    running_remainder,
-                );
-            }
+        // make room for the new collateral's allocation by scaling the existing allocations down
+        // to what is left, in proportion to their current allocations
+        if current_collateral_allocation > 0 {
+            let remaining_allocation = MAX_ALLOCATION_PPM
+                .checked_sub(allocation)
+                .ok_or(ErrorCode::AllocationPpmError)?;
+            self.apportion_allocations(remaining_allocation)?;
         }
 
-        let adjusted_allocation = allocation
-            .checked_sub(
-                running_remainder
-                    .checked_div(get_divisor(running_remainder)?)
-                    .ok_or_else(math_error!())?
-                    .try_into()
-                    .unwrap(),
-            )
-            .ok_or_else(math_error!())?;
-
-        self.collateral.push(Collateral {
-            mint,
-            allocation: adjusted_allocation,
-        });
+        // shift later elements up by one to make room for the new collateral
+        let len = self.collateral_len as usize;
+        self.collateral.copy_within(insert_idx..len, insert_idx + 1);
+        self.collateral_pause_flags
+            .copy_within(insert_idx..len, insert_idx + 1);
+        self.collateral[insert_idx] = Collateral { mint, allocation };
+        self.collateral_pause_flags[insert_idx] = 0;
+        self.collateral_len += 1;
 
-        msg!(
-            "new collateral's requested allocation = {}. actual allocation = {}.",
-            allocation,
-            adjusted_allocation,
+        msg!("new collateral's allocation = {}", allocation);
+
+        // catch any invalid updated allocations. we always want allocations to sum to MAX_ALLOCATION_PPM
+        invariant!(
+            sum_allocations(self.collateral())? == MAX_ALLOCATION_PPM,
+            ErrorCode::AllocationPpmError
         );
 
-        // catch any invalid updated allocations. we always want allocations to sum to MAX_BASIS_POINTS
+        Ok(())
+    }
+
+    /// scale the allocations of the authorized collateral so that they sum to total, keeping their
+    /// proportions
+    fn apportion_allocations(&mut self, total: u32) -> ProgramResult {
+        let weights: Vec<u64> = self
+            .collateral()
+            .iter()
+            .map(|el| el.allocation as u64)
+            .collect();
+        let allocations = apportion(&weights, total as u64)?;
+
+        for (collateral, allocation) in self.collateral_mut().iter_mut().zip(allocations) {
+            let allocation: u32 = cast(allocation)?;
+            msg!(
+                "mint {} with allocation {} is changing to {}",
+                collateral.mint,
+                collateral.allocation,
+                allocation
+            );
+            collateral.allocation = allocation;
+        }
+
+        Ok(())
+    }
+
+    /// overwrite a zeroed bucket with the contents of a [BucketV0]. fields the old layout did not
+    /// have keep their zero defaults. allocations are converted from bps to ppm.
+    pub fn migrate_from(&mut self, bucket: &BucketV0) -> ProgramResult {
         invariant!(
-            sum_allocations(&self.collateral)? == MAX_BASIS_POINTS,
-            ErrorCode::AllocationBpsError
+            bucket.collateral.len() <= MAX_COLLATERAL_ELEMENTS,
+            ErrorCode::CollateralSizeLimitsExceeded
         );
 
+        self.version = BUCKET_VERSION;
+        self.bump = bucket.bump;
+        self.crate_mint = bucket.crate_mint;
+        self.crate_token = bucket.crate_token;
+        self.authority = bucket.authority;
+        self.rebalance_authority = bucket.rebalance_authority;
+
+        let mut collateral = bucket.collateral.clone();
+        collateral.sort_unstable_by_key(|el| el.mint);
+        for (idx, el) in collateral.iter().enumerate() {
+            self.collateral[idx] = Collateral {
+                mint: el.mint,
+                allocation: (el.allocation as u32)
+                    .checked_mul(ALLOCATION_PPM_PER_BPS)
+                    .ok_or_else(math_error!())?,
+            };
+        }
+        self.collateral_len = collateral.len() as u16;
+
         Ok(())
     }
 }
 
-// hard-coding collateral size of ~100
-pub const BUCKET_ACCOUNT_SPACE: usize =
-    // discriminator
-    8 +
-    // bump
-    1 +
-    // crate_mint
-    32 +
-    // crate_token
-    32 +
-    // authority
-    32 +
-    // collateral
-    4 + (32 * 100);
+/// size of the bucket account. the collateral array has a fixed capacity, so every bucket has the
+/// same size.
+pub const BUCKET_ACCOUNT_SPACE: usize = 8 + std::mem::size_of::<Bucket>();
+
+/// layout of bucket accounts created before the version byte was introduced
+#[derive(AnchorDeserialize, Clone, Debug)]
+pub struct BucketV0 {
+    pub bump: u8,
+    pub crate_mint: Pubkey,
+    pub crate_token: Pubkey,
+    pub authority: Pubkey,
+    pub rebalance_authority: Pubkey,
+    pub collateral: Vec<CollateralV0>,
+}
+
+#[derive(AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CollateralV0 {
+    pub mint: Pubkey,
+    pub allocation: u16,
+}
@@ -0,0 +1,70 @@
+use {
+    crate::{error::ErrorCode, math_error},
+    anchor_lang::prelude::*,
+};
+
+/// running totals of a bucket's activity, so that analytics and fee logic can read them without
+/// replaying history. totals that apply to a single collateral live in its collateral config. the
+/// stats live in their own PDA because the bucket account is already at its size limit.
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct BucketStats {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket these stats belong to
+    pub bucket: Pubkey,
+    /// Reserve issued over the lifetime of the bucket, including keeper bounties, in base units
+    pub total_reserve_issued: u128,
+    /// Reserve burned over the lifetime of the bucket, in base units
+    pub total_reserve_burned: u128,
+    /// Slot of the most recent deposit, redeem or rebalance
+    pub last_activity_slot: u64,
+}
+
+impl BucketStats {
+    pub fn init(&mut self, bump: u8, bucket: Pubkey) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.total_reserve_issued = 0;
+        self.total_reserve_burned = 0;
+        self.last_activity_slot = 0;
+    }
+
+    pub fn record_issue(&mut self, amount: u64, slot: u64) -> ProgramResult {
+        self.total_reserve_issued = self
+            .total_reserve_issued
+            .checked_add(amount as u128)
+            .ok_or_else(math_error!())?;
+        self.record_activity(slot);
+
+        Ok(())
+    }
+
+    pub fn record_burn(&mut self, amount: u64, slot: u64) -> ProgramResult {
+        self.total_reserve_burned = self
+            .total_reserve_burned
+            .checked_add(amount as u128)
+            .ok_or_else(math_error!())?;
+        self.record_activity(slot);
+
+        Ok(())
+    }
+
+    pub fn record_activity(&mut self, slot: u64) {
+        self.last_activity_slot = slot;
+    }
+}
+
+pub const BUCKET_STATS_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // total_reserve_issued
+    16 +
+    // total_reserve_burned
+    16 +
+    // last_activity_slot
+    8;
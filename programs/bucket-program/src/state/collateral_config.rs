@@ -0,0 +1,264 @@
+use {
+    crate::{
+        constant::MAX_BASIS_POINTS, error::ErrorCode, math_error, state::oracle::OracleSource,
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+/// everything the program knows about a single collateral of a bucket, apart from its allocation.
+/// the bucket only keeps a compact, sorted index of (mint, allocation) pairs, because allocations
+/// are always updated together. anything else that applies to one collateral lives here, so that
+/// deposits and redeems only load the configs they touch and per-collateral features do not grow
+/// the bucket account.
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct CollateralConfig {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket this config belongs to
+    pub bucket: Pubkey,
+    /// Collateral mint
+    pub mint: Pubkey,
+    /// Decimals of the collateral mint
+    pub decimals: u8,
+    /// Kind of price feed the oracle account is
+    pub oracle_source: OracleSource,
+    /// Price feed deposits of this collateral must be priced with
+    pub oracle: Pubkey,
+    /// Limits on how much of this collateral the bucket takes in
+    pub caps: CollateralCaps,
+    /// Fees that replace the bucket's fees for this collateral
+    pub fee_overrides: FeeOverrides,
+    /// Tolerance around the collateral's target allocation
+    pub band: AllocationBand,
+    /// Which operations this collateral currently supports
+    pub status: CollateralStatus,
+    /// Running totals of value that flowed through this collateral
+    pub stats: CollateralStats,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct CollateralCaps {
+    /// max amount of a single deposit, in base units. zero disables the cap.
+    pub max_deposit_amount: u64,
+    /// max amount the crate can hold after a deposit, in base units. zero disables the cap.
+    pub max_crate_balance: u64,
+}
+
+/// tolerance around a collateral's target allocation, in bps of the bucket's total value. the
+/// collateral is in band while its weight is within [allocation - lower_bps, allocation + upper_bps].
+/// rebalances must move collateral that is out of band. a zero band makes the target a point target,
+/// i.e. any deviation is out of band.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct AllocationBand {
+    pub lower_bps: u16,
+    pub upper_bps: u16,
+    /// reject deposits that would push the collateral above its upper band. such deposits must
+    /// supply the accounts needed to value the bucket's collateral.
+    pub reject_deposits_above_band: bool,
+}
+
+/// the bucket does not charge fees yet. once it does, these replace the bucket-wide fees for this
+/// collateral when set.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct FeeOverrides {
+    pub deposit_fee_bps: Option<u16>,
+    pub redeem_fee_bps: Option<u16>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum CollateralStatus {
+    /// deposits and redeems are allowed
+    Active,
+    /// the collateral is being phased out. redeems are allowed, deposits are not.
+    Sunsetting,
+    /// neither deposits nor redeems are allowed
+    Frozen,
+}
+
+impl Default for CollateralStatus {
+    fn default() -> Self {
+        CollateralStatus::Active
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Debug)]
+pub struct CollateralStats {
+    /// collateral deposited over the lifetime of the config, in base units
+    pub total_deposited: u128,
+    /// collateral redeemed over the lifetime of the config, in base units
+    pub total_redeemed: u128,
+    /// collateral the crate received from rebalances over the lifetime of the config, in base units
+    pub total_swapped_in: u128,
+    /// collateral the crate sent out in rebalances over the lifetime of the config, in base units
+    pub total_swapped_out: u128,
+    /// slot of the most recent deposit, redeem or rebalance of this collateral
+    pub last_activity_slot: u64,
+}
+
+impl CollateralConfig {
+    pub fn init(
+        &mut self,
+        bump: u8,
+        bucket: Pubkey,
+        mint: Pubkey,
+        decimals: u8,
+        oracle_source: OracleSource,
+        oracle: Pubkey,
+        caps: CollateralCaps,
+        fee_overrides: FeeOverrides,
+    ) -> ProgramResult {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.mint = mint;
+        self.decimals = decimals;
+        self.status = CollateralStatus::Active;
+        self.stats = CollateralStats::default();
+        self.update(oracle_source, oracle, caps, fee_overrides)
+    }
+
+    pub fn update(
+        &mut self,
+        oracle_source: OracleSource,
+        oracle: Pubkey,
+        caps: CollateralCaps,
+        fee_overrides: FeeOverrides,
+    ) -> ProgramResult {
+        // get_oracle_price can only read pyth oracles so far
+        invariant!(
+            oracle_source == OracleSource::Pyth,
+            ErrorCode::UnsupportedOracleSource
+        );
+
+        self.oracle_source = oracle_source;
+        self.oracle = oracle;
+        self.caps = caps;
+        self.fee_overrides = fee_overrides;
+
+        Ok(())
+    }
+
+    pub fn set_band(&mut self, band: AllocationBand) -> ProgramResult {
+        invariant!(
+            band.lower_bps <= MAX_BASIS_POINTS && band.upper_bps <= MAX_BASIS_POINTS,
+            ErrorCode::InvalidAllocationBand
+        );
+
+        self.band = band;
+
+        Ok(())
+    }
+
+    /// verify that a deposit of amount is allowed, given the crate's balance before the deposit,
+    /// and record it
+    pub fn record_deposit(&mut self, amount: u64, crate_balance: u64, slot: u64) -> ProgramResult {
+        self.verify_deposit(amount, crate_balance)?;
+
+        self.stats.total_deposited = self
+            .stats
+            .total_deposited
+            .checked_add(amount as u128)
+            .ok_or_else(math_error!())?;
+        self.stats.last_activity_slot = slot;
+
+        Ok(())
+    }
+
+    /// verify that a deposit of amount into a crate holding crate_balance is allowed
+    pub fn verify_deposit(&self, amount: u64, crate_balance: u64) -> ProgramResult {
+        invariant!(
+            self.status == CollateralStatus::Active,
+            ErrorCode::CollateralNotAcceptingDeposits
+        );
+
+        invariant!(
+            self.caps.max_deposit_amount == 0 || amount <= self.caps.max_deposit_amount,
+            ErrorCode::CollateralCapExceeded
+        );
+        let crate_balance_after_deposit = crate_balance
+            .checked_add(amount)
+            .ok_or_else(math_error!())?;
+        invariant!(
+            self.caps.max_crate_balance == 0
+                || crate_balance_after_deposit <= self.caps.max_crate_balance,
+            ErrorCode::CollateralCapExceeded
+        );
+
+        Ok(())
+    }
+
+    /// verify that a redeem of amount is allowed and record it
+    pub fn record_redeem(&mut self, amount: u64, slot: u64) -> ProgramResult {
+        self.verify_redeem()?;
+
+        self.stats.total_redeemed = self
+            .stats
+            .total_redeemed
+            .checked_add(amount as u128)
+            .ok_or_else(math_error!())?;
+        self.stats.last_activity_slot = slot;
+
+        Ok(())
+    }
+
+    /// verify that redeems of the collateral are allowed
+    pub fn verify_redeem(&self) -> ProgramResult {
+        invariant!(
+            self.status != CollateralStatus::Frozen,
+            ErrorCode::CollateralFrozen
+        );
+
+        Ok(())
+    }
+
+    /// record collateral the crate received from a rebalance
+    pub fn record_swap_in(&mut self, amount: u64, slot: u64) -> ProgramResult {
+        self.stats.total_swapped_in = self
+            .stats
+            .total_swapped_in
+            .checked_add(amount as u128)
+            .ok_or_else(math_error!())?;
+        self.stats.last_activity_slot = slot;
+
+        Ok(())
+    }
+
+    /// record collateral the crate sent out in a rebalance
+    pub fn record_swap_out(&mut self, amount: u64, slot: u64) -> ProgramResult {
+        self.stats.total_swapped_out = self
+            .stats
+            .total_swapped_out
+            .checked_add(amount as u128)
+            .ok_or_else(math_error!())?;
+        self.stats.last_activity_slot = slot;
+
+        Ok(())
+    }
+}
+
+pub const COLLATERAL_CONFIG_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // mint
+    32 +
+    // decimals
+    1 +
+    // oracle_source
+    1 +
+    // oracle
+    32 +
+    // caps
+    8 + 8 +
+    // fee_overrides
+    (1 + 2) + (1 + 2) +
+    // band
+    2 + 2 + 1 +
+    // status
+    1 +
+    // stats
+    16 + 16 + 16 + 16 + 8;
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+/// per-collateral config recording the saber decimal wrapper used when this collateral is swapped
+/// against collateral with more decimals. saber's stable swap invariant ignores decimals, so pools
+/// between mixed-decimal stables trade a wrapped token that adds the missing decimals. more details:
+/// https://docs.saber.so/docs/developing/decimal-wrappers
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct CollateralWrapper {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket this config belongs to
+    pub bucket: Pubkey,
+    /// Collateral mint that is wrapped
+    pub mint: Pubkey,
+    /// Decimal wrapper program
+    pub wrapper_program: Pubkey,
+    /// Wrapper PDA of the decimal wrapper program
+    pub wrapper: Pubkey,
+    /// Mint of the wrapped token
+    pub wrapper_mint: Pubkey,
+    /// Token account holding the wrapper's underlying collateral
+    pub wrapper_underlying_tokens: Pubkey,
+}
+
+impl CollateralWrapper {
+    pub fn init(
+        &mut self,
+        bump: u8,
+        bucket: Pubkey,
+        mint: Pubkey,
+        wrapper_program: Pubkey,
+        wrapper: Pubkey,
+        wrapper_mint: Pubkey,
+        wrapper_underlying_tokens: Pubkey,
+    ) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.mint = mint;
+        self.wrapper_program = wrapper_program;
+        self.wrapper = wrapper;
+        self.wrapper_mint = wrapper_mint;
+        self.wrapper_underlying_tokens = wrapper_underlying_tokens;
+    }
+}
+
+pub const COLLATERAL_WRAPPER_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // mint
+    32 +
+    // wrapper_program
+    32 +
+    // wrapper
+    32 +
+    // wrapper_mint
+    32 +
+    // wrapper_underlying_tokens
+    32;
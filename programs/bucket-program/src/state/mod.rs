@@ -1,4 +1,15 @@
+// program paths must return an error instead of panicking
+#![deny(clippy::unwrap_used, clippy::expect_used, clippy::panic)]
+
+pub mod allocation_ramp;
 pub mod bucket;
+pub mod bucket_stats;
+pub mod collateral_config;
+pub mod collateral_wrapper;
 pub mod issue_authority;
+pub mod nav_snapshot;
 pub mod oracle;
+pub mod proposal;
+pub mod rebalance_state;
+pub mod swap_pair;
 pub mod withdraw_authority;
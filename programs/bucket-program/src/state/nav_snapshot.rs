@@ -0,0 +1,96 @@
+use {
+    crate::{constant::NAV_SNAPSHOT_HISTORY_LEN, error::ErrorCode},
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+/// ring buffer of the bucket's most recent net asset value snapshots, written by snapshot_nav.
+/// other programs can read the latest snapshot as a price for the reserve token. per collateral
+/// weights are only emitted with the snapshot's event, since they do not fit in the history.
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct NavSnapshot {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket these snapshots belong to
+    pub bucket: Pubkey,
+    /// Index of the most recent snapshot. meaningless while there are no snapshots.
+    pub head: u16,
+    /// Up to NAV_SNAPSHOT_HISTORY_LEN snapshots. once full, the oldest snapshot is overwritten.
+    pub snapshots: Vec<Nav>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct Nav {
+    /// Slot the snapshot was taken in
+    pub slot: u64,
+    /// Unix timestamp the snapshot was taken at
+    pub unix_timestamp: i64,
+    /// Value of the bucket's collateral at oracle prices, in TARGET_ORACLE_PRECISION units
+    pub total_value: u128,
+    /// Reserve token supply, in base units
+    pub reserve_supply: u64,
+    /// Value backing one whole reserve token, in TARGET_ORACLE_PRECISION units. zero while there
+    /// is no reserve supply.
+    pub nav_per_token: u128,
+    /// Collateral value over the reserve supply valued at $1 per token, in bps. zero while there is
+    /// no reserve supply.
+    pub collateralization_bps: u64,
+}
+
+impl NavSnapshot {
+    pub fn init(&mut self, bump: u8, bucket: Pubkey) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.head = 0;
+        self.snapshots = Vec::new();
+    }
+
+    /// most recent snapshot, if any
+    pub fn latest(&self) -> Option<&Nav> {
+        self.snapshots.get(self.head as usize)
+    }
+
+    /// record a snapshot, overwriting the oldest one once the history is full. at most one
+    /// snapshot is taken per slot, so that the history cannot be flushed within a single slot.
+    pub fn record(&mut self, nav: Nav) -> ProgramResult {
+        if let Some(latest) = self.latest() {
+            invariant!(nav.slot > latest.slot, ErrorCode::NavSnapshotAlreadyTaken);
+        }
+
+        if self.snapshots.len() < NAV_SNAPSHOT_HISTORY_LEN {
+            self.snapshots.push(nav);
+            self.head = (self.snapshots.len() - 1) as u16;
+        } else {
+            self.head = ((self.head as usize + 1) % NAV_SNAPSHOT_HISTORY_LEN) as u16;
+            self.snapshots[self.head as usize] = nav;
+        }
+
+        Ok(())
+    }
+}
+
+pub const NAV_SNAPSHOT_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // head
+    2 +
+    // snapshots
+    4 + NAV_SNAPSHOT_HISTORY_LEN * (
+        // slot
+        8 +
+        // unix_timestamp
+        8 +
+        // total_value
+        16 +
+        // reserve_supply
+        8 +
+        // nav_per_token
+        16 +
+        // collateralization_bps
+        8
+    );
@@ -18,7 +18,7 @@ pub struct OraclePriceData {
 
 // inspired by https://github.com/drift-labs/protocol-v1/blob/f8c80cfe041bb3780928364ab17641e23dcd42bd/programs/clearing_house/src/state/state.rs#L51
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
 pub enum OracleSource {
     Pyth,
     Switchboard,
@@ -30,16 +30,17 @@ impl Default for OracleSource {
     }
 }
 
+/// read the price of the oracle with the source recorded in the collateral's config. switchboard
+/// is not supported yet, and collateral configs cannot select it.
 pub fn get_oracle_price(
     price_oracle: &AccountInfo,
+    oracle_source: OracleSource,
     clock_slot: u64,
     precision: u32,
 ) -> Result<OraclePriceData, ErrorCode> {
-    let default_oracle_source = OracleSource::Pyth;
-
-    let (price, twap, confidence, delay) = match default_oracle_source {
+    let (price, twap, confidence, delay) = match oracle_source {
         OracleSource::Pyth => get_pyth_price(price_oracle, clock_slot, precision)?,
-        OracleSource::Switchboard => (0, 0, 0, 0),
+        OracleSource::Switchboard => return Err(ErrorCode::UnsupportedOracleSource),
     };
 
     let result = OraclePriceData {
@@ -48,10 +49,7 @@ pub fn get_oracle_price(
         confidence,
         delay,
     };
-    require!(
-        is_oracle_valid(&result).unwrap() == true,
-        ErrorCode::InvalidOracle
-    );
+    require!(is_oracle_valid(&result)?, ErrorCode::InvalidOracle);
 
     Ok(result)
 }
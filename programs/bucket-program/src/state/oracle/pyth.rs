@@ -6,9 +6,8 @@ use {
         math_error,
     },
     anchor_lang::prelude::*,
-    bytemuck::{cast_slice_mut, from_bytes_mut, try_cast_slice_mut, Pod, Zeroable},
+    bytemuck::{from_bytes_mut, try_from_bytes, Pod, Zeroable},
     std::cell::RefMut,
-    std::convert::TryInto,
 };
 
 #[derive(Default, Copy, Clone)]
@@ -140,11 +139,12 @@ impl Price {
     #[inline]
     pub fn load<'a>(price_feed: &'a AccountInfo) -> Result<RefMut<'a, Price>, ProgramError> {
         let account_data: RefMut<'a, [u8]> =
-            RefMut::map(price_feed.try_borrow_mut_data().unwrap(), |data| *data);
+            RefMut::map(price_feed.try_borrow_mut_data()?, |data| *data);
 
-        let state: RefMut<'a, Self> = RefMut::map(account_data, |data| {
-            from_bytes_mut(cast_slice_mut::<u8, u8>(try_cast_slice_mut(data).unwrap()))
-        });
+        // from_bytes_mut panics if the data is not exactly one aligned price account
+        try_from_bytes::<Price>(&account_data).map_err(|_| ErrorCode::InvalidOracle)?;
+
+        let state: RefMut<'a, Self> = RefMut::map(account_data, |data| from_bytes_mut(data));
         Ok(state)
     }
 }
@@ -157,27 +157,14 @@ pub fn get_pyth_price(
     clock_slot: u64,
     target_precision: u32,
 ) -> Result<(i128, i128, u128, i64), ErrorCode> {
-    let price_account = Price::load(&price_oracle).unwrap();
+    let price_account = Price::load(&price_oracle).map_err(|_| ErrorCode::InvalidOracle)?;
 
     let oracle_price = cast_to_i128(price_account.agg.price)?;
     let oracle_conf = cast_to_u128(price_account.agg.conf)?;
-    let oracle_twap: i128 = cast_to_i128(price_account.twap)?.try_into().unwrap();
-
-    let orace_price_scaled: i128 = oracle_price
-        .checked_mul(10i128.pow(target_precision))
-        .unwrap()
-        .checked_div(10i128.pow((-price_account.expo).try_into().unwrap()))
-        .unwrap()
-        .try_into()
-        .unwrap();
-
-    let oracle_twap_scaled: i128 = (oracle_twap)
-        .checked_mul(10i128.pow(target_precision))
-        .unwrap()
-        .checked_div(10i128.pow((-price_account.expo).try_into().unwrap()))
-        .unwrap()
-        .try_into()
-        .unwrap();
+    let oracle_twap = cast_to_i128(price_account.twap)?;
+
+    let orace_price_scaled = scale_pyth_value(oracle_price, price_account.expo, target_precision)?;
+    let oracle_twap_scaled = scale_pyth_value(oracle_twap, price_account.expo, target_precision)?;
 
     let oracle_delay: i64 = cast_to_i64(clock_slot)?
         .checked_sub(cast(price_account.valid_slot)?)
@@ -190,3 +177,24 @@ pub fn get_pyth_price(
         oracle_delay,
     ))
 }
+
+/// value * 10^expo expressed with target_precision decimals. pyth only publishes negative
+/// exponents, so anything else is rejected rather than silently mis-scaled.
+fn scale_pyth_value(value: i128, expo: i32, target_precision: u32) -> Result<i128, ErrorCode> {
+    if expo > 0 {
+        return Err(ErrorCode::OracleExponentUnsupported);
+    }
+    let divisor = 10_i128
+        .checked_pow(expo.unsigned_abs())
+        .ok_or(ErrorCode::OracleExponentUnsupported)?;
+
+    value
+        .checked_mul(
+            10_i128
+                .checked_pow(target_precision)
+                .ok_or_else(math_error!())?,
+        )
+        .ok_or_else(math_error!())?
+        .checked_div(divisor)
+        .ok_or_else(math_error!())
+}
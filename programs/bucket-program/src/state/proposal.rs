@@ -0,0 +1,124 @@
+use {
+    crate::state::{
+        bucket::{Collateral, KeeperBounty},
+        collateral_config::{AllocationBand, CollateralCaps, FeeOverrides},
+        oracle::OracleSource,
+        swap_pair::SwapVenue,
+    },
+    anchor_lang::prelude::*,
+};
+
+/// a queued admin change. when the bucket's timelock is enabled, the authority enqueues changes
+/// instead of applying them directly. anyone can execute the proposal once eta_slot has passed,
+/// and the authority or the guardian can cancel it before then. this gives holders time to exit
+/// before a change they disagree with takes effect.
+#[account]
+#[derive(Debug, PartialEq)]
+pub struct Proposal {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket the action applies to
+    pub bucket: Pubkey,
+    /// Sequential id of the proposal within the bucket, used in PDA derivation
+    pub id: u64,
+    /// Account that enqueued the proposal and receives its rent back
+    pub proposer: Pubkey,
+    /// Earliest slot at which the proposal can be executed
+    pub eta_slot: u64,
+    /// Change to apply to the bucket
+    pub action: AdminAction,
+}
+
+/// admin changes that go through the timelock. each variant mirrors the arguments of the
+/// instruction that applies the change directly when the timelock is disabled. changes to accounts
+/// other than the bucket take those accounts as remaining accounts of execute_proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Debug)]
+pub enum AdminAction {
+    AuthorizeCollateral {
+        mint: Pubkey,
+        allocation: u32,
+    },
+    RemoveCollateral {
+        mint: Pubkey,
+    },
+    SetCollateralAllocations {
+        allocations: Vec<Collateral>,
+    },
+    UpdateCollateralSet {
+        collateral: Vec<Collateral>,
+    },
+    UpdateRebalanceAuthority {
+        rebalance_authority: Pubkey,
+    },
+    ConfigureTimelock {
+        delay_slots: u64,
+        guardian: Pubkey,
+    },
+    SetGuardian {
+        guardian: Pubkey,
+    },
+    SetKeeperBounty {
+        keeper_bounty: KeeperBounty,
+    },
+    UpdateCollateralConfig {
+        mint: Pubkey,
+        oracle_source: OracleSource,
+        oracle: Pubkey,
+        caps: CollateralCaps,
+        fee_overrides: FeeOverrides,
+    },
+    SetAllocationBand {
+        mint: Pubkey,
+        band: AllocationBand,
+    },
+    AddSwapPair {
+        bump: u8,
+        mint_a: Pubkey,
+        mint_b: Pubkey,
+        venue: SwapVenue,
+        swap_program: Pubkey,
+        swap: Pubkey,
+    },
+    RemoveSwapPair {
+        mint_a: Pubkey,
+        mint_b: Pubkey,
+    },
+}
+
+impl Proposal {
+    pub fn init(
+        &mut self,
+        bump: u8,
+        bucket: Pubkey,
+        id: u64,
+        proposer: Pubkey,
+        eta_slot: u64,
+        action: AdminAction,
+    ) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.id = id;
+        self.proposer = proposer;
+        self.eta_slot = eta_slot;
+        self.action = action;
+    }
+
+    /// the action is variable length, so the account is sized to the action it stores
+    pub fn space(action: &AdminAction) -> usize {
+        PROPOSAL_BASE_ACCOUNT_SPACE + action.try_to_vec().map_or(0, |action| action.len())
+    }
+}
+
+pub const PROPOSAL_BASE_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // id
+    8 +
+    // proposer
+    32 +
+    // eta_slot
+    8;
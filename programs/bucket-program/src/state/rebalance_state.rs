@@ -0,0 +1,212 @@
+use {
+    crate::{
+        constant::{
+            DEFAULT_MAX_TURNOVER_BPS, DEFAULT_MIN_SLOTS_BETWEEN_REBALANCES,
+            DEFAULT_TURNOVER_WINDOW_SLOTS, MAX_BASIS_POINTS,
+        },
+        error::ErrorCode,
+        math::decimal::{mul_div, Rounding},
+        math_error,
+        util::apply_bps,
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+/// tracks how often and how much a bucket has been rebalanced. every rebalance instruction records
+/// itself here, which bounds the damage a compromised rebalance authority can do: at most
+/// max_turnover_bps of the bucket's value can be swapped in any trailing turnover window, and
+/// consecutive rebalances must be at least min_slots_between_rebalances apart. the rebalance
+/// authority has its own cooldown, so that permissionless callers cannot hold it off by
+/// rebalancing first.
+///
+/// turnover is kept in two consecutive fixed windows. the trailing window's turnover is estimated
+/// as the current window's turnover plus the previous window's, weighted by how much of the previous
+/// window still overlaps the trailing window. unlike a single window that resets, this does not
+/// allow twice the limit to be swapped around a window boundary.
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct RebalanceState {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket this state belongs to
+    pub bucket: Pubkey,
+    /// Limits configured by the bucket authority
+    pub limits: RebalanceLimits,
+    /// Slot of the most recent rebalance by a caller other than the rebalance authority
+    pub last_rebalance_slot: u64,
+    /// Slot of the most recent rebalance by the rebalance authority
+    pub last_authority_rebalance_slot: u64,
+    /// Slot at which the current turnover window started. always a multiple of the window length,
+    /// unless the window length changed.
+    pub window_start_slot: u64,
+    /// Value swapped during the current window, in TARGET_ORACLE_PRECISION units
+    pub window_turnover: u128,
+    /// Value swapped during the window before the current one, in TARGET_ORACLE_PRECISION units
+    pub previous_window_turnover: u128,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub struct RebalanceLimits {
+    /// minimum number of slots between two rebalances by the same kind of caller, i.e. the rebalance
+    /// authority or anyone else
+    pub min_slots_between_rebalances: u64,
+    /// length of the trailing turnover window in slots
+    pub turnover_window_slots: u64,
+    /// max value swapped in the trailing window, in bps of the bucket's total collateral value
+    pub max_turnover_bps: u16,
+}
+
+impl Default for RebalanceLimits {
+    fn default() -> Self {
+        RebalanceLimits {
+            min_slots_between_rebalances: DEFAULT_MIN_SLOTS_BETWEEN_REBALANCES,
+            turnover_window_slots: DEFAULT_TURNOVER_WINDOW_SLOTS,
+            max_turnover_bps: DEFAULT_MAX_TURNOVER_BPS,
+        }
+    }
+}
+
+impl RebalanceState {
+    pub fn init(&mut self, bump: u8, bucket: Pubkey) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.limits = RebalanceLimits::default();
+        self.last_rebalance_slot = 0;
+        self.last_authority_rebalance_slot = 0;
+        self.window_start_slot = 0;
+        self.window_turnover = 0;
+        self.previous_window_turnover = 0;
+    }
+
+    pub fn set_limits(&mut self, limits: RebalanceLimits) -> ProgramResult {
+        invariant!(
+            limits.turnover_window_slots > 0 && limits.max_turnover_bps <= MAX_BASIS_POINTS,
+            ErrorCode::InvalidRebalanceLimits
+        );
+
+        self.limits = limits;
+
+        Ok(())
+    }
+
+    /// record a rebalance that sold collateral worth value, at oracle prices, from a bucket worth
+    /// total_value. fails if the caller's previous rebalance is too recent, or if the swap pushes
+    /// the trailing window's turnover above the configured limit.
+    pub fn record_rebalance(
+        &mut self,
+        slot: u64,
+        value: u128,
+        total_value: u128,
+        caller_is_rebalance_authority: bool,
+    ) -> ProgramResult {
+        let last_rebalance_slot = if caller_is_rebalance_authority {
+            &mut self.last_authority_rebalance_slot
+        } else {
+            &mut self.last_rebalance_slot
+        };
+        // the very first rebalance is never subject to the cooldown
+        if *last_rebalance_slot > 0 {
+            let slots_since_last_rebalance = slot
+                .checked_sub(*last_rebalance_slot)
+                .ok_or_else(math_error!())?;
+            invariant!(
+                slots_since_last_rebalance >= self.limits.min_slots_between_rebalances,
+                ErrorCode::RebalanceCooldownActive
+            );
+        }
+        *last_rebalance_slot = slot;
+
+        self.roll_turnover_window(slot)?;
+        let window_turnover = self
+            .window_turnover
+            .checked_add(value)
+            .ok_or_else(math_error!())?;
+        let trailing_turnover = window_turnover
+            .checked_add(self.previous_window_overlap(slot)?)
+            .ok_or_else(math_error!())?;
+        let max_turnover = apply_bps(total_value, self.limits.max_turnover_bps, Rounding::Down)?;
+
+        msg!(
+            "rebalance turnover in trailing window ending at slot {}: {} of max {}",
+            slot,
+            trailing_turnover,
+            max_turnover
+        );
+
+        invariant!(
+            trailing_turnover <= max_turnover,
+            ErrorCode::RebalanceTurnoverExceeded
+        );
+
+        self.window_turnover = window_turnover;
+
+        Ok(())
+    }
+
+    /// start a new window if slot is past the current one. the current window's turnover becomes
+    /// the previous window's, unless a whole window passed without rebalances.
+    fn roll_turnover_window(&mut self, slot: u64) -> ProgramResult {
+        let window_slots = self.limits.turnover_window_slots;
+        let elapsed = slot.saturating_sub(self.window_start_slot);
+        if elapsed < window_slots {
+            return Ok(());
+        }
+
+        let windows_elapsed = elapsed
+            .checked_div(window_slots)
+            .ok_or_else(math_error!())?;
+        self.previous_window_turnover = if windows_elapsed == 1 {
+            self.window_turnover
+        } else {
+            0
+        };
+        self.window_turnover = 0;
+        self.window_start_slot = self
+            .window_start_slot
+            .checked_add(
+                windows_elapsed
+                    .checked_mul(window_slots)
+                    .ok_or_else(math_error!())?,
+            )
+            .ok_or_else(math_error!())?;
+
+        Ok(())
+    }
+
+    /// part of the previous window's turnover that falls in the trailing window ending at slot,
+    /// assuming it was swapped evenly over the previous window. rounded up so that turnover is
+    /// never undercounted.
+    fn previous_window_overlap(&self, slot: u64) -> Result<u128, ProgramError> {
+        let window_slots = self.limits.turnover_window_slots;
+        let overlap_slots =
+            window_slots.saturating_sub(slot.saturating_sub(self.window_start_slot));
+
+        Ok(mul_div(
+            self.previous_window_turnover,
+            overlap_slots as u128,
+            window_slots as u128,
+            Rounding::Up,
+        )?)
+    }
+}
+
+pub const REBALANCE_STATE_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // limits
+    8 + 8 + 2 +
+    // last_rebalance_slot
+    8 +
+    // last_authority_rebalance_slot
+    8 +
+    // window_start_slot
+    8 +
+    // window_turnover
+    16 +
+    // previous_window_turnover
+    16;
@@ -0,0 +1,106 @@
+use {crate::error::ErrorCode, anchor_lang::prelude::*, vipers::invariant};
+
+/// a collateral pair the bucket authority has whitelisted for rebalancing, along with the venue,
+/// program and pool that rebalances of this pair must be routed through. the bucket signs swaps
+/// with its PDA seeds, so rebalance refuses any program or pool not recorded here. mints are stored
+/// in canonical order, i.e. mint_a < mint_b, so that a single PDA covers swaps in both directions.
+#[account]
+#[derive(Debug, Default, PartialEq)]
+pub struct SwapPair {
+    /// Bump.
+    pub bump: u8,
+    /// Bucket this pair belongs to
+    pub bucket: Pubkey,
+    /// Mint with the lower pubkey of the pair
+    pub mint_a: Pubkey,
+    /// Mint with the higher pubkey of the pair
+    pub mint_b: Pubkey,
+    /// Venue that swaps between mint_a and mint_b are executed on
+    pub venue: SwapVenue,
+    /// Program that rebalance is allowed to invoke for this pair
+    pub swap_program: Pubkey,
+    /// Pool account of the swap program, e.g. saber's swap account
+    pub swap: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug)]
+pub enum SwapVenue {
+    /// saber stable swap pools
+    Saber,
+    /// generic constant-product (x * y = k) pools
+    ConstantProduct,
+}
+
+impl Default for SwapVenue {
+    fn default() -> Self {
+        SwapVenue::Saber
+    }
+}
+
+impl SwapVenue {
+    /// number of remaining accounts needed to parse the venue's swap accounts
+    pub fn num_accounts(&self) -> usize {
+        match self {
+            SwapVenue::Saber => 5,
+            SwapVenue::ConstantProduct => 4,
+        }
+    }
+}
+
+impl SwapPair {
+    pub fn init(
+        &mut self,
+        bump: u8,
+        bucket: Pubkey,
+        mint_a: Pubkey,
+        mint_b: Pubkey,
+        venue: SwapVenue,
+        swap_program: Pubkey,
+        swap: Pubkey,
+    ) {
+        self.bump = bump;
+        self.bucket = bucket;
+        self.mint_a = mint_a;
+        self.mint_b = mint_b;
+        self.venue = venue;
+        self.swap_program = swap_program;
+        self.swap = swap;
+    }
+
+    /// saber pools can only live under the saber program. constant-product venues are generic, so
+    /// the authority is trusted to register a program that implements the venue interface.
+    pub fn verify_venue(venue: SwapVenue, swap_program: Pubkey) -> ProgramResult {
+        if venue == SwapVenue::Saber {
+            invariant!(
+                swap_program == stable_swap_anchor::ID,
+                ErrorCode::UnregisteredSwapProgram
+            );
+        }
+
+        Ok(())
+    }
+
+    /// true if this pair covers a swap between the given mints, in either direction
+    pub fn matches(&self, source_mint: Pubkey, dest_mint: Pubkey) -> bool {
+        (self.mint_a == source_mint && self.mint_b == dest_mint)
+            || (self.mint_a == dest_mint && self.mint_b == source_mint)
+    }
+}
+
+pub const SWAP_PAIR_ACCOUNT_SPACE: usize =
+    // discriminator
+    8 +
+    // bump
+    1 +
+    // bucket
+    32 +
+    // mint_a
+    32 +
+    // mint_b
+    32 +
+    // venue
+    1 +
+    // swap_program
+    32 +
+    // swap
+    32;
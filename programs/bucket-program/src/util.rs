@@ -1,77 +1,429 @@
 use {
-    crate::{constant::MAX_BASIS_POINTS, error::ErrorCode, state::bucket::Collateral},
-    anchor_lang::prelude::*,
+    crate::{
+        constant::{
+            ALLOCATION_PPM_PER_BPS, MAX_ALLOCATION_PPM, MAX_BASIS_POINTS, TARGET_ORACLE_PRECISION,
+        },
+        context::CollateralValuationAsset,
+        error::ErrorCode,
+        math::{
+            casting::{cast, cast_to_u128},
+            decimal::{mul_div, Decimal, Rounding},
+        },
+        math_error,
+        state::{
+            bucket::Collateral,
+            collateral_config::AllocationBand,
+            oracle::{get_oracle_price, OraclePriceData},
+        },
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            account_info::next_account_infos,
+            entrypoint::MAX_PERMITTED_DATA_INCREASE,
+            program::{invoke, invoke_signed},
+            system_instruction,
+        },
+    },
     std::cmp::Ordering,
+    vipers::invariant,
 };
 
-pub fn sum_allocations(collateral: &Vec<Collateral>) -> std::result::Result<u16, ErrorCode> {
-    let mut total_allocation: u16 = 0;
+pub fn sum_allocations(collateral: &[Collateral]) -> std::result::Result<u32, ErrorCode> {
+    let total_allocation = collateral.iter().try_fold(0_u32, |sum, el| {
+        sum.checked_add(el.allocation).ok_or_else(math_error!())
+    })?;
 
-    if collateral.len() > 0 {
-        total_allocation = collateral
-            .iter()
-            .map(|el| el.allocation)
-            .reduce(|a, b| a + b)
-            .unwrap();
-    }
-
-    if total_allocation > MAX_BASIS_POINTS {
-        msg!("Allocation sum exceeds max bps");
-        return Err(ErrorCode::AllocationBpsError.into());
+    if total_allocation > MAX_ALLOCATION_PPM {
+        msg!("Allocation sum exceeds max ppm");
+        return Err(ErrorCode::AllocationPpmError.into());
     }
 
     Ok(total_allocation)
 }
 
-pub fn is_collateral_authorized(collateral: &Vec<Collateral>, mint: Pubkey) -> bool {
-    return collateral.iter().filter(|&el| el.mint == mint).count() == 1;
+/// collateral must be sorted by mint, which the bucket guarantees for its authorized collateral
+pub fn is_collateral_authorized(collateral: &[Collateral], mint: Pubkey) -> bool {
+    get_collateral_idx(collateral, mint).is_ok()
+}
+
+/// collateral must be sorted by mint, which the bucket guarantees for its authorized collateral
+pub fn get_collateral_idx(collateral: &[Collateral], target: Pubkey) -> Result<usize, ErrorCode> {
+    collateral
+        .binary_search_by_key(&target, |x| x.mint)
+        .ok()
+        .ok_or(ErrorCode::CollateralDoesNotExistError)
 }
 
-pub fn get_collateral_idx(
-    collateral: &Vec<Collateral>,
-    target: Pubkey,
-) -> Result<usize, ErrorCode> {
-    match collateral.iter().position(|&x| x.mint == target) {
-        Some(index) => Ok(index),
-        // todo: does this throw an err?
-        None => return Err(ErrorCode::CollateralDoesNotExistError.into()),
+/// amount of a token with decimals_a expressed in units of a token with decimals_b. scaling down
+/// rounds down.
+pub fn scale_amount_for_decimals(
+    amount: u64,
+    decimals_a: u8,
+    decimals_b: u8,
+) -> Result<u64, ErrorCode> {
+    let scaled_amount = match decimals_a.cmp(&decimals_b) {
+        Ordering::Equal => Some(amount),
+        Ordering::Less => 10u64
+            .checked_pow((decimals_b - decimals_a).into())
+            .and_then(|factor| amount.checked_mul(factor)),
+        Ordering::Greater => 10u64
+            .checked_pow((decimals_a - decimals_b).into())
+            .and_then(|factor| amount.checked_div(factor)),
+    };
+
+    scaled_amount.ok_or(ErrorCode::DecimalScalingOverflow)
+}
+
+/// value of a collateral position, denominated in TARGET_ORACLE_PRECISION units, e.g. an oracle
+/// price of 1_000_000 means the collateral is worth $1. all collateral values across a bucket are
+/// computed with the same precision, so they can be compared and summed directly.
+#[derive(Clone, Copy, Debug)]
+pub struct CollateralValue {
+    pub mint: Pubkey,
+    pub crate_collateral: Pubkey,
+    pub decimals: u8,
+    pub price: i128,
+    pub amount: u64,
+    pub value: u128,
+    pub allocation: u32,
+    pub target_value: u128,
+    pub band: AllocationBand,
+    /// lowest value that is within the collateral's allocation band
+    pub min_value: u128,
+    /// highest value that is within the collateral's allocation band
+    pub max_value: u128,
+}
+
+impl CollateralValue {
+    /// absolute distance between the collateral's current value and its target value.
+    pub fn deviation(&self) -> u128 {
+        absolute_difference(self.value, self.target_value)
+    }
+
+    pub fn is_above_band(&self) -> bool {
+        self.value > self.max_value
+    }
+
+    pub fn is_below_band(&self) -> bool {
+        self.value < self.min_value
+    }
+
+    pub fn is_out_of_band(&self) -> bool {
+        self.is_above_band() || self.is_below_band()
+    }
+
+    /// replace the collateral's balance and revalue it at the same oracle price. the target value
+    /// and band are stale until they are recomputed with update_target_values.
+    pub fn set_amount(&mut self, amount: u64) -> Result<(), ErrorCode> {
+        self.amount = amount;
+        self.value = compute_collateral_value(amount, self.decimals, self.price, Rounding::Down)?;
+
+        Ok(())
     }
 }
 
-pub fn get_divisor(n: u64) -> Result<u64, ErrorCode> {
-    let mut divisor: u64 = 1;
+/// number of accounts needed to parse a single [CollateralValuationAsset]
+pub const ACCOUNTS_PER_COLLATERAL_VALUATION: usize = 4;
+
+/// parse remaining accounts into (mint, crate collateral ATA, oracle, collateral config) groups and
+/// compute each collateral's value, target value and band. the groups must be supplied in the same
+/// order as the bucket's collateral, i.e. sorted by mint, so that we know every authorized
+/// collateral is accounted for.
+pub fn collateral_values_from_account_infos<'a, 'info>(
+    remaining_accounts: &'a [AccountInfo<'info>],
+    collateral: &[Collateral],
+    bucket: &Pubkey,
+    crate_token: &Pubkey,
+    clock_slot: u64,
+) -> Result<Vec<CollateralValue>, ProgramError> {
+    invariant!(
+        remaining_accounts.len() == collateral.len() * ACCOUNTS_PER_COLLATERAL_VALUATION,
+        ErrorCode::CollateralValuationAccountsMismatch
+    );
+
+    let mut collateral_values: Vec<CollateralValue> = Vec::with_capacity(collateral.len());
+    let remaining_accounts_iter = &mut remaining_accounts.iter();
+    for el in collateral.iter() {
+        let asset: CollateralValuationAsset = Accounts::try_accounts(
+            &crate::ID,
+            &mut next_account_infos(remaining_accounts_iter, ACCOUNTS_PER_COLLATERAL_VALUATION)?,
+            &[],
+        )?;
+
+        invariant!(
+            asset.mint.key() == el.mint && asset.crate_collateral.owner == *crate_token,
+            ErrorCode::CollateralValuationAccountsMismatch
+        );
+        invariant!(
+            asset.collateral_config.bucket == *bucket
+                && asset.collateral_config.mint == el.mint
+                && asset.collateral_config.oracle == asset.oracle.key(),
+            ErrorCode::InvalidCollateralConfig
+        );
+
+        let oracle_price_data: OraclePriceData = get_oracle_price(
+            &asset.oracle,
+            asset.collateral_config.oracle_source,
+            clock_slot,
+            TARGET_ORACLE_PRECISION,
+        )?;
 
-    // no need to check n >= 0 because u64 type limits
-    if n > 0 && n < 10 {
-        divisor = 1
+        collateral_values.push(CollateralValue {
+            mint: el.mint,
+            crate_collateral: asset.crate_collateral.key(),
+            decimals: asset.mint.decimals,
+            price: oracle_price_data.price,
+            amount: asset.crate_collateral.amount,
+            value: compute_collateral_value(
+                asset.crate_collateral.amount,
+                asset.mint.decimals,
+                oracle_price_data.price,
+                Rounding::Down,
+            )?,
+            allocation: el.allocation,
+            target_value: 0,
+            band: asset.collateral_config.band,
+            min_value: 0,
+            max_value: 0,
+        });
     }
-    if n >= 10 && n < 100 {
-        divisor = 10
+    update_target_values(&mut collateral_values)?;
+
+    Ok(collateral_values)
+}
+
+/// recompute every collateral's target value and band from the total value of all collateral
+pub fn update_target_values(collateral_values: &mut Vec<CollateralValue>) -> Result<(), ErrorCode> {
+    let total_value = sum_collateral_values(collateral_values)?;
+    for collateral_value in collateral_values.iter_mut() {
+        collateral_value.target_value =
+            compute_target_value(total_value, collateral_value.allocation)?;
+        let (min_value, max_value) = compute_band_values(
+            total_value,
+            collateral_value.allocation,
+            &collateral_value.band,
+        )?;
+        collateral_value.min_value = min_value;
+        collateral_value.max_value = max_value;
     }
-    if n >= 100 && n < 1000 {
-        divisor = 100
+
+    Ok(())
+}
+
+/// lowest and highest value of a collateral that are within its allocation band, given the bucket's
+/// total value. bands are configured in bps, allocations are in ppm.
+pub fn compute_band_values(
+    total_value: u128,
+    allocation: u32,
+    band: &AllocationBand,
+) -> Result<(u128, u128), ErrorCode> {
+    let lower = (band.lower_bps as u32)
+        .checked_mul(ALLOCATION_PPM_PER_BPS)
+        .ok_or_else(math_error!())?;
+    let upper = (band.upper_bps as u32)
+        .checked_mul(ALLOCATION_PPM_PER_BPS)
+        .ok_or_else(math_error!())?;
+
+    Ok((
+        compute_target_value(total_value, allocation.saturating_sub(lower))?,
+        compute_target_value(total_value, allocation.saturating_add(upper))?,
+    ))
+}
+
+pub fn sum_collateral_values(collateral_values: &Vec<CollateralValue>) -> Result<u128, ErrorCode> {
+    collateral_values.iter().try_fold(0_u128, |sum, el| {
+        sum.checked_add(el.value).ok_or_else(math_error!())
+    })
+}
+
+/// total deviation is the sum of every collateral's distance from its target value. a perfectly
+/// balanced bucket has a total deviation of zero.
+pub fn sum_collateral_deviations(
+    collateral_values: &Vec<CollateralValue>,
+) -> Result<u128, ErrorCode> {
+    collateral_values.iter().try_fold(0_u128, |sum, el| {
+        sum.checked_add(el.deviation()).ok_or_else(math_error!())
+    })
+}
+
+/// value = amount * price / 10^decimals
+pub fn compute_collateral_value(
+    amount: u64,
+    decimals: u8,
+    price: i128,
+    rounding: Rounding,
+) -> Result<u128, ErrorCode> {
+    let amount = Decimal::from_scaled(amount as u128, decimals as u32, rounding)?;
+    let price = Decimal::from_scaled(cast_to_u128(price)?, TARGET_ORACLE_PRECISION, rounding)?;
+
+    amount
+        .checked_mul(price, rounding)?
+        .to_scaled(TARGET_ORACLE_PRECISION, rounding)
+}
+
+/// amount = value * 10^decimals / price. inverse of compute_collateral_value.
+pub fn compute_collateral_amount(
+    value: u128,
+    decimals: u8,
+    price: i128,
+    rounding: Rounding,
+) -> Result<u64, ErrorCode> {
+    let price = Decimal::from_scaled(cast_to_u128(price)?, TARGET_ORACLE_PRECISION, rounding)?;
+    invariant!(price > Decimal::zero(), ErrorCode::InvalidOracle);
+    let value = Decimal::from_scaled(value, TARGET_ORACLE_PRECISION, rounding)?;
+
+    cast(
+        value
+            .checked_div(price, rounding)?
+            .to_scaled(decimals as u32, rounding)?,
+    )
+}
+
+/// target_value = total_value * allocation / MAX_ALLOCATION_PPM, rounded down
+pub fn compute_target_value(total_value: u128, allocation: u32) -> Result<u128, ErrorCode> {
+    mul_div(
+        total_value,
+        allocation as u128,
+        MAX_ALLOCATION_PPM as u128,
+        Rounding::Down,
+    )
+}
+
+/// each value's share of the sum of values in bps, rounded down. weights are reported in bps rather
+/// than ppm so that the weights of a full bucket fit in an instruction's return data.
+pub fn compute_weights_bps(values: &[u128]) -> Result<Vec<u16>, ErrorCode> {
+    let total_value = values.iter().try_fold(0_u128, |sum, value| {
+        sum.checked_add(*value).ok_or_else(math_error!())
+    })?;
+    if total_value == 0 {
+        return Ok(vec![0; values.len()]);
     }
-    if n >= 1000 && n < 10000 {
-        divisor = 1000
+
+    values
+        .iter()
+        .map(|value| {
+            cast(mul_div(
+                *value,
+                MAX_BASIS_POINTS as u128,
+                total_value,
+                Rounding::Down,
+            )?)
+        })
+        .collect()
+}
+
+/// amount * bps / MAX_BASIS_POINTS
+pub fn apply_bps(amount: u128, bps: u16, rounding: Rounding) -> Result<u128, ErrorCode> {
+    mul_div(amount, bps as u128, MAX_BASIS_POINTS as u128, rounding)
+}
+
+pub fn absolute_difference(a: u128, b: u128) -> u128 {
+    if a > b {
+        a - b
+    } else {
+        b - a
     }
-    if n >= 10000 && n < 100000 {
-        divisor = 10000
+}
+
+/// resize a program-owned account to new_len bytes while keeping it rent exempt. when the account
+/// grows, payer covers the extra rent. when it shrinks, the excess rent is refunded to payer.
+pub fn resize_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> ProgramResult {
+    let current_len = account.data_len();
+    invariant!(
+        new_len <= current_len.saturating_add(MAX_PERMITTED_DATA_INCREASE),
+        ErrorCode::AccountResizeTooLarge
+    );
+
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account.lamports();
+    if rent_exempt_lamports > current_lamports {
+        invoke(
+            &system_instruction::transfer(
+                payer.key,
+                account.key,
+                rent_exempt_lamports - current_lamports,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
+    } else if current_lamports > rent_exempt_lamports {
+        let excess_lamports = current_lamports - rent_exempt_lamports;
+        **account.try_borrow_mut_lamports()? -= excess_lamports;
+        **payer.try_borrow_mut_lamports()? = payer
+            .lamports()
+            .checked_add(excess_lamports)
+            .ok_or_else(math_error!())?;
     }
-    if n >= 100000 {
-        return Err(ErrorCode::NumberOfSizeNotSupported);
+
+    msg!(
+        "resizing account {} from {} to {} bytes",
+        account.key,
+        current_len,
+        new_len
+    );
+
+    account.realloc(new_len, true)
+}
+
+/// create a PDA owned by this program with space bytes, funded by payer. anyone can transfer
+/// lamports to the PDA's address ahead of time, so the account is created with transfer, allocate
+/// and assign rather than create_account, which fails on a funded address.
+pub fn create_pda_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let rent_exempt_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = account.lamports();
+    if rent_exempt_lamports > current_lamports {
+        invoke(
+            &system_instruction::transfer(
+                payer.key,
+                account.key,
+                rent_exempt_lamports - current_lamports,
+            ),
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
     }
 
-    Ok(divisor)
+    invoke_signed(
+        &system_instruction::allocate(account.key, space as u64),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(account.key, &crate::ID),
+        &[account.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+
+    Ok(())
 }
 
-pub fn scale_amount_for_decimals(amount: u64, decimals_a: u8, decimals_b: u8) -> Option<u64> {
-    match decimals_a.cmp(&decimals_b) {
-        Ordering::Equal => amount.into(),
-        Ordering::Less => {
-            amount.checked_mul(10u64.checked_pow(decimals_b.checked_sub(decimals_a)?.into())?)
-        }
-        Ordering::Greater => {
-            amount.checked_div(10u64.checked_pow(decimals_a.checked_sub(decimals_b)?.into())?)
-        }
+/// close a program-owned account and send its rent to destination. accounts that were never
+/// initialized are left alone, since some PDAs are only created on first use.
+pub fn close_account_if_initialized<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> ProgramResult {
+    if account.owner != &crate::ID || account.data_is_empty() {
+        return Ok(());
     }
+
+    let destination_lamports = destination
+        .lamports()
+        .checked_add(account.lamports())
+        .ok_or_else(math_error!())?;
+    **destination.lamports.borrow_mut() = destination_lamports;
+    **account.lamports.borrow_mut() = 0;
+    account.try_borrow_mut_data()?.fill(0);
+
+    Ok(())
 }
@@ -0,0 +1,83 @@
+use {
+    crate::{
+        context::{ConstantProductSwapAsset, Rebalance},
+        error::ErrorCode,
+        state::swap_pair::SwapPair,
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            account_info::next_account_infos,
+            instruction::{AccountMeta, Instruction},
+            program::invoke_signed,
+        },
+    },
+    vipers::invariant,
+};
+
+/// a constant-product venue exposes an anchor instruction named `swap` with the following
+/// interface, e.g. see the mock-amm program used in localnet tests:
+///
+/// accounts: [pool, pool_authority, user_authority (signer), user_source (mut),
+///            user_destination (mut), input_reserve (mut), output_reserve (mut), token_program]
+/// data:     discriminator || amount_in (u64 le) || minimum_amount_out (u64 le)
+///
+/// the discriminator is the first 8 bytes of sha256("global:swap").
+pub const SWAP_INSTRUCTION_DISCRIMINATOR: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+
+pub fn swap<'info>(
+    accounts: &Rebalance<'info>,
+    swap_pair: &SwapPair,
+    swap_program: &AccountInfo<'info>,
+    user_source: &AccountInfo<'info>,
+    user_destination: &AccountInfo<'info>,
+    venue_accounts: &[AccountInfo<'info>],
+    amount_in: u64,
+    minimum_amount_out: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let pool: ConstantProductSwapAsset = Accounts::try_accounts(
+        &crate::ID,
+        &mut next_account_infos(&mut venue_accounts.iter(), venue_accounts.len())?,
+        &[],
+    )?;
+    invariant!(
+        pool.pool.key() == swap_pair.swap,
+        ErrorCode::UnregisteredSwapPool
+    );
+
+    let mut data: Vec<u8> = SWAP_INSTRUCTION_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&amount_in.to_le_bytes());
+    data.extend_from_slice(&minimum_amount_out.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: swap_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(pool.pool.key(), false),
+            AccountMeta::new_readonly(pool.pool_authority.key(), false),
+            AccountMeta::new_readonly(accounts.bucket.key(), true),
+            AccountMeta::new(user_source.key(), false),
+            AccountMeta::new(user_destination.key(), false),
+            AccountMeta::new(pool.input_reserve.key(), false),
+            AccountMeta::new(pool.output_reserve.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            pool.pool.to_account_info(),
+            pool.pool_authority.to_account_info(),
+            accounts.bucket.to_account_info(),
+            user_source.clone(),
+            user_destination.clone(),
+            pool.input_reserve.to_account_info(),
+            pool.output_reserve.to_account_info(),
+            accounts.token_program.to_account_info(),
+            swap_program.clone(),
+        ],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,98 @@
+use {
+    crate::context::{DecimalWrapperAsset, Rebalance},
+    anchor_lang::{
+        prelude::*,
+        solana_program::{
+            instruction::{AccountMeta, Instruction},
+            program::invoke_signed,
+        },
+    },
+};
+
+/// saber's decimal wrapper (add-decimals) program exposes deposit and withdraw_all instructions
+/// that share the following accounts:
+///
+/// accounts: [wrapper, wrapper_mint (mut), wrapper_underlying_tokens (mut), owner (signer),
+///            user_underlying_tokens (mut), user_wrapped_tokens (mut), token_program]
+///
+/// the discriminators are the first 8 bytes of sha256("global:deposit") and
+/// sha256("global:withdraw_all"), respectively. source:
+/// https://github.com/saber-hq/saber-periphery/tree/master/programs/add-decimals
+pub const DEPOSIT_INSTRUCTION_DISCRIMINATOR: [u8; 8] = [242, 35, 198, 137, 82, 225, 242, 182];
+pub const WITHDRAW_ALL_INSTRUCTION_DISCRIMINATOR: [u8; 8] = [96, 246, 166, 130, 229, 50, 43, 70];
+
+/// wrap amount of the underlying collateral held in user_underlying_tokens into the bucket's
+/// wrapped token account.
+pub fn wrap<'info>(
+    accounts: &Rebalance<'info>,
+    wrapper: &DecimalWrapperAsset<'info>,
+    user_underlying_tokens: &AccountInfo<'info>,
+    amount: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let mut data: Vec<u8> = DEPOSIT_INSTRUCTION_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&amount.to_le_bytes());
+
+    invoke_user_stake(
+        accounts,
+        wrapper,
+        user_underlying_tokens,
+        data,
+        signer_seeds,
+    )
+}
+
+/// unwrap every token in the bucket's wrapped token account back into user_underlying_tokens.
+pub fn unwrap_all<'info>(
+    accounts: &Rebalance<'info>,
+    wrapper: &DecimalWrapperAsset<'info>,
+    user_underlying_tokens: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let data: Vec<u8> = WITHDRAW_ALL_INSTRUCTION_DISCRIMINATOR.to_vec();
+
+    invoke_user_stake(
+        accounts,
+        wrapper,
+        user_underlying_tokens,
+        data,
+        signer_seeds,
+    )
+}
+
+fn invoke_user_stake<'info>(
+    accounts: &Rebalance<'info>,
+    wrapper: &DecimalWrapperAsset<'info>,
+    user_underlying_tokens: &AccountInfo<'info>,
+    data: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let ix = Instruction {
+        program_id: wrapper.wrapper_program.key(),
+        accounts: vec![
+            AccountMeta::new_readonly(wrapper.wrapper.key(), false),
+            AccountMeta::new(wrapper.wrapper_mint.key(), false),
+            AccountMeta::new(wrapper.wrapper_underlying_tokens.key(), false),
+            AccountMeta::new_readonly(accounts.bucket.key(), true),
+            AccountMeta::new(user_underlying_tokens.key(), false),
+            AccountMeta::new(wrapper.bucket_wrapped_ata.key(), false),
+            AccountMeta::new_readonly(accounts.token_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            wrapper.wrapper.to_account_info(),
+            wrapper.wrapper_mint.to_account_info(),
+            wrapper.wrapper_underlying_tokens.to_account_info(),
+            accounts.bucket.to_account_info(),
+            user_underlying_tokens.clone(),
+            wrapper.bucket_wrapped_ata.to_account_info(),
+            accounts.token_program.to_account_info(),
+            wrapper.wrapper_program.to_account_info(),
+        ],
+        signer_seeds,
+    )
+}
@@ -0,0 +1,66 @@
+pub mod constant_product;
+pub mod decimal_wrapper;
+pub mod saber;
+
+use {
+    crate::{
+        context::Rebalance,
+        error::ErrorCode,
+        state::swap_pair::{SwapPair, SwapVenue},
+    },
+    anchor_lang::prelude::*,
+    vipers::invariant,
+};
+
+/// swap amount_in of user_source's token for at least minimum_amount_out of user_destination's
+/// token on the swap pair's venue, using the swap pair's registered program. each venue parses its
+/// own accounts from venue_accounts. the bucket signs as the owner of both token accounts.
+pub fn swap<'info>(
+    accounts: &Rebalance<'info>,
+    swap_pair: &SwapPair,
+    swap_program: &AccountInfo<'info>,
+    user_source: &AccountInfo<'info>,
+    user_destination: &AccountInfo<'info>,
+    venue_accounts: &[AccountInfo<'info>],
+    amount_in: u64,
+    minimum_amount_out: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    // the bucket signs the CPI below with its PDA seeds. never hand those to a program that the
+    // authority has not registered for this pair.
+    invariant!(
+        swap_program.key() == swap_pair.swap_program,
+        ErrorCode::UnregisteredSwapProgram
+    );
+
+    let venue = swap_pair.venue;
+    invariant!(
+        venue_accounts.len() == venue.num_accounts(),
+        ErrorCode::InvalidSwapVenueAccounts
+    );
+
+    match venue {
+        SwapVenue::Saber => saber::swap(
+            accounts,
+            swap_pair,
+            swap_program,
+            user_source,
+            user_destination,
+            venue_accounts,
+            amount_in,
+            minimum_amount_out,
+            signer_seeds,
+        ),
+        SwapVenue::ConstantProduct => constant_product::swap(
+            accounts,
+            swap_pair,
+            swap_program,
+            user_source,
+            user_destination,
+            venue_accounts,
+            amount_in,
+            minimum_amount_out,
+            signer_seeds,
+        ),
+    }
+}
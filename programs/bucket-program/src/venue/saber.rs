@@ -0,0 +1,42 @@
+use {
+    crate::{
+        context::{Rebalance, SaberSwapAsset},
+        error::ErrorCode,
+        state::swap_pair::SwapPair,
+    },
+    anchor_lang::{prelude::*, solana_program::account_info::next_account_infos},
+    vipers::invariant,
+};
+
+pub fn swap<'info>(
+    accounts: &Rebalance<'info>,
+    swap_pair: &SwapPair,
+    swap_program: &AccountInfo<'info>,
+    user_source: &AccountInfo<'info>,
+    user_destination: &AccountInfo<'info>,
+    venue_accounts: &[AccountInfo<'info>],
+    amount_in: u64,
+    minimum_amount_out: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let saber: SaberSwapAsset = Accounts::try_accounts(
+        &crate::ID,
+        &mut next_account_infos(&mut venue_accounts.iter(), venue_accounts.len())?,
+        &[],
+    )?;
+    invariant!(
+        saber.swap.key() == swap_pair.swap,
+        ErrorCode::UnregisteredSwapPool
+    );
+
+    // the saber stable swap invariant formula does not adjust for the number of decimal places that
+    // the underlying token has. when collateral decimals differ, rebalance wraps the collateral with
+    // fewer decimals before calling into this venue, so both sides of the swap share decimals.
+    stable_swap_anchor::swap(
+        accounts
+            .into_saber_swap_context(&saber, swap_program, user_source, user_destination)
+            .with_signer(signer_seeds),
+        amount_in,
+        minimum_amount_out,
+    )
+}
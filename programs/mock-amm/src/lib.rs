@@ -0,0 +1,191 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+declare_id!("4v5CqRupmSXBac2WoTXs6dZtTVZjjCWhszakuCoYkETw");
+
+/// minimal constant-product (x * y = k) AMM. this program is purely for localnet testing of the
+/// bucket program's constant-product swap venue, similar to how the pyth program mocks oracles.
+/// there are no fees and no LP tokens; reserves are funded by transferring tokens directly.
+#[program]
+pub mod mock_amm {
+    use super::*;
+
+    pub fn initialize_pool(
+        ctx: Context<InitializePool>,
+        _pool_bump: u8,
+        authority_bump: u8,
+    ) -> ProgramResult {
+        let pool = &mut ctx.accounts.pool;
+        pool.authority_bump = authority_bump;
+        pool.mint_a = ctx.accounts.mint_a.key();
+        pool.mint_b = ctx.accounts.mint_b.key();
+        pool.reserve_a = ctx.accounts.reserve_a.key();
+        pool.reserve_b = ctx.accounts.reserve_b.key();
+
+        Ok(())
+    }
+
+    /// swap amount_in of the input reserve's mint for the output reserve's mint. the account
+    /// order and instruction data of this instruction define the constant-product venue
+    /// interface that the bucket program invokes.
+    pub fn swap(ctx: Context<Swap>, amount_in: u64, minimum_amount_out: u64) -> ProgramResult {
+        // amount_out = reserve_out * amount_in / (reserve_in + amount_in)
+        let amount_out = (ctx.accounts.output_reserve.amount as u128)
+            .checked_mul(amount_in as u128)
+            .and_then(|num| {
+                num.checked_div(
+                    (ctx.accounts.input_reserve.amount as u128).checked_add(amount_in as u128)?,
+                )
+            })
+            .ok_or(ErrorCode::MathError)? as u64;
+
+        require!(
+            amount_out >= minimum_amount_out,
+            ErrorCode::ExceededSlippage
+        );
+
+        token::transfer(ctx.accounts.into_transfer_in_context(), amount_in)?;
+
+        let pool = ctx.accounts.pool.key();
+        let pool_authority_signer_seeds: &[&[&[u8]]] = &[&[
+            AUTHORITY_SEED.as_bytes(),
+            pool.as_ref(),
+            &[ctx.accounts.pool.authority_bump],
+        ]];
+        token::transfer(
+            ctx.accounts
+                .into_transfer_out_context()
+                .with_signer(pool_authority_signer_seeds),
+            amount_out,
+        )?;
+
+        Ok(())
+    }
+}
+
+pub const POOL_SEED: &str = "pool";
+pub const AUTHORITY_SEED: &str = "authority";
+
+#[derive(Accounts)]
+pub struct InitializePool<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        seeds = [
+            POOL_SEED.as_bytes(),
+            mint_a.key().as_ref(),
+            mint_b.key().as_ref()
+        ],
+        bump,
+        payer = payer
+    )]
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: PDA that owns the pool reserves
+    #[account(
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            pool.key().as_ref()
+        ],
+        bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    pub mint_a: Account<'info, Mint>,
+
+    pub mint_b: Account<'info, Mint>,
+
+    #[account(
+        constraint = reserve_a.mint == mint_a.key(),
+        constraint = reserve_a.owner == pool_authority.key()
+    )]
+    pub reserve_a: Account<'info, TokenAccount>,
+
+    #[account(
+        constraint = reserve_b.mint == mint_b.key(),
+        constraint = reserve_b.owner == pool_authority.key()
+    )]
+    pub reserve_b: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Swap<'info> {
+    pub pool: Account<'info, Pool>,
+
+    /// CHECK: PDA that owns the pool reserves
+    #[account(
+        seeds = [
+            AUTHORITY_SEED.as_bytes(),
+            pool.key().as_ref()
+        ],
+        bump = pool.authority_bump,
+    )]
+    pub pool_authority: UncheckedAccount<'info>,
+
+    pub user_authority: Signer<'info>,
+
+    #[account(mut)]
+    pub user_source: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user_destination: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = input_reserve.key() == pool.reserve_a || input_reserve.key() == pool.reserve_b
+    )]
+    pub input_reserve: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = output_reserve.key() == pool.reserve_a || output_reserve.key() == pool.reserve_b,
+        constraint = output_reserve.key() != input_reserve.key()
+    )]
+    pub output_reserve: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[account]
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Pool {
+    pub authority_bump: u8,
+    pub mint_a: Pubkey,
+    pub mint_b: Pubkey,
+    pub reserve_a: Pubkey,
+    pub reserve_b: Pubkey,
+}
+
+impl<'info> Swap<'info> {
+    pub fn into_transfer_in_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.user_source.to_account_info(),
+            to: self.input_reserve.to_account_info(),
+            authority: self.user_authority.to_account_info(),
+        };
+
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+
+    pub fn into_transfer_out_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        let cpi_accounts = Transfer {
+            from: self.output_reserve.to_account_info(),
+            to: self.user_destination.to_account_info(),
+            authority: self.pool_authority.to_account_info(),
+        };
+
+        CpiContext::new(self.token_program.to_account_info(), cpi_accounts)
+    }
+}
+
+#[error]
+pub enum ErrorCode {
+    #[msg("Math Error")]
+    MathError,
+    #[msg("Swap output is less than the minimum amount out")]
+    ExceededSlippage,
+}
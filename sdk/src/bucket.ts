@@ -1,5 +1,5 @@
 import * as anchor from "@project-serum/anchor";
-import { Program, Provider, Idl, Wallet } from "@project-serum/anchor";
+import { BN, Program, Provider, Idl, Wallet } from "@project-serum/anchor";
 import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
 import {
   AccountMeta,
@@ -14,7 +14,7 @@ import {
   CRATE_ADDRESSES,
   generateCrateAddress,
 } from "@crateprotocol/crate-sdk";
-import { StableSwap, SWAP_PROGRAM_ID } from "@saberhq/stableswap-sdk";
+import { StableSwap } from "@saberhq/stableswap-sdk";
 import { SaberRegistryProvider } from "saber-swap-registry-provider";
 
 import { AccountUtils } from "./common/account-utils";
@@ -25,7 +25,18 @@ import {
   Collateral,
   CollateralAllocationResult,
   RebalanceConfig,
+  RebalanceToTargetConfig,
+  TwoHopRebalanceConfig,
+  AdminAction,
+  AllocationBand,
+  CollateralCaps,
+  CollateralStatus,
   ExtendedCluster,
+  FeeOverrides,
+  KeeperBounty,
+  OracleSource,
+  RebalanceLimits,
+  SwapVenue,
 } from "./common/types";
 import {
   addIxn,
@@ -33,9 +44,19 @@ import {
   flattenValidInstructions,
   computeMappingFromList,
   computeSwapAmounts,
+  parseReturnData,
+  sortMints,
+  toAccountMetas,
 } from "./common/util";
 import { BucketProgram } from "./types/bucket_program";
-import { DEVNET, LOCALNET } from "./common/constant";
+import {
+  BOUNTY_SOURCE_RESERVE,
+  DEVNET,
+  LOCALNET,
+  MAX_ALLOCATION_PPM,
+  MAX_PERMITTED_DATA_INCREASE,
+  ZERO_U64,
+} from "./common/constant";
 
 export class BucketClient extends AccountUtils {
   wallet: Wallet;
@@ -139,13 +160,151 @@ export class BucketClient extends AccountUtils {
     } as PdaDerivationResult;
   };
 
+  generateCollateralConfigAddress = async (
+    bucket: PublicKey,
+    mint: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "collateral-config",
+      bucket,
+      mint,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  generateCollateralWrapperAddress = async (
+    bucket: PublicKey,
+    mint: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "collateral-wrapper",
+      bucket,
+      mint,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  // the mints can be passed in either order
+  generateSwapPairAddress = async (
+    bucket: PublicKey,
+    mintA: PublicKey,
+    mintB: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [lowerMint, higherMint] = sortMints(mintA, mintB);
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "swap-pair",
+      bucket,
+      lowerMint,
+      higherMint,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  generateRebalanceStateAddress = async (
+    bucket: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "rebalance-state",
+      bucket,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  generateBucketStatsAddress = async (
+    bucket: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "bucket-stats",
+      bucket,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  generateAllocationRampAddress = async (
+    bucket: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "allocation-ramp",
+      bucket,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  generateNavSnapshotAddress = async (
+    bucket: PublicKey,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "nav-snapshot",
+      bucket,
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
+  // proposals are numbered by the bucket's proposal count at the time they are enqueued
+  generateProposalAddress = async (
+    bucket: PublicKey,
+    proposalId: BN,
+    programID: PublicKey = this.bucketProgram.programId
+  ) => {
+    const [addr, bump] = await this.findProgramAddress(programID, [
+      "proposal",
+      bucket,
+      proposalId.toArrayLike(Buffer, "le", 8),
+    ]);
+
+    return {
+      addr,
+      bump,
+    } as PdaDerivationResult;
+  };
+
   // ================================================
   // Fetch & deserialize objects
   // ================================================
 
   fetchBucket = async (addr: PublicKey) => {
     const bucket = await this.bucketProgram.account.bucket.fetch(addr);
-    const collateral = bucket.collateral as Collateral[];
+    // the bucket is a zero-copy account with a fixed capacity collateral array. only the first
+    // collateralLen elements are authorized collateral.
+    const collateral = (bucket.collateral as Collateral[]).slice(
+      0,
+      bucket.collateralLen
+    );
 
     return {
       bucket,
@@ -157,6 +316,57 @@ export class BucketClient extends AccountUtils {
   // Fetch token account balanaces
   // ================================================
 
+  fetchCollateralConfig = async (bucket: PublicKey, mint: PublicKey) => {
+    const { addr } = await this.generateCollateralConfigAddress(bucket, mint);
+
+    return this.bucketProgram.account.collateralConfig.fetch(addr);
+  };
+
+  fetchSwapPair = async (
+    bucket: PublicKey,
+    mintA: PublicKey,
+    mintB: PublicKey
+  ) => {
+    const { addr } = await this.generateSwapPairAddress(bucket, mintA, mintB);
+
+    return this.bucketProgram.account.swapPair.fetch(addr);
+  };
+
+  fetchCollateralWrapper = async (bucket: PublicKey, mint: PublicKey) => {
+    const { addr } = await this.generateCollateralWrapperAddress(bucket, mint);
+
+    return this.bucketProgram.account.collateralWrapper.fetch(addr);
+  };
+
+  fetchRebalanceState = async (bucket: PublicKey) => {
+    const { addr } = await this.generateRebalanceStateAddress(bucket);
+
+    return this.bucketProgram.account.rebalanceState.fetch(addr);
+  };
+
+  fetchBucketStats = async (bucket: PublicKey) => {
+    const { addr } = await this.generateBucketStatsAddress(bucket);
+
+    return this.bucketProgram.account.bucketStats.fetch(addr);
+  };
+
+  fetchNavSnapshot = async (bucket: PublicKey) => {
+    const { addr } = await this.generateNavSnapshotAddress(bucket);
+
+    return this.bucketProgram.account.navSnapshot.fetch(addr);
+  };
+
+  fetchProposal = async (addr: PublicKey) => {
+    return this.bucketProgram.account.proposal.fetch(addr);
+  };
+
+  fetchMintDecimals = async (mint: PublicKey): Promise<number> => {
+    const mintInfo = await this.conn.getParsedAccountInfo(mint);
+    invariant(mintInfo.value, `missing mint ${mint.toBase58()}`);
+
+    return (mintInfo.value.data as any).parsed.info.decimals;
+  };
+
   fetchTokenBalance = async (
     mint: PublicKey,
     owner: PublicKey
@@ -300,6 +510,10 @@ export class BucketClient extends AccountUtils {
       await this.generateIssueAuthority(bucket);
     const { addr: withdrawAuthority, bump: withdrawBump } =
       await this.generateWithdrawAuthority(bucket);
+    const { addr: rebalanceState, bump: rebalanceStateBump } =
+      await this.generateRebalanceStateAddress(bucket);
+    const { addr: bucketStats, bump: bucketStatsBump } =
+      await this.generateBucketStatsAddress(bucket);
 
     const signerInfo = getSignersFromPayer(payer);
     const crateATA = await this.getOrCreateATA(
@@ -316,6 +530,8 @@ export class BucketClient extends AccountUtils {
       crateToken: crate,
       issueAuthority,
       withdrawAuthority,
+      rebalanceState,
+      bucketStats,
       // defaults to original creator. this entity has the ability
       // to update the value later.
       rebalanceAuthority: signerInfo.payer,
@@ -330,6 +546,8 @@ export class BucketClient extends AccountUtils {
       crateBump,
       issueBump,
       withdrawBump,
+      rebalanceStateBump,
+      bucketStatsBump,
       {
         accounts,
         preInstructions: [
@@ -342,6 +560,14 @@ export class BucketClient extends AccountUtils {
             decimals
           )),
           ...(crateATA.instruction ? [crateATA.instruction] : []),
+          // the bucket is larger than an account created through a CPI can be. resize_bucket
+          // creates it and create_bucket grows it the rest of the way.
+          this.resizeBucketInstruction(
+            bucket,
+            bucketBump,
+            crate,
+            signerInfo.payer
+          ),
         ],
         signers: [reserve, ...signerInfo.signers],
       }
@@ -350,6 +576,91 @@ export class BucketClient extends AccountUtils {
     return { tx, ...accounts };
   };
 
+  resizeBucketInstruction = (
+    bucket: PublicKey,
+    bucketBump: number,
+    crate: PublicKey,
+    payer: PublicKey
+  ): TransactionInstruction =>
+    this.bucketProgram.instruction.resizeBucket(bucketBump, {
+      accounts: {
+        payer,
+        bucket,
+        crateToken: crate,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+
+  // grows the bucket account by as much as a single instruction can, creating it if it does not exist.
+  resizeBucket = async (reserve: PublicKey, payer: PublicKey | Keypair) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket, bump: bucketBump } = await this.generateBucketAddress(
+      crate
+    );
+
+    return this.bucketProgram.rpc.resizeBucket(bucketBump, {
+      accounts: {
+        payer: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        systemProgram: SystemProgram.programId,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // buckets created before the current layout need to be grown to it, and need the rebalance state
+  // and bucket stats created before they can deposit, redeem or rebalance.
+  migrateBucket = async (reserve: PublicKey, payer: PublicKey | Keypair) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket, bump: bucketBump } = await this.generateBucketAddress(
+      crate
+    );
+    const { addr: rebalanceState, bump: rebalanceStateBump } =
+      await this.generateRebalanceStateAddress(bucket);
+    const { addr: bucketStats, bump: bucketStatsBump } =
+      await this.generateBucketStatsAddress(bucket);
+
+    // migrate_bucket grows the account by up to MAX_PERMITTED_DATA_INCREASE itself. resize it
+    // first for whatever is left beyond that.
+    const { data } = await this.provider.connection.getAccountInfo(bucket);
+    const resizes = Math.max(
+      0,
+      Math.ceil(
+        (this.bucketProgram.account.bucket.size - data.length) /
+          MAX_PERMITTED_DATA_INCREASE
+      ) - 1
+    );
+
+    return this.bucketProgram.rpc.migrateBucket(
+      rebalanceStateBump,
+      bucketStatsBump,
+      {
+        accounts: {
+          authority: signerInfo.payer,
+          bucket,
+          crateToken: crate,
+          rebalanceState,
+          bucketStats,
+          systemProgram: SystemProgram.programId,
+        },
+        preInstructions: [...Array(resizes)].map(() =>
+          this.resizeBucketInstruction(
+            bucket,
+            bucketBump,
+            crate,
+            signerInfo.payer
+          )
+        ),
+        signers: signerInfo.signers,
+      }
+    );
+  };
+
   updateRebalanceAuthority = async (
     reserve: PublicKey,
     rebalanceAuthority: PublicKey,
@@ -370,10 +681,10 @@ export class BucketClient extends AccountUtils {
     });
   };
 
-  authorizeCollateral = async (
-    collateral: PublicKey,
-    allocation: number,
+
+  proposeAuthority = async (
     reserve: PublicKey,
+    pendingAuthority: PublicKey,
     payer: PublicKey | Keypair
   ) => {
     const signerInfo: SignerInfo = getSignersFromPayer(payer);
@@ -381,104 +692,830 @@ export class BucketClient extends AccountUtils {
     const [crate, _crateBump] = await generateCrateAddress(reserve);
     const { addr: bucket } = await this.generateBucketAddress(crate);
 
-    return this.bucketProgram.rpc.authorizeCollateral(collateral, allocation, {
+    return this.bucketProgram.rpc.proposeAuthority(pendingAuthority, {
       accounts: {
+        authority: signerInfo.payer,
         bucket,
         crateToken: crate,
-        authority: signerInfo.payer,
       },
       signers: signerInfo.signers,
     });
   };
 
-  removeCollateral = async (
+  cancelAuthorityTransfer = async (
     reserve: PublicKey,
-    collateral: PublicKey,
-    depositor: PublicKey | Keypair
+    payer: PublicKey | Keypair
   ) => {
-    const signerInfo = getSignersFromPayer(depositor);
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
 
     const [crate, _crateBump] = await generateCrateAddress(reserve);
     const { addr: bucket } = await this.generateBucketAddress(crate);
 
-    return this.bucketProgram.rpc.removeCollateral(collateral, {
+    return this.bucketProgram.rpc.cancelAuthorityTransfer({
       accounts: {
+        authority: signerInfo.payer,
         bucket,
         crateToken: crate,
-        authority: signerInfo.payer,
       },
       signers: signerInfo.signers,
     });
   };
 
-  setCollateralAllocations = async (
+  acceptAuthority = async (
     reserve: PublicKey,
-    collateral: Collateral[],
-    depositor: PublicKey | Keypair
+    pendingAuthority: PublicKey | Keypair
   ) => {
-    const signerInfo = getSignersFromPayer(depositor);
+    const signerInfo: SignerInfo = getSignersFromPayer(pendingAuthority);
 
     const [crate, _crateBump] = await generateCrateAddress(reserve);
     const { addr: bucket } = await this.generateBucketAddress(crate);
 
-    return this.bucketProgram.rpc.setCollateralAllocations(collateral, {
+    return this.bucketProgram.rpc.acceptAuthority({
       accounts: {
+        pendingAuthority: signerInfo.payer,
         bucket,
         crateToken: crate,
-        authority: signerInfo.payer,
       },
       signers: signerInfo.signers,
     });
   };
 
-  // in the underlying swap, we need token A and token B. the client supplies
-  // mintToRemove = token A. we will query current collateral amounts to figure
-  // out what collateral mint to use as token B.
-  removeUnauthorizedCollateralTokens = async (
-    mintToRemove: PublicKey,
+  decommissionBucket = async (
     reserve: PublicKey,
-    payer: PublicKey | Keypair,
-    cluster: ExtendedCluster = DEVNET,
-    swapAccount?: PublicKey // allow localnet overrides
+    payer: PublicKey | Keypair
   ) => {
     const signerInfo: SignerInfo = getSignersFromPayer(payer);
 
     const [crate, _crateBump] = await generateCrateAddress(reserve);
-    const { addr: bucketAddress } = await this.generateBucketAddress(crate);
-    const { bucket, collateral } = await this.fetchBucket(bucketAddress);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
 
-    if (bucket.authority.toBase58() === signerInfo.payer.toBase58()) {
-      throw new Error("bucket authority should directly call rebalance");
-    }
+    return this.bucketProgram.rpc.decommissionBucket({
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+      },
+      signers: signerInfo.signers,
+    });
+  };
 
-    const _mintToRemove = mintToRemove.toBase58();
+  // the bucket must be decommissioned and empty. remaining accounts are the crate ATA and the
+  // collateral config of each authorized collateral.
+  closeBucket = async (reserve: PublicKey, payer: PublicKey | Keypair) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
 
-    const collateralMints = collateral.map((c) => c.mint);
-    if (collateralMints.length === 0) {
-      throw new Error("no authorized collateral tokens to swap with");
-    }
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { collateral } = await this.fetchBucket(bucket);
 
-    if (
-      collateralMints.map((mint) => mint.toBase58()).includes(_mintToRemove)
-    ) {
-      throw new Error(`${_mintToRemove} is an authorized mint`);
+    const remainingAccounts: AccountMeta[] = [];
+    for (const c of collateral) {
+      remainingAccounts.push(
+        ...toAccountMetas([
+          await this.findAssociatedTokenAddress(crate, c.mint),
+        ]),
+        ...toAccountMetas(
+          [(await this.generateCollateralConfigAddress(bucket, c.mint)).addr],
+          true
+        )
+      );
     }
 
-    const tokenAccountToRemove = await this.fetchParsedTokenAccountsByMints(
-      [mintToRemove],
-      crate
-    );
-
-    const mintAmountToRemove = tokenAccountToRemove[0].amount.toNumber();
-    const authorizedCollateralATAs =
-      await this.fetchParsedTokenAccountsForAuthorizedCollateral(
-        bucketAddress,
-        crate,
-        collateral.map((c) => c.mint)
-      );
+    return this.bucketProgram.rpc.closeBucket({
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        crateMint: reserve,
+        issueAuthority: (await this.generateIssueAuthority(bucket)).addr,
+        withdrawAuthority: (await this.generateWithdrawAuthority(bucket)).addr,
+        rebalanceState: (await this.generateRebalanceStateAddress(bucket)).addr,
+        bucketStats: (await this.generateBucketStatsAddress(bucket)).addr,
+        navSnapshot: (await this.generateNavSnapshotAddress(bucket)).addr,
+        allocationRamp: (await this.generateAllocationRampAddress(bucket))
+          .addr,
+      },
+      remainingAccounts,
+      signers: signerInfo.signers,
+    });
+  };
 
-    const reserveSupply = +(await this.conn.getTokenSupply(reserve)).value
-      .amount;
+  configureTimelock = async (
+    reserve: PublicKey,
+    delaySlots: u64,
+    guardian: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.configureTimelock(delaySlots, guardian, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // the guardian can always set the guardian. the authority can only set it directly while the
+  // timelock is disabled, otherwise it has to enqueue a SetGuardian proposal.
+  setGuardian = async (
+    reserve: PublicKey,
+    guardian: PublicKey,
+    setter: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(setter);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.setGuardian(guardian, {
+      accounts: {
+        setter: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  enqueueProposal = async (
+    reserve: PublicKey,
+    action: AdminAction,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { bucket: bucketData } = await this.fetchBucket(bucket);
+    const { addr: proposal, bump } = await this.generateProposalAddress(
+      bucket,
+      bucketData.timelock.proposalCount
+    );
+
+    const tx = await this.bucketProgram.rpc.enqueueProposal(bump, action, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        proposal,
+        systemProgram: SystemProgram.programId,
+      },
+      signers: signerInfo.signers,
+    });
+
+    return { tx, proposal };
+  };
+
+  // anyone can execute a proposal once its eta has passed. the proposal rent goes back to the
+  // proposer.
+  executeProposal = async (
+    reserve: PublicKey,
+    proposal: PublicKey,
+    executor: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(executor);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const proposalData = await this.fetchProposal(proposal);
+
+    return this.bucketProgram.rpc.executeProposal({
+      accounts: {
+        executor: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        proposal,
+        proposer: proposalData.proposer,
+      },
+      remainingAccounts: await this.getProposalAccounts(
+        bucket,
+        proposalData.action as AdminAction
+      ),
+      signers: signerInfo.signers,
+    });
+  };
+
+  // proposals can be cancelled by the authority or the guardian
+  cancelProposal = async (
+    reserve: PublicKey,
+    proposal: PublicKey,
+    canceller: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(canceller);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const proposalData = await this.fetchProposal(proposal);
+
+    return this.bucketProgram.rpc.cancelProposal({
+      accounts: {
+        canceller: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        proposal,
+        proposer: proposalData.proposer,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // allocation is in parts per million, see MAX_ALLOCATION_PPM
+  authorizeCollateral = async (
+    collateral: PublicKey,
+    allocation: number,
+    reserve: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.authorizeCollateral(collateral, allocation, {
+      accounts: {
+        bucket,
+        crateToken: crate,
+        authority: signerInfo.payer,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  removeCollateral = async (
+    reserve: PublicKey,
+    collateral: PublicKey,
+    depositor: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(depositor);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.removeCollateral(collateral, {
+      accounts: {
+        bucket,
+        crateToken: crate,
+        authority: signerInfo.payer,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  setCollateralAllocations = async (
+    reserve: PublicKey,
+    collateral: Collateral[],
+    depositor: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(depositor);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.setCollateralAllocations(collateral, {
+      accounts: {
+        bucket,
+        crateToken: crate,
+        authority: signerInfo.payer,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // replaces the whole collateral set, i.e. adds, removes and reallocates collateral at once
+  updateCollateralSet = async (
+    reserve: PublicKey,
+    collateral: Collateral[],
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.updateCollateralSet(collateral, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  rampAllocations = async (
+    reserve: PublicKey,
+    allocations: Collateral[],
+    endSlot: u64,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { addr: allocationRamp, bump } =
+      await this.generateAllocationRampAddress(bucket);
+
+    return this.bucketProgram.rpc.rampAllocations(bump, allocations, endSlot, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        allocationRamp,
+        systemProgram: SystemProgram.programId,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  stopAllocationRamp = async (
+    reserve: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.stopAllocationRamp({
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        allocationRamp: (await this.generateAllocationRampAddress(bucket))
+          .addr,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  setKeeperBounty = async (
+    reserve: PublicKey,
+    keeperBounty: KeeperBounty,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.setKeeperBounty(keeperBounty, {
+      accounts: {
+        bucket,
+        crateToken: crate,
+        authority: signerInfo.payer,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  setRebalanceLimits = async (
+    reserve: PublicKey,
+    limits: RebalanceLimits,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.setRebalanceLimits(limits, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        rebalanceState: (await this.generateRebalanceStateAddress(bucket))
+          .addr,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // flags are the PAUSE_* bitflags. without a mint, the whole bucket is paused. the authority and
+  // the guardian can pause, but only the authority can unpause.
+  pause = async (
+    reserve: PublicKey,
+    flags: number,
+    mint: PublicKey | null,
+    pauser: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(pauser);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.pause(flags, mint, {
+      accounts: {
+        pauser: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  unpause = async (
+    reserve: PublicKey,
+    flags: number,
+    mint: PublicKey | null,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.unpause(flags, mint, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // the saber pool for mintA/mintB is looked up via the registry if no swap account is given
+  addSwapPair = async (
+    reserve: PublicKey,
+    mintA: PublicKey,
+    mintB: PublicKey,
+    venue: SwapVenue,
+    swapProgram: PublicKey,
+    payer: PublicKey | Keypair,
+    swap?: PublicKey,
+    cluster: ExtendedCluster = DEVNET
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { addr: swapPair, bump } = await this.generateSwapPairAddress(
+      bucket,
+      mintA,
+      mintB
+    );
+    const [lowerMint, higherMint] = sortMints(mintA, mintB);
+
+    if (!swap && cluster === LOCALNET)
+      throw new Error("Cannot lookup swap account via registry on localnet");
+    const _swap = swap
+      ? swap
+      : await this.saberProvider.getSwapAccountFromMints(mintA, mintB, cluster);
+
+    return this.bucketProgram.rpc.addSwapPair(bump, venue, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        mintA: lowerMint,
+        mintB: higherMint,
+        swapPair,
+        swapProgram,
+        swap: _swap,
+        systemProgram: SystemProgram.programId,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  removeSwapPair = async (
+    reserve: PublicKey,
+    mintA: PublicKey,
+    mintB: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.removeSwapPair({
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        swapPair: (await this.generateSwapPairAddress(bucket, mintA, mintB))
+          .addr,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  addCollateralWrapper = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    wrapperProgram: PublicKey,
+    wrapper: PublicKey,
+    wrapperMint: PublicKey,
+    wrapperUnderlyingTokens: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { addr: collateralWrapper, bump } =
+      await this.generateCollateralWrapperAddress(bucket, mint);
+
+    return this.bucketProgram.rpc.addCollateralWrapper(bump, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        mint,
+        collateralWrapper,
+        wrapperProgram,
+        wrapper,
+        wrapperMint,
+        wrapperUnderlyingTokens,
+        systemProgram: SystemProgram.programId,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  removeCollateralWrapper = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.removeCollateralWrapper({
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        collateralWrapper: (
+          await this.generateCollateralWrapperAddress(bucket, mint)
+        ).addr,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  addCollateralConfig = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    oracle: PublicKey,
+    payer: PublicKey | Keypair,
+    oracleSource: OracleSource = { pyth: {} },
+    caps: CollateralCaps = {
+      maxDepositAmount: ZERO_U64,
+      maxCrateBalance: ZERO_U64,
+    },
+    feeOverrides: FeeOverrides = { depositFeeBps: null, redeemFeeBps: null }
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { addr: collateralConfig, bump } =
+      await this.generateCollateralConfigAddress(bucket, mint);
+
+    return this.bucketProgram.rpc.addCollateralConfig(
+      bump,
+      oracleSource,
+      oracle,
+      caps,
+      feeOverrides,
+      {
+        accounts: {
+          authority: signerInfo.payer,
+          bucket,
+          crateToken: crate,
+          mint,
+          collateralConfig,
+          systemProgram: SystemProgram.programId,
+        },
+        signers: signerInfo.signers,
+      }
+    );
+  };
+
+  updateCollateralConfig = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    oracleSource: OracleSource,
+    oracle: PublicKey,
+    caps: CollateralCaps,
+    feeOverrides: FeeOverrides,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.updateCollateralConfig(
+      oracleSource,
+      oracle,
+      caps,
+      feeOverrides,
+      {
+        accounts: {
+          authority: signerInfo.payer,
+          bucket,
+          crateToken: crate,
+          collateralConfig: (
+            await this.generateCollateralConfigAddress(bucket, mint)
+          ).addr,
+        },
+        signers: signerInfo.signers,
+      }
+    );
+  };
+
+  setCollateralStatus = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    status: CollateralStatus,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.setCollateralStatus(status, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        collateralConfig: (
+          await this.generateCollateralConfigAddress(bucket, mint)
+        ).addr,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  setAllocationBand = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    band: AllocationBand,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.setAllocationBand(band, {
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        collateralConfig: (
+          await this.generateCollateralConfigAddress(bucket, mint)
+        ).addr,
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // the collateral must no longer be authorized and the crate must not hold any of it
+  closeCollateralConfig = async (
+    reserve: PublicKey,
+    mint: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    return this.bucketProgram.rpc.closeCollateralConfig({
+      accounts: {
+        authority: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        collateralConfig: (
+          await this.generateCollateralConfigAddress(bucket, mint)
+        ).addr,
+        crateCollateral: await this.findAssociatedTokenAddress(crate, mint),
+      },
+      signers: signerInfo.signers,
+    });
+  };
+
+  // ================================================
+  // Reporting
+  // ================================================
+
+  // returns the indices of out of band collateral, in the bucket's collateral order
+  reportAllocationBands = async (reserve: PublicKey): Promise<number[]> => {
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    const { raw } = await this.bucketProgram.simulate.reportAllocationBands({
+      accounts: {
+        bucket,
+        crateToken: crate,
+        allocationRamp: (await this.generateAllocationRampAddress(bucket))
+          .addr,
+      },
+      remainingAccounts: await this.getCollateralValuationAccounts(
+        bucket,
+        crate
+      ),
+    });
+
+    const data = parseReturnData(
+      raw as string[],
+      this.bucketProgram.programId
+    );
+    invariant(data, "missing return data");
+
+    // borsh encodes a Vec<u16> as a u32 length followed by the elements
+    const len = data.readUInt32LE(0);
+    return [...Array(len).keys()].map((i) => data.readUInt16LE(4 + i * 2));
+  };
+
+  snapshotNav = async (reserve: PublicKey, payer: PublicKey | Keypair) => {
+    const signerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { addr: navSnapshot, bump } = await this.generateNavSnapshotAddress(
+      bucket
+    );
+
+    return this.bucketProgram.rpc.snapshotNav(bump, {
+      accounts: {
+        payer: signerInfo.payer,
+        bucket,
+        crateToken: crate,
+        crateMint: reserve,
+        allocationRamp: (await this.generateAllocationRampAddress(bucket))
+          .addr,
+        navSnapshot,
+        systemProgram: SystemProgram.programId,
+      },
+      remainingAccounts: await this.getCollateralValuationAccounts(
+        bucket,
+        crate
+      ),
+      signers: signerInfo.signers,
+    });
+  };
+
+  // in the underlying swap, we need token A and token B. the client supplies
+  // mintToRemove = token A. we will query current collateral amounts to figure
+  // out what collateral mint to use as token B.
+  removeUnauthorizedCollateralTokens = async (
+    mintToRemove: PublicKey,
+    reserve: PublicKey,
+    payer: PublicKey | Keypair,
+    venueAccounts?: PublicKey[] // required for non-saber venues
+  ) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucketAddress } = await this.generateBucketAddress(crate);
+    const { bucket, collateral } = await this.fetchBucket(bucketAddress);
+
+    if (bucket.rebalanceAuthority.toBase58() === signerInfo.payer.toBase58()) {
+      throw new Error("rebalance authority should directly call rebalance");
+    }
+
+    const _mintToRemove = mintToRemove.toBase58();
+
+    const collateralMints = collateral.map((c) => c.mint);
+    if (collateralMints.length === 0) {
+      throw new Error("no authorized collateral tokens to swap with");
+    }
+
+    if (
+      collateralMints.map((mint) => mint.toBase58()).includes(_mintToRemove)
+    ) {
+      throw new Error(`${_mintToRemove} is an authorized mint`);
+    }
+
+    const tokenAccountToRemove = await this.fetchParsedTokenAccountsByMints(
+      [mintToRemove],
+      crate
+    );
+
+    const mintAmountToRemove = tokenAccountToRemove[0].amount.toNumber();
+    const authorizedCollateralATAs =
+      await this.fetchParsedTokenAccountsForAuthorizedCollateral(
+        bucketAddress,
+        crate,
+        collateral.map((c) => c.mint)
+      );
+
+    const reserveSupply = +(await this.conn.getTokenSupply(reserve)).value
+      .amount;
 
     const collateralMintToAllocation = computeMappingFromList<Collateral>(
       collateral,
@@ -496,7 +1533,8 @@ export class BucketClient extends AccountUtils {
     for (const ata of authorizedCollateralATAs) {
       const collateralShareOfSupply =
         reserveSupply *
-        (collateralMintToAllocation[ata.mint.toBase58()].allocation / 10_000);
+        (collateralMintToAllocation[ata.mint.toBase58()].allocation /
+          MAX_ALLOCATION_PPM);
 
       const _allocationDifference =
         collateralShareOfSupply - ata.amount.toNumber();
@@ -517,58 +1555,499 @@ export class BucketClient extends AccountUtils {
       }
     }
 
-    return this.rebalance(
+    return this.rebalance(
+      {
+        // we decide amount to transfer on-chain
+        amountIn: 0,
+        maxSlippageBps: 0,
+        tokenA: mintToRemove,
+        tokenB: collateralToCredit.mint,
+        venueAccounts,
+        claimBounty: true,
+      },
+      reserve,
+      payer
+    );
+  };
+
+  // the (mint, crate ATA, oracle, collateral config) accounts the program uses to value each of the
+  // bucket's authorized collateral, in the bucket's collateral order. the oracles are read from the
+  // collateral configs. the program records stats on the configs of writableMints.
+  getCollateralValuationAccounts = async (
+    bucket: PublicKey,
+    crate: PublicKey,
+    writableMints: PublicKey[] = []
+  ): Promise<AccountMeta[]> => {
+    const { collateral } = await this.fetchBucket(bucket);
+    const _writableMints = writableMints.map((mint) => mint.toBase58());
+
+    const accounts: AccountMeta[] = [];
+    for (const c of collateral) {
+      const { addr: collateralConfig } =
+        await this.generateCollateralConfigAddress(bucket, c.mint);
+      const { oracle } =
+        await this.bucketProgram.account.collateralConfig.fetch(
+          collateralConfig
+        );
+
+      accounts.push(
+        ...toAccountMetas([
+          c.mint,
+          await this.findAssociatedTokenAddress(crate, c.mint),
+          oracle,
+        ]),
+        ...toAccountMetas(
+          [collateralConfig],
+          _writableMints.includes(c.mint.toBase58())
+        )
+      );
+    }
+
+    return accounts;
+  };
+
+  // collateral that is not valued with the rest of the bucket, like collateral that is no longer
+  // authorized or the intermediate collateral of a two hop rebalance, is priced with the oracle on
+  // its collateral config.
+  getCollateralOracleAccounts = async (
+    bucket: PublicKey,
+    mint: PublicKey
+  ): Promise<AccountMeta[]> => {
+    const { addr: collateralConfig } =
+      await this.generateCollateralConfigAddress(bucket, mint);
+    const { oracle } = await this.bucketProgram.account.collateralConfig.fetch(
+      collateralConfig
+    );
+
+    return toAccountMetas([oracle, collateralConfig]);
+  };
+
+  // the (bounty destination ATA, issue authority, reserve mint) accounts a keeper appends to claim
+  // the bucket's keeper bounty. the bounty is paid in token B or in reserve tokens, depending on
+  // the bucket's bounty source.
+  getKeeperBountyAccounts = async (
+    bucket: PublicKey,
+    reserve: PublicKey,
+    tokenB: PublicKey,
+    payer: PublicKey
+  ) => {
+    const { bucket: bucketData } = await this.fetchBucket(bucket);
+    const bountyMint =
+      bucketData.keeperBountySource === BOUNTY_SOURCE_RESERVE
+        ? reserve
+        : tokenB;
+
+    const bountyDestinationATA = await this.getOrCreateATA(
+      bountyMint,
+      payer,
+      payer,
+      this.provider.connection
+    );
+
+    return {
+      remainingAccounts: [
+        ...toAccountMetas([bountyDestinationATA.address], true),
+        ...toAccountMetas([(await this.generateIssueAuthority(bucket)).addr]),
+        ...toAccountMetas([reserve], true),
+      ],
+      preInstructions: flattenValidInstructions([bountyDestinationATA]),
+    };
+  };
+
+  // saber accounts are looked up from the pool. other venues need the caller to pass the venue
+  // accounts in the order the venue expects them, i.e. the pool, its authority and then the
+  // writable reserve accounts.
+  getSwapVenueAccounts = async (
+    swapPair: { venue: any; swap: PublicKey; swapProgram: PublicKey },
+    inputMint: PublicKey,
+    venueAccounts?: PublicKey[]
+  ): Promise<AccountMeta[]> => {
+    if (venueAccounts) {
+      return [
+        ...toAccountMetas(venueAccounts.slice(0, 2)),
+        ...toAccountMetas(venueAccounts.slice(2), true),
+      ];
+    }
+
+    invariant(swapPair.venue.saber, "missing swap venue accounts");
+    const fetchedStableSwap = await StableSwap.load(
+      this.provider.connection,
+      swapPair.swap,
+      swapPair.swapProgram
+    );
+
+    // enable A->B and B->A swaps within the same pool
+    const [input, output] =
+      inputMint.toBase58() === fetchedStableSwap.state.tokenA.mint.toBase58()
+        ? [fetchedStableSwap.state.tokenA, fetchedStableSwap.state.tokenB]
+        : [fetchedStableSwap.state.tokenB, fetchedStableSwap.state.tokenA];
+
+    return [
+      ...toAccountMetas([
+        fetchedStableSwap.config.swapAccount,
+        fetchedStableSwap.config.authority,
+      ]),
+      ...toAccountMetas(
+        [input.reserve, output.reserve, output.adminFeeAccount],
+        true
+      ),
+    ];
+  };
+
+  // the accounts for a single swap through the bucket's swap pair for tokenA/tokenB: the 6 rebalance
+  // asset accounts, the 6 decimal wrapper accounts if the collateral decimals differ and the venue
+  // accounts.
+  getSwapRouteAccounts = async (
+    bucket: PublicKey,
+    crate: PublicKey,
+    tokenA: PublicKey,
+    tokenB: PublicKey,
+    payer: PublicKey,
+    venueAccounts?: PublicKey[]
+  ) => {
+    const { addr: swapPair } = await this.generateSwapPairAddress(
+      bucket,
+      tokenA,
+      tokenB
+    );
+    const swapPairData = await this.bucketProgram.account.swapPair.fetch(
+      swapPair
+    );
+
+    // we need 4 ATAs: crate source, bucket source, crate destination, bucket destination
+    const crateSourceATA = await this.getOrCreateATA(
+      tokenA,
+      crate,
+      payer,
+      this.provider.connection
+    );
+    const bucketSourceATA = await this.getOrCreateATA(
+      tokenA,
+      bucket,
+      payer,
+      this.provider.connection
+    );
+    const crateDestinationATA = await this.getOrCreateATA(
+      tokenB,
+      crate,
+      payer,
+      this.provider.connection
+    );
+    const bucketDestinationATA = await this.getOrCreateATA(
+      tokenB,
+      bucket,
+      payer,
+      this.provider.connection
+    );
+
+    // these instructions ensure ATAs exist before transferring tokens to these
+    // accounts. otherwise, transaction will fail. it's possible that too many ixns
+    // packed into the same tx can result in tx failure.
+    const preInstructions = flattenValidInstructions([
+      crateSourceATA,
+      bucketSourceATA,
+      crateDestinationATA,
+      bucketDestinationATA,
+    ]);
+
+    const remainingAccounts = toAccountMetas(
+      [
+        tokenA,
+        tokenB,
+        crateSourceATA.address,
+        bucketSourceATA.address,
+        crateDestinationATA.address,
+        bucketDestinationATA.address,
+      ],
+      true
+    );
+
+    // the venue only sees tokens with matching decimals, so the collateral with fewer decimals is
+    // swapped through its decimal wrapper.
+    let venueInputMint = tokenA;
+    const decimalsA = await this.fetchMintDecimals(tokenA);
+    const decimalsB = await this.fetchMintDecimals(tokenB);
+    if (decimalsA !== decimalsB) {
+      const wrappedMint = decimalsA < decimalsB ? tokenA : tokenB;
+      const { addr: collateralWrapper } =
+        await this.generateCollateralWrapperAddress(bucket, wrappedMint);
+      const wrapper =
+        await this.bucketProgram.account.collateralWrapper.fetch(
+          collateralWrapper
+        );
+
+      const bucketWrappedATA = await this.getOrCreateATA(
+        wrapper.wrapperMint,
+        bucket,
+        payer,
+        this.provider.connection
+      );
+      addIxn(bucketWrappedATA.instruction, preInstructions);
+
+      remainingAccounts.push(
+        ...toAccountMetas([
+          collateralWrapper,
+          wrapper.wrapperProgram,
+          wrapper.wrapper,
+        ]),
+        ...toAccountMetas(
+          [
+            wrapper.wrapperMint,
+            wrapper.wrapperUnderlyingTokens,
+            bucketWrappedATA.address,
+          ],
+          true
+        )
+      );
+
+      if (wrappedMint.toBase58() === tokenA.toBase58()) {
+        venueInputMint = wrapper.wrapperMint;
+      }
+    }
+
+    remainingAccounts.push(
+      ...(await this.getSwapVenueAccounts(
+        swapPairData,
+        venueInputMint,
+        venueAccounts
+      ))
+    );
+
+    return {
+      swapPair,
+      swapProgram: swapPairData.swapProgram,
+      remainingAccounts,
+      preInstructions,
+    };
+  };
+
+  getRebalanceAccounts = async (
+    bucket: PublicKey,
+    crate: PublicKey,
+    payer: PublicKey,
+    swapPair: PublicKey,
+    swapProgram: PublicKey
+  ) => {
+    return {
+      payer,
+      bucket,
+      crateToken: crate,
+      withdrawAuthority: (await this.generateWithdrawAuthority(bucket)).addr,
+      rebalanceState: (await this.generateRebalanceStateAddress(bucket)).addr,
+      bucketStats: (await this.generateBucketStatsAddress(bucket)).addr,
+      allocationRamp: (await this.generateAllocationRampAddress(bucket)).addr,
+      swapPair,
+      crateTokenProgram: CRATE_ADDRESSES.CrateToken,
+      swapProgram,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    };
+  };
+
+  // the proposal's accounts are derived from its action. only actions that touch accounts other
+  // than the bucket need remaining accounts.
+  getProposalAccounts = async (
+    bucket: PublicKey,
+    action: AdminAction
+  ): Promise<AccountMeta[]> => {
+    if (action.updateCollateralConfig || action.setAllocationBand) {
+      const { mint } =
+        action.updateCollateralConfig || action.setAllocationBand;
+      const { addr: collateralConfig } =
+        await this.generateCollateralConfigAddress(bucket, mint);
+
+      return toAccountMetas([collateralConfig], true);
+    }
+
+    if (action.addSwapPair) {
+      const { mintA, mintB, swapProgram, swap } = action.addSwapPair;
+      const { addr: swapPair } = await this.generateSwapPairAddress(
+        bucket,
+        mintA,
+        mintB
+      );
+
+      return [
+        ...toAccountMetas([swapPair], true),
+        ...toAccountMetas([swapProgram, swap, SystemProgram.programId]),
+      ];
+    }
+
+    if (action.removeSwapPair) {
+      const { mintA, mintB } = action.removeSwapPair;
+      const { addr: swapPair } = await this.generateSwapPairAddress(
+        bucket,
+        mintA,
+        mintB
+      );
+
+      return toAccountMetas([swapPair], true);
+    }
+
+    return [];
+  };
+
+  // in the future, we can enhance the sdk by having it select token mints to swap
+  // between & how much of those tokens to swap. for now, we will require the client,
+  // to provide this information for us. the swap goes through the bucket's swap pair
+  // for token A and token B.
+  rebalance = async (
+    rebalanceConfig: RebalanceConfig,
+    reserve: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { collateral } = await this.fetchBucket(bucket);
+
+    const { swapPair, swapProgram, remainingAccounts, preInstructions } =
+      await this.getSwapRouteAccounts(
+        bucket,
+        crate,
+        rebalanceConfig.tokenA,
+        rebalanceConfig.tokenB,
+        signerInfo.payer,
+        rebalanceConfig.venueAccounts
+      );
+    remainingAccounts.push(
+      ...(await this.getCollateralValuationAccounts(bucket, crate, [
+        rebalanceConfig.tokenA,
+        rebalanceConfig.tokenB,
+      ]))
+    );
+
+    // collateral that is no longer authorized is priced from its own oracle
+    if (!this.isCollateralAuthorized(collateral, rebalanceConfig.tokenA)) {
+      remainingAccounts.push(
+        ...(await this.getCollateralOracleAccounts(
+          bucket,
+          rebalanceConfig.tokenA
+        ))
+      );
+    }
+
+    if (rebalanceConfig.claimBounty) {
+      const bounty = await this.getKeeperBountyAccounts(
+        bucket,
+        reserve,
+        rebalanceConfig.tokenB,
+        signerInfo.payer
+      );
+      remainingAccounts.push(...bounty.remainingAccounts);
+      preInstructions.push(...bounty.preInstructions);
+    }
+
+    const swapAmount = computeSwapAmounts(
+      rebalanceConfig.amountIn,
+      rebalanceConfig.maxSlippageBps
+    );
+
+    // note: token A & B accounts are parsed off the remaining accounts
+    return this.bucketProgram.rpc.rebalance(
+      swapAmount.amountIn,
+      swapAmount.minAmountOut,
       {
-        // we decide amount to transfer on-chain
-        amountIn: 0,
-        maxSlippageBps: 0,
-        tokenA: mintToRemove,
-        tokenB: collateralToCredit.mint,
-        swapAccount,
-      },
-      reserve,
-      payer,
-      cluster
+        accounts: await this.getRebalanceAccounts(
+          bucket,
+          crate,
+          signerInfo.payer,
+          swapPair,
+          swapProgram
+        ),
+        remainingAccounts,
+        preInstructions,
+        signers: signerInfo.signers,
+      }
     );
   };
 
-  // in the future, we can enhance the sdk by having it select token mints to swap
-  // between & how much of those tokens to swap. for now, we will require the client,
-  // to provide this information for us.
-  rebalance = async (
-    rebalanceConfig: RebalanceConfig,
+  // the program sizes the swap to bring the collateral back to its target allocation, up to
+  // maxAmountIn
+  rebalanceToTarget = async (
+    rebalanceConfig: RebalanceToTargetConfig,
     reserve: PublicKey,
-    payer: PublicKey | Keypair,
-    cluster: ExtendedCluster = DEVNET
+    payer: PublicKey | Keypair
   ) => {
-    const _getSwapAccountFromMints = (cluster: ExtendedCluster) => {
-      if (cluster === LOCALNET)
-        throw new Error("Cannot lookup swap account via registry on localnet");
-      return this.saberProvider.getSwapAccountFromMints(
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    const { swapPair, swapProgram, remainingAccounts, preInstructions } =
+      await this.getSwapRouteAccounts(
+        bucket,
+        crate,
         rebalanceConfig.tokenA,
         rebalanceConfig.tokenB,
-        cluster
+        signerInfo.payer,
+        rebalanceConfig.venueAccounts
       );
-    };
+    remainingAccounts.push(
+      ...(await this.getCollateralValuationAccounts(bucket, crate, [
+        rebalanceConfig.tokenA,
+        rebalanceConfig.tokenB,
+      ]))
+    );
+
+    if (rebalanceConfig.claimBounty) {
+      const bounty = await this.getKeeperBountyAccounts(
+        bucket,
+        reserve,
+        rebalanceConfig.tokenB,
+        signerInfo.payer
+      );
+      remainingAccounts.push(...bounty.remainingAccounts);
+      preInstructions.push(...bounty.preInstructions);
+    }
+
+    return this.bucketProgram.rpc.rebalanceToTarget(
+      new u64(rebalanceConfig.maxAmountIn),
+      {
+        accounts: await this.getRebalanceAccounts(
+          bucket,
+          crate,
+          signerInfo.payer,
+          swapPair,
+          swapProgram
+        ),
+        remainingAccounts,
+        preInstructions,
+        signers: signerInfo.signers,
+      }
+    );
+  };
 
+  // swaps token A for token B through an intermediate token, i.e. through the bucket's swap pairs
+  // for A/intermediate and intermediate/B. all three tokens must have the same decimals.
+  rebalanceTwoHop = async (
+    rebalanceConfig: TwoHopRebalanceConfig,
+    reserve: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
     const signerInfo: SignerInfo = getSignersFromPayer(payer);
 
     const [crate, _crateBump] = await generateCrateAddress(reserve);
     const { addr: bucket } = await this.generateBucketAddress(crate);
+    const { collateral } = await this.fetchBucket(bucket);
 
-    // fetch data needed to perform swap; for now, we are only using saber, so we need to
-    // use he swap account for pool with mints A/B.
-    const swapAccount = rebalanceConfig.swapAccount
-      ? rebalanceConfig.swapAccount
-      : await _getSwapAccountFromMints(cluster);
-
-    const fetchedStableSwap = await StableSwap.load(
-      this.provider.connection,
-      swapAccount,
-      SWAP_PROGRAM_ID
+    const { addr: firstSwapPair } = await this.generateSwapPairAddress(
+      bucket,
+      rebalanceConfig.tokenA,
+      rebalanceConfig.tokenIntermediate
+    );
+    const firstSwapPairData = await this.bucketProgram.account.swapPair.fetch(
+      firstSwapPair
+    );
+    const { addr: secondSwapPair } = await this.generateSwapPairAddress(
+      bucket,
+      rebalanceConfig.tokenIntermediate,
+      rebalanceConfig.tokenB
+    );
+    const secondSwapPairData = await this.bucketProgram.account.swapPair.fetch(
+      secondSwapPair
     );
 
-    // we need 4 ATAs: crate source, bucket source, crate destination, bucket destination
     const crateSourceATA = await this.getOrCreateATA(
       rebalanceConfig.tokenA,
       crate,
@@ -581,9 +2060,9 @@ export class BucketClient extends AccountUtils {
       signerInfo.payer,
       this.provider.connection
     );
-    const crateDestinationATA = await this.getOrCreateATA(
-      rebalanceConfig.tokenB,
-      crate,
+    const bucketIntermediateATA = await this.getOrCreateATA(
+      rebalanceConfig.tokenIntermediate,
+      bucket,
       signerInfo.payer,
       this.provider.connection
     );
@@ -593,113 +2072,169 @@ export class BucketClient extends AccountUtils {
       signerInfo.payer,
       this.provider.connection
     );
-
-    const remainingAccounts = [
-      rebalanceConfig.tokenA,
+    const crateDestinationATA = await this.getOrCreateATA(
       rebalanceConfig.tokenB,
-      crateSourceATA.address,
-      bucketSourceATA.address,
-      crateDestinationATA.address,
-      bucketDestinationATA.address,
-    ].map(
-      (acc): AccountMeta => ({
-        pubkey: acc,
-        isSigner: false,
-        isWritable: true,
-      })
+      crate,
+      signerInfo.payer,
+      this.provider.connection
     );
 
+    const remainingAccounts = [
+      ...toAccountMetas([
+        rebalanceConfig.tokenA,
+        rebalanceConfig.tokenIntermediate,
+        rebalanceConfig.tokenB,
+      ]),
+      ...toAccountMetas(
+        [
+          crateSourceATA.address,
+          bucketSourceATA.address,
+          bucketIntermediateATA.address,
+          bucketDestinationATA.address,
+          crateDestinationATA.address,
+        ],
+        true
+      ),
+      ...toAccountMetas([secondSwapPair, secondSwapPairData.swapProgram]),
+      ...(await this.getSwapVenueAccounts(
+        firstSwapPairData,
+        rebalanceConfig.tokenA,
+        rebalanceConfig.firstVenueAccounts
+      )),
+      ...(await this.getSwapVenueAccounts(
+        secondSwapPairData,
+        rebalanceConfig.tokenIntermediate,
+        rebalanceConfig.secondVenueAccounts
+      )),
+      ...(await this.getCollateralValuationAccounts(bucket, crate, [
+        rebalanceConfig.tokenA,
+        rebalanceConfig.tokenB,
+      ])),
+      ...(await this.getCollateralOracleAccounts(
+        bucket,
+        rebalanceConfig.tokenIntermediate
+      )),
+    ];
+
+    // collateral that is no longer authorized is priced from its own oracle
+    if (!this.isCollateralAuthorized(collateral, rebalanceConfig.tokenA)) {
+      remainingAccounts.push(
+        ...(await this.getCollateralOracleAccounts(
+          bucket,
+          rebalanceConfig.tokenA
+        ))
+      );
+    }
+
     const swapAmount = computeSwapAmounts(
       rebalanceConfig.amountIn,
       rebalanceConfig.maxSlippageBps
     );
 
-    // enable A->B and B->A swaps within the same pool. this can probably be isolated to its
-    // own saber util function. consider using input/output terminology instead of A/B.
-    const reserveAndFeeATAs =
-      rebalanceConfig.tokenA.toBase58() ===
-      fetchedStableSwap.state.tokenA.mint.toBase58()
-        ? {
-            inputAReserve: fetchedStableSwap.state.tokenA.reserve,
-            outputBReserve: fetchedStableSwap.state.tokenB.reserve,
-            outputBFees: fetchedStableSwap.state.tokenB.adminFeeAccount,
-          }
-        : {
-            inputAReserve: fetchedStableSwap.state.tokenB.reserve,
-            outputBReserve: fetchedStableSwap.state.tokenA.reserve,
-            outputBFees: fetchedStableSwap.state.tokenA.adminFeeAccount,
-          };
-
-    // note: token A & B accounts are parsed off the remaining accounts
-    return this.bucketProgram.rpc.rebalance(
+    return this.bucketProgram.rpc.rebalanceTwoHop(
       swapAmount.amountIn,
       swapAmount.minAmountOut,
       {
-        accounts: {
-          payer: signerInfo.payer,
+        accounts: await this.getRebalanceAccounts(
           bucket,
-          crateToken: crate,
-          withdrawAuthority: (await this.generateWithdrawAuthority(bucket))
-            .addr,
-          swap: fetchedStableSwap.config.swapAccount,
-          swapAuthority: fetchedStableSwap.config.authority,
-          userAuthority: signerInfo.payer,
-          ...reserveAndFeeATAs,
-          poolMint: fetchedStableSwap.state.poolTokenMint,
-          crateTokenProgram: CRATE_ADDRESSES.CrateToken,
-          saberProgram: fetchedStableSwap.config.swapProgramID,
-          tokenProgram: TOKEN_PROGRAM_ID,
-        },
+          crate,
+          signerInfo.payer,
+          firstSwapPair,
+          firstSwapPairData.swapProgram
+        ),
         remainingAccounts,
-        // these instructions ensure ATAs exist before transferring tokens to these
-        // accounts. otherwise, transaction will fail. it's possible that too many ixns
-        // packed into the same tx can result in tx failure.
         preInstructions: flattenValidInstructions([
           crateSourceATA,
           bucketSourceATA,
-          crateDestinationATA,
+          bucketIntermediateATA,
           bucketDestinationATA,
+          crateDestinationATA,
         ]),
         signers: signerInfo.signers,
       }
     );
   };
 
-  deposit = async (
-    amount: u64,
+  // quotes the rebalance without executing it. the quote is the program's return data.
+  quoteRebalance = async (
+    rebalanceConfig: RebalanceToTargetConfig,
+    reserve: PublicKey,
+    payer: PublicKey | Keypair
+  ) => {
+    const signerInfo: SignerInfo = getSignersFromPayer(payer);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    const route = await this.getSwapRouteAccounts(
+      bucket,
+      crate,
+      rebalanceConfig.tokenA,
+      rebalanceConfig.tokenB,
+      signerInfo.payer,
+      rebalanceConfig.venueAccounts
+    );
+
+    const { raw } = await this.bucketProgram.simulate.quoteRebalance(
+      new u64(rebalanceConfig.maxAmountIn),
+      {
+        accounts: await this.getRebalanceAccounts(
+          bucket,
+          crate,
+          signerInfo.payer,
+          route.swapPair,
+          route.swapProgram
+        ),
+        remainingAccounts: [
+          ...route.remainingAccounts,
+          ...(await this.getCollateralValuationAccounts(bucket, crate)),
+        ],
+        preInstructions: route.preInstructions,
+        signers: signerInfo.signers,
+      }
+    );
+
+    return this.decodeReturnData("RebalanceQuote", raw as string[]);
+  };
+
+  isCollateralAuthorized = (collateral: Collateral[], mint: PublicKey) =>
+    collateral.some((el) => el.mint.equals(mint));
+
+  getDepositAccounts = async (
     reserve: PublicKey,
     collateral: PublicKey,
     issueAuthority: PublicKey,
-    depositor: PublicKey | Keypair,
+    depositor: PublicKey,
     oracle: PublicKey
   ) => {
-    const signerInfo = getSignersFromPayer(depositor);
-
     const [crate, _crateBump] = await generateCrateAddress(reserve);
     const { addr: bucket } = await this.generateBucketAddress(crate);
 
     const depositorCollateralATA = await this.getOrCreateATA(
       collateral,
-      signerInfo.payer,
-      signerInfo.payer,
+      depositor,
+      depositor,
       this.provider.connection
     );
 
     const depositorReserveATA = await this.getOrCreateATA(
       reserve,
-      signerInfo.payer,
-      signerInfo.payer,
+      depositor,
+      depositor,
       this.provider.connection
     );
 
     const crateCollateralATA = await this.getOrCreateATA(
       collateral,
       crate,
-      signerInfo.payer,
+      depositor,
       this.provider.connection
     );
 
-    return this.bucketProgram.rpc.deposit(amount, {
+    const { addr: collateralConfig } =
+      await this.generateCollateralConfigAddress(bucket, collateral);
+
+    return {
       accounts: {
         common: {
           bucket: bucket,
@@ -712,29 +2247,103 @@ export class BucketClient extends AccountUtils {
         issueAuthority: issueAuthority,
         crateCollateral: crateCollateralATA.address,
         collateralMint: collateral,
-        depositor: signerInfo.payer,
+        depositor: depositor,
         depositorCollateral: depositorCollateralATA.address,
         depositorReserve: depositorReserveATA.address,
+        collateralConfig,
         oracle: oracle,
+        allocationRamp: (await this.generateAllocationRampAddress(bucket))
+          .addr,
+        bucketStats: (await this.generateBucketStatsAddress(bucket)).addr,
       },
       preInstructions: flattenValidInstructions([
         depositorCollateralATA,
         depositorReserveATA,
         crateCollateralATA,
       ]),
+    };
+  };
+
+  deposit = async (
+    amount: u64,
+    reserve: PublicKey,
+    collateral: PublicKey,
+    issueAuthority: PublicKey,
+    depositor: PublicKey | Keypair,
+    oracle: PublicKey
+  ) => {
+    const signerInfo = getSignersFromPayer(depositor);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    const { accounts, preInstructions } = await this.getDepositAccounts(
+      reserve,
+      collateral,
+      issueAuthority,
+      signerInfo.payer,
+      oracle
+    );
+
+    // the program only values the bucket's collateral if the band rejects deposits that would push
+    // the collateral above its band
+    const { band } = await this.bucketProgram.account.collateralConfig.fetch(
+      accounts.collateralConfig
+    );
+    const remainingAccounts = band.rejectDepositsAboveBand
+      ? await this.getCollateralValuationAccounts(bucket, crate)
+      : [];
+
+    return this.bucketProgram.rpc.deposit(amount, {
+      accounts,
+      remainingAccounts,
+      preInstructions,
       signers: signerInfo.signers,
     });
   };
 
-  redeem = async (
+  // quotes the deposit without executing it. the quote includes the collateral weights after the
+  // deposit.
+  quoteDeposit = async (
     amount: u64,
+    reserve: PublicKey,
+    collateral: PublicKey,
+    issueAuthority: PublicKey,
+    depositor: PublicKey | Keypair,
+    oracle: PublicKey
+  ) => {
+    const signerInfo = getSignersFromPayer(depositor);
+
+    const [crate, _crateBump] = await generateCrateAddress(reserve);
+    const { addr: bucket } = await this.generateBucketAddress(crate);
+
+    const { accounts, preInstructions } = await this.getDepositAccounts(
+      reserve,
+      collateral,
+      issueAuthority,
+      signerInfo.payer,
+      oracle
+    );
+
+    const { raw } = await this.bucketProgram.simulate.quoteDeposit(amount, {
+      accounts,
+      remainingAccounts: await this.getCollateralValuationAccounts(
+        bucket,
+        crate
+      ),
+      preInstructions,
+      signers: signerInfo.signers,
+    });
+
+    return this.decodeReturnData("DepositQuote", raw as string[]);
+  };
+
+  getRedeemAccounts = async (
     reserve: PublicKey,
     collateralTokens: PublicKey[],
     withdrawAuthority: PublicKey,
-    withdrawer: PublicKey | Keypair
+    withdrawer: PublicKey
   ) => {
-    const signerInfo = getSignersFromPayer(withdrawer);
-
     const [crate, _crateBump] = await generateCrateAddress(reserve);
     const { addr: bucket } = await this.generateBucketAddress(crate);
 
@@ -744,16 +2353,16 @@ export class BucketClient extends AccountUtils {
     const createATAInstructions: TransactionInstruction[] = [];
     const withdrawerReserveATA = await this.getOrCreateATA(
       reserve,
-      signerInfo.payer,
-      signerInfo.payer,
+      withdrawer,
+      withdrawer,
       this.provider.connection
     );
     addIxn(withdrawerReserveATA.instruction, createATAInstructions);
 
     const ownerATAs = await this.getOrCreateATAs(
       collateralTokens,
-      signerInfo.payer,
-      signerInfo.payer,
+      withdrawer,
+      withdrawer,
       this.provider.connection
     );
     ownerATAs.instructions.forEach((ixn) => addIxn(ixn, createATAInstructions));
@@ -761,40 +2370,41 @@ export class BucketClient extends AccountUtils {
     const crateATAs = await this.getOrCreateATAs(
       collateralTokens,
       crate,
-      signerInfo.payer,
+      withdrawer,
       this.provider.connection
     );
     crateATAs.instructions.forEach((ixn) => addIxn(ixn, createATAInstructions));
 
-    const remainingAccountKeys = ((): PublicKey[] => {
-      // no withdraw or protocol fees for now. refactor later to
-      // include more robust fee distribution.
-      return collateralTokens.flatMap((token) => {
-        const tokenAddress = token.toBase58();
-
-        const crateATA = (crateATAs.addresses as Record<string, PublicKey>)[
-          tokenAddress
-        ];
-        const ownerATA = (ownerATAs.addresses as Record<string, PublicKey>)[
-          tokenAddress
-        ];
-
-        invariant(ownerATA && crateATA, "missing ATA");
-
-        // use owner ATAs for the fees, since there are no fees
-        return [token, crateATA, ownerATA, ownerATA, ownerATA];
-      });
-    })();
-
-    const remainingAccounts = remainingAccountKeys.map(
-      (acc): AccountMeta => ({
-        pubkey: acc,
-        isSigner: false,
-        isWritable: true,
-      })
-    );
+    const remainingAccountKeys: PublicKey[] = [];
+    // no withdraw or protocol fees for now. refactor later to
+    // include more robust fee distribution.
+    for (const token of collateralTokens) {
+      const tokenAddress = token.toBase58();
+
+      const crateATA = (crateATAs.addresses as Record<string, PublicKey>)[
+        tokenAddress
+      ];
+      const ownerATA = (ownerATAs.addresses as Record<string, PublicKey>)[
+        tokenAddress
+      ];
+
+      invariant(ownerATA && crateATA, "missing ATA");
+
+      const { addr: collateralConfig } =
+        await this.generateCollateralConfigAddress(bucket, token);
+
+      // use owner ATAs for the fees, since there are no fees
+      remainingAccountKeys.push(
+        token,
+        crateATA,
+        ownerATA,
+        ownerATA,
+        ownerATA,
+        collateralConfig
+      );
+    }
 
-    return this.bucketProgram.rpc.redeem(amount, {
+    return {
       accounts: {
         common: {
           bucket: bucket,
@@ -805,12 +2415,72 @@ export class BucketClient extends AccountUtils {
           crateTokenProgram: CRATE_ADDRESSES.CrateToken,
         },
         withdrawAuthority: withdrawAuthority,
-        withdrawer: signerInfo.payer,
+        withdrawer: withdrawer,
         withdrawerReserve: withdrawerReserveATA.address,
+        bucketStats: (await this.generateBucketStatsAddress(bucket)).addr,
       },
-      remainingAccounts,
+      remainingAccounts: toAccountMetas(remainingAccountKeys, true),
       preInstructions: createATAInstructions,
+    };
+  };
+
+  redeem = async (
+    amount: u64,
+    reserve: PublicKey,
+    collateralTokens: PublicKey[],
+    withdrawAuthority: PublicKey,
+    withdrawer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(withdrawer);
+
+    const { accounts, remainingAccounts, preInstructions } =
+      await this.getRedeemAccounts(
+        reserve,
+        collateralTokens,
+        withdrawAuthority,
+        signerInfo.payer
+      );
+
+    return this.bucketProgram.rpc.redeem(amount, {
+      accounts,
+      remainingAccounts,
+      preInstructions,
+      signers: signerInfo.signers,
+    });
+  };
+
+  // quotes the redemption without executing it. the quote includes each collateral's share.
+  quoteRedeem = async (
+    amount: u64,
+    reserve: PublicKey,
+    collateralTokens: PublicKey[],
+    withdrawAuthority: PublicKey,
+    withdrawer: PublicKey | Keypair
+  ) => {
+    const signerInfo = getSignersFromPayer(withdrawer);
+
+    const { accounts, remainingAccounts, preInstructions } =
+      await this.getRedeemAccounts(
+        reserve,
+        collateralTokens,
+        withdrawAuthority,
+        signerInfo.payer
+      );
+
+    const { raw } = await this.bucketProgram.simulate.quoteRedeem(amount, {
+      accounts,
+      remainingAccounts,
+      preInstructions,
       signers: signerInfo.signers,
     });
+
+    return this.decodeReturnData("RedeemQuote", raw as string[]);
+  };
+
+  decodeReturnData = (typeName: string, logs: string[]) => {
+    const data = parseReturnData(logs, this.bucketProgram.programId);
+    invariant(data, "missing return data");
+
+    return this.bucketProgram.coder.types.decode(typeName, data);
   };
 }
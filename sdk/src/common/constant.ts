@@ -9,4 +9,24 @@ export const MAX_BPS = 10_000;
 export const MAX_BPS_U64 = new u64(MAX_BPS);
 export const ZERO_U64 = new u64(0);
 
-export const LEAKED_KP_FILE: string = "./data/leaked_keypair.json";
\ No newline at end of file
+// collateral allocations are in parts per million
+export const MAX_ALLOCATION_PPM = 1_000_000;
+
+export const LEAKED_KP_FILE: string = "./data/leaked_keypair.json";
+
+// a bucket holds at most this many collateral
+export const MAX_COLLATERAL_ELEMENTS = 315;
+
+// an account can grow by at most this many bytes per instruction, and an account created through a
+// CPI can be at most this large. the bucket account is larger, see resizeBucket.
+export const MAX_PERMITTED_DATA_INCREASE = 10_240;
+
+// pause bitflags, see the pause instruction
+export const PAUSE_DEPOSITS = 1 << 0;
+export const PAUSE_REDEEMS = 1 << 1;
+export const PAUSE_REBALANCES = 1 << 2;
+export const PAUSE_ALL = PAUSE_DEPOSITS | PAUSE_REDEEMS | PAUSE_REBALANCES;
+
+// keeper bounty sources, see BountySource
+export const BOUNTY_SOURCE_SWAP_OUTPUT = 0;
+export const BOUNTY_SOURCE_RESERVE = 1;
@@ -80,5 +80,66 @@ export interface RebalanceConfig {
   maxSlippageBps: number;
   tokenA: PublicKey;
   tokenB: PublicKey;
-  swapAccount?: PublicKey; // allows localnet overrides
+  // accounts of a venue the sdk cannot look up, e.g. a constant-product pool. in the order the
+  // venue expects them.
+  venueAccounts?: PublicKey[];
+  // pay the keeper bounty, if any, to the payer's ATA
+  claimBounty?: boolean;
+}
+
+export interface RebalanceToTargetConfig {
+  maxAmountIn: number;
+  tokenA: PublicKey;
+  tokenB: PublicKey;
+  venueAccounts?: PublicKey[];
+  claimBounty?: boolean;
+}
+
+export interface TwoHopRebalanceConfig {
+  amountIn: number;
+  maxSlippageBps: number;
+  tokenA: PublicKey;
+  tokenIntermediate: PublicKey;
+  tokenB: PublicKey;
+  // accounts of the tokenA -> tokenIntermediate venue, then of the tokenIntermediate -> tokenB venue
+  firstVenueAccounts?: PublicKey[];
+  secondVenueAccounts?: PublicKey[];
+}
+
+// anchor encodes rust enums as an object keyed by the camel cased variant
+export type OracleSource = { pyth: {} } | { switchboard: {} };
+export type SwapVenue = { saber: {} } | { constantProduct: {} };
+export type BountySource = { swapOutput: {} } | { reserve: {} };
+export type CollateralStatus =
+  | { active: {} }
+  | { sunsetting: {} }
+  | { frozen: {} };
+export type AdminAction = { [variant: string]: { [field: string]: any } };
+
+export interface CollateralCaps {
+  maxDepositAmount: u64;
+  maxCrateBalance: u64;
+}
+
+export interface FeeOverrides {
+  depositFeeBps: number | null;
+  redeemFeeBps: number | null;
+}
+
+export interface AllocationBand {
+  lowerBps: number;
+  upperBps: number;
+  rejectDepositsAboveBand: boolean;
+}
+
+export interface KeeperBounty {
+  bps: number;
+  maxAmount: u64;
+  source: BountySource;
+}
+
+export interface RebalanceLimits {
+  minSlotsBetweenRebalances: u64;
+  turnoverWindowSlots: u64;
+  maxTurnoverBps: number;
 }
\ No newline at end of file
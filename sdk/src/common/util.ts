@@ -1,5 +1,6 @@
 import { u64 } from "@solana/spl-token";
 import {
+  AccountMeta,
   Connection,
   Keypair,
   Transaction,
@@ -100,6 +101,40 @@ export const computeMappingFromList = <T>(
   }, {} as { [key: string]: T });
 };
 
+// swap pairs are keyed by their mints in the program's byte order, i.e. mintA < mintB
+export const sortMints = (
+  mintA: PublicKey,
+  mintB: PublicKey
+): [PublicKey, PublicKey] => {
+  return Buffer.compare(mintA.toBuffer(), mintB.toBuffer()) < 0
+    ? [mintA, mintB]
+    : [mintB, mintA];
+};
+
+export const toAccountMetas = (
+  accounts: PublicKey[],
+  isWritable = false
+): AccountMeta[] => {
+  return accounts.map(
+    (acc): AccountMeta => ({
+      pubkey: acc,
+      isSigner: false,
+      isWritable,
+    })
+  );
+};
+
+// the runtime logs data set with set_return_data as "Program return: <program id> <base64 data>"
+export const parseReturnData = (
+  logs: string[],
+  programId: PublicKey
+): Buffer | null => {
+  const prefix = `Program return: ${programId.toBase58()} `;
+  const log = logs.filter((log) => log.startsWith(prefix)).pop();
+
+  return log ? Buffer.from(log.slice(prefix.length), "base64") : null;
+};
+
 export const loadKeypairFromFile = (path: string) => {
   return Keypair.fromSecretKey(Uint8Array.from(fs.readFileSync(path)));
 };
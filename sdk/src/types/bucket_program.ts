@@ -25,6 +25,16 @@ export type BucketProgram = {
           "isMut": true,
           "isSigner": false
         },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
         {
           "name": "rebalanceAuthority",
           "isMut": false,
@@ -67,6 +77,14 @@ export type BucketProgram = {
         {
           "name": "withdrawAuthorityBump",
           "type": "u8"
+        },
+        {
+          "name": "rebalanceStateBump",
+          "type": "u8"
+        },
+        {
+          "name": "bucketStatsBump",
+          "type": "u8"
         }
       ]
     },
@@ -97,7 +115,7 @@ export type BucketProgram = {
       ]
     },
     {
-      "name": "authorizeCollateral",
+      "name": "proposeAuthority",
       "accounts": [
         {
           "name": "authority",
@@ -117,17 +135,13 @@ export type BucketProgram = {
       ],
       "args": [
         {
-          "name": "mint",
+          "name": "pendingAuthority",
           "type": "publicKey"
-        },
-        {
-          "name": "allocation",
-          "type": "u16"
         }
       ]
     },
     {
-      "name": "removeCollateral",
+      "name": "cancelAuthorityTransfer",
       "accounts": [
         {
           "name": "authority",
@@ -145,19 +159,35 @@ export type BucketProgram = {
           "isSigner": false
         }
       ],
-      "args": [
+      "args": []
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
         {
-          "name": "mint",
-          "type": "publicKey"
+          "name": "pendingAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
         }
-      ]
+      ],
+      "args": []
     },
     {
-      "name": "setCollateralAllocations",
+      "name": "resizeBucket",
       "accounts": [
         {
-          "name": "authority",
-          "isMut": false,
+          "name": "payer",
+          "isMut": true,
           "isSigner": true
         },
         {
@@ -169,24 +199,25 @@ export type BucketProgram = {
           "name": "crateToken",
           "isMut": false,
           "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "allocations",
-          "type": {
-            "vec": {
-              "defined": "Collateral"
-            }
-          }
+          "name": "bucketBump",
+          "type": "u8"
         }
       ]
     },
     {
-      "name": "rebalance",
+      "name": "migrateBucket",
       "accounts": [
         {
-          "name": "payer",
+          "name": "authority",
           "isMut": true,
           "isSigner": true
         },
@@ -201,304 +232,6909 @@ export type BucketProgram = {
           "isSigner": false
         },
         {
-          "name": "withdrawAuthority",
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rebalanceStateBump",
+          "type": "u8"
         },
         {
-          "name": "swap",
+          "name": "bucketStatsBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "decommissionBucket",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "swapAuthority",
+          "name": "crateToken",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeBucket",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
         },
         {
-          "name": "userAuthority",
+          "name": "bucket",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "inputAReserve",
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "issueAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "outputBReserve",
+          "name": "withdrawAuthority",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "outputBFees",
+          "name": "rebalanceState",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "poolMint",
+          "name": "bucketStats",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "crateTokenProgram",
-          "isMut": false,
+          "name": "navSnapshot",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "saberProgram",
+          "name": "allocationRamp",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "authorizeCollateral",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "tokenProgram",
+          "name": "crateToken",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "amountIn",
-          "type": "u64"
+          "name": "mint",
+          "type": "publicKey"
         },
         {
-          "name": "minimumAmountOut",
-          "type": "u64"
+          "name": "allocation",
+          "type": "u32"
         }
       ]
     },
     {
-      "name": "deposit",
+      "name": "removeCollateral",
       "accounts": [
         {
-          "name": "depositor",
+          "name": "authority",
           "isMut": false,
           "isSigner": true
         },
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "bucket",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateToken",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "crateTokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
         },
         {
-          "name": "issueAuthority",
+          "name": "crateToken",
           "isMut": false,
           "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setCollateralAllocations",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "crateCollateral",
+          "name": "bucket",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "collateralMint",
+          "name": "crateToken",
           "isMut": false,
           "isSigner": false
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "depositorCollateral",
-          "isMut": true,
-          "isSigner": false
+          "name": "allocations",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralSet",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
         },
         {
-          "name": "depositorReserve",
+          "name": "bucket",
           "isMut": true,
           "isSigner": false
         },
         {
-          "name": "oracle",
+          "name": "crateToken",
           "isMut": false,
           "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "depositAmount",
-          "type": "u64"
+          "name": "collateral",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          }
         }
       ]
     },
     {
-      "name": "redeem",
+      "name": "rampAllocations",
       "accounts": [
         {
-          "name": "withdrawer",
-          "isMut": false,
+          "name": "authority",
+          "isMut": true,
           "isSigner": true
         },
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "bucket",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateToken",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "crateTokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "allocations",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
             }
-          ]
+          }
         },
         {
-          "name": "withdrawAuthority",
+          "name": "endSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stopAllocationRamp",
+      "accounts": [
+        {
+          "name": "authority",
           "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
           "isSigner": false
         },
         {
-          "name": "withdrawerReserve",
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configureTimelock",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
           "isMut": true,
           "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
         }
       ],
       "args": [
         {
-          "name": "withdrawAmount",
+          "name": "delaySlots",
           "type": "u64"
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey"
         }
       ]
-    }
-  ],
-  "accounts": [
+    },
+    {
+      "name": "setGuardian",
+      "accounts": [
+        {
+          "name": "setter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enqueueProposal",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeProposal",
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelProposal",
+      "accounts": [
+        {
+          "name": "canceller",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSwapPair",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintB",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swap",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeSwapPair",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCollateralWrapper",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralWrapper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wrapperProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrapperMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrapperUnderlyingTokens",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeCollateralWrapper",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralWrapper",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCollateralConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          }
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey"
+        },
+        {
+          "name": "caps",
+          "type": {
+            "defined": "CollateralCaps"
+          }
+        },
+        {
+          "name": "feeOverrides",
+          "type": {
+            "defined": "FeeOverrides"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          }
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey"
+        },
+        {
+          "name": "caps",
+          "type": {
+            "defined": "CollateralCaps"
+          }
+        },
+        {
+          "name": "feeOverrides",
+          "type": {
+            "defined": "FeeOverrides"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCollateralStatus",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": "CollateralStatus"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAllocationBand",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "band",
+          "type": {
+            "defined": "AllocationBand"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reportAllocationBands",
+      "accounts": [
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "snapshotNav",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "navSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "navSnapshotBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeCollateralConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateCollateral",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setKeeperBounty",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "keeperBounty",
+          "type": {
+            "defined": "KeeperBounty"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRebalanceLimits",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "limits",
+          "type": {
+            "defined": "RebalanceLimits"
+          }
+        }
+      ]
+    },
+    {
+      "name": "pause",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unpause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rebalance",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minimumAmountOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rebalanceToTarget",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxAmountIn",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rebalanceTwoHop",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minimumAmountOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "issueAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "depositorCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositorReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "depositAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawerReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteDeposit",
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "issueAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "depositorCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositorReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "depositAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteRedeem",
+      "accounts": [
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawerReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteRebalance",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxAmountIn",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "allocationRamp",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "startSlot",
+            "type": "u64"
+          },
+          {
+            "name": "endSlot",
+            "type": "u64"
+          },
+          {
+            "name": "startAllocations",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "endAllocations",
+            "type": {
+              "vec": "u32"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bucketStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalReserveIssued",
+            "type": "u128"
+          },
+          {
+            "name": "totalReserveBurned",
+            "type": "u128"
+          },
+          {
+            "name": "lastActivitySlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "keeperBountyBps",
+            "type": "u16"
+          },
+          {
+            "name": "collateralLen",
+            "type": "u16"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "decommissioned",
+            "type": "u8"
+          },
+          {
+            "name": "keeperBountySource",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": "u8"
+          },
+          {
+            "name": "collateralConfigCount",
+            "type": "u16"
+          },
+          {
+            "name": "swapPairCount",
+            "type": "u16"
+          },
+          {
+            "name": "collateralWrapperCount",
+            "type": "u16"
+          },
+          {
+            "name": "keeperBountyMaxAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timelock",
+            "type": {
+              "defined": "Timelock"
+            }
+          },
+          {
+            "name": "crateMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "crateToken",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rebalanceAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "guardian",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateral",
+            "type": {
+              "array": [
+                {
+                  "defined": "Collateral"
+                },
+                315
+              ]
+            }
+          },
+          {
+            "name": "collateralPauseFlags",
+            "type": {
+              "array": [
+                "u8",
+                315
+              ]
+            }
+          },
+          {
+            "name": "paddingEnd",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collateralConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "oracleSource",
+            "type": {
+              "defined": "OracleSource"
+            }
+          },
+          {
+            "name": "oracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "caps",
+            "type": {
+              "defined": "CollateralCaps"
+            }
+          },
+          {
+            "name": "feeOverrides",
+            "type": {
+              "defined": "FeeOverrides"
+            }
+          },
+          {
+            "name": "band",
+            "type": {
+              "defined": "AllocationBand"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "CollateralStatus"
+            }
+          },
+          {
+            "name": "stats",
+            "type": {
+              "defined": "CollateralStats"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collateralWrapper",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapperProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapper",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapperMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapperUnderlyingTokens",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "issueAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "navSnapshot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "snapshots",
+            "type": {
+              "vec": {
+                "defined": "Nav"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "etaSlot",
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebalanceState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": "RebalanceLimits"
+            }
+          },
+          {
+            "name": "lastRebalanceSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lastAuthorityRebalanceSlot",
+            "type": "u64"
+          },
+          {
+            "name": "windowStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "windowTurnover",
+            "type": "u128"
+          },
+          {
+            "name": "previousWindowTurnover",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapPair",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintA",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintB",
+            "type": "publicKey"
+          },
+          {
+            "name": "venue",
+            "type": {
+              "defined": "SwapVenue"
+            }
+          },
+          {
+            "name": "swapProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "swap",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "withdrawAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RedeemedShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralWeight",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "weight",
+            "type": "u32"
+          },
+          {
+            "name": "targetWeight",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DepositQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "i128"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "reserveIssued",
+            "type": "u64"
+          },
+          {
+            "name": "weightsBps",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebalanceQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintIn",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintOut",
+            "type": "publicKey"
+          },
+          {
+            "name": "priceIn",
+            "type": "i128"
+          },
+          {
+            "name": "priceOut",
+            "type": "i128"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "expectedAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "minimumAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "keeperBounty",
+            "type": "u64"
+          },
+          {
+            "name": "weightsBps",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reserveBurned",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": {
+              "vec": {
+                "defined": "RedeemedShare"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Collateral",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "allocation",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "KeeperBounty",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bps",
+            "type": "u16"
+          },
+          {
+            "name": "maxAmount",
+            "type": "u64"
+          },
+          {
+            "name": "source",
+            "type": {
+              "defined": "BountySource"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Timelock",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralSetDiff",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "added",
+            "type": {
+              "vec": {
+                "defined": "Collateral"
+              }
+            }
+          },
+          {
+            "name": "removed",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "reallocated",
+            "type": {
+              "vec": {
+                "defined": "AllocationChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllocationChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "previousAllocation",
+            "type": "u32"
+          },
+          {
+            "name": "allocation",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralCaps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxDepositAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxCrateBalance",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllocationBand",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lowerBps",
+            "type": "u16"
+          },
+          {
+            "name": "upperBps",
+            "type": "u16"
+          },
+          {
+            "name": "rejectDepositsAboveBand",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeOverrides",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "depositFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalDeposited",
+            "type": "u128"
+          },
+          {
+            "name": "totalRedeemed",
+            "type": "u128"
+          },
+          {
+            "name": "totalSwappedIn",
+            "type": "u128"
+          },
+          {
+            "name": "totalSwappedOut",
+            "type": "u128"
+          },
+          {
+            "name": "lastActivitySlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Nav",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "unixTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "totalValue",
+            "type": "u128"
+          },
+          {
+            "name": "reserveSupply",
+            "type": "u64"
+          },
+          {
+            "name": "navPerToken",
+            "type": "u128"
+          },
+          {
+            "name": "collateralizationBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebalanceLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minSlotsBetweenRebalances",
+            "type": "u64"
+          },
+          {
+            "name": "turnoverWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "maxTurnoverBps",
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Rounding",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Down"
+          },
+          {
+            "name": "Up"
+          }
+        ]
+      }
+    },
+    {
+      "name": "ChildAccount",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CollateralConfig"
+          },
+          {
+            "name": "SwapPair"
+          },
+          {
+            "name": "CollateralWrapper"
+          }
+        ]
+      }
+    },
+    {
+      "name": "BountySource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "SwapOutput"
+          },
+          {
+            "name": "Reserve"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Active"
+          },
+          {
+            "name": "Sunsetting"
+          },
+          {
+            "name": "Frozen"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceStatus",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unknown"
+          },
+          {
+            "name": "Trading"
+          },
+          {
+            "name": "Halted"
+          },
+          {
+            "name": "Auction"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CorpAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "NoCorpAct"
+          }
+        ]
+      }
+    },
+    {
+      "name": "PriceType",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unknown"
+          },
+          {
+            "name": "Price"
+          },
+          {
+            "name": "TWAP"
+          },
+          {
+            "name": "Volatility"
+          }
+        ]
+      }
+    },
+    {
+      "name": "OracleSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "Switchboard"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AdminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AuthorizeCollateral",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "allocation",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "RemoveCollateral",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetCollateralAllocations",
+            "fields": [
+              {
+                "name": "allocations",
+                "type": {
+                  "vec": {
+                    "defined": "Collateral"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "UpdateCollateralSet",
+            "fields": [
+              {
+                "name": "collateral",
+                "type": {
+                  "vec": {
+                    "defined": "Collateral"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "UpdateRebalanceAuthority",
+            "fields": [
+              {
+                "name": "rebalance_authority",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ConfigureTimelock",
+            "fields": [
+              {
+                "name": "delay_slots",
+                "type": "u64"
+              },
+              {
+                "name": "guardian",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetGuardian",
+            "fields": [
+              {
+                "name": "guardian",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetKeeperBounty",
+            "fields": [
+              {
+                "name": "keeper_bounty",
+                "type": {
+                  "defined": "KeeperBounty"
+                }
+              }
+            ]
+          },
+          {
+            "name": "UpdateCollateralConfig",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "oracle_source",
+                "type": {
+                  "defined": "OracleSource"
+                }
+              },
+              {
+                "name": "oracle",
+                "type": "publicKey"
+              },
+              {
+                "name": "caps",
+                "type": {
+                  "defined": "CollateralCaps"
+                }
+              },
+              {
+                "name": "fee_overrides",
+                "type": {
+                  "defined": "FeeOverrides"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetAllocationBand",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "band",
+                "type": {
+                  "defined": "AllocationBand"
+                }
+              }
+            ]
+          },
+          {
+            "name": "AddSwapPair",
+            "fields": [
+              {
+                "name": "bump",
+                "type": "u8"
+              },
+              {
+                "name": "mint_a",
+                "type": "publicKey"
+              },
+              {
+                "name": "mint_b",
+                "type": "publicKey"
+              },
+              {
+                "name": "venue",
+                "type": {
+                  "defined": "SwapVenue"
+                }
+              },
+              {
+                "name": "swap_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "swap",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveSwapPair",
+            "fields": [
+              {
+                "name": "mint_a",
+                "type": "publicKey"
+              },
+              {
+                "name": "mint_b",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "SwapVenue",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Saber"
+          },
+          {
+            "name": "ConstantProduct"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "BucketCreated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "crateToken",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "crateMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BucketMigrated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bucketVersion",
+          "type": "u8",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BucketResized",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "space",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BucketDecommissioned",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "BucketClosed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferProposed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferCancelled",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AuthorityTransferAccepted",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RebalanceAuthorityUpdated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousRebalanceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rebalanceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "TimelockConfigured",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delaySlots",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "GuardianSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "setter",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalEnqueued",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposalId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "proposer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "etaSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalExecuted",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposalId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalCancelled",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "proposalId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "canceller",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "PauseFlagsSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "flags",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralAuthorized",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "allocation",
+          "type": "u32",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralRemoved",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralSetUpdated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "added",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "removed",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "reallocated",
+          "type": {
+            "vec": {
+              "defined": "AllocationChange"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationsSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "allocations",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationRampStarted",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "startSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "startAllocations",
+          "type": {
+            "vec": "u32"
+          },
+          "index": false
+        },
+        {
+          "name": "endAllocations",
+          "type": {
+            "vec": "u32"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationRampStopped",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationBandsReported",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "outOfBand",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralConfigAdded",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          },
+          "index": false
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralConfigUpdated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          },
+          "index": false
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralConfigClosed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationBandSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "band",
+          "type": {
+            "defined": "AllocationBand"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralStatusSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "status",
+          "type": {
+            "defined": "CollateralStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralWrapperAdded",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "wrapperMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralWrapperRemoved",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "wrapperMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapPairAdded",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintA",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintB",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          },
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapPairRemoved",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintA",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintB",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "KeeperBountySet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "keeperBounty",
+          "type": {
+            "defined": "KeeperBounty"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "RebalanceLimitsSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "limits",
+          "type": {
+            "defined": "RebalanceLimits"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Deposited",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "reserveIssued",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Redeemed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reserveBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": {
+              "defined": "RedeemedShare"
+            }
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Rebalanced",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintIn",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintOut",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pools",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "keeperBounty",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NavSnapshotTaken",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalValue",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "reserveSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "navPerToken",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "collateralizationBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "weights",
+          "type": {
+            "vec": {
+              "defined": "CollateralWeight"
+            }
+          },
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "WrongBurnError",
+      "msg": "Must burn reserve token"
+    },
+    {
+      "code": 6001,
+      "name": "AllocationPpmError",
+      "msg": "Allocation ppm error"
+    },
+    {
+      "code": 6002,
+      "name": "WrongCollateralError",
+      "msg": "Must deposit an approved collateral mint"
+    },
+    {
+      "code": 6003,
+      "name": "CollateralAlreadyAuthorizedError",
+      "msg": "Cannot re-authorized a collateral mint authorized"
+    },
+    {
+      "code": 6004,
+      "name": "CollateralDoesNotExistError",
+      "msg": "Cannot de-authorized a collateral mint that does not exist"
+    },
+    {
+      "code": 6005,
+      "name": "CollateralSizeLimitsExceeded",
+      "msg": "Collateral size limits exceeded"
+    },
+    {
+      "code": 6006,
+      "name": "CallerCannotRebalanceCollateral",
+      "msg": "Caller is not authorized to rebalance specified mints"
+    },
+    {
+      "code": 6007,
+      "name": "MinCollateralError",
+      "msg": "Must maintain at least 1 approved collateral mint"
+    },
+    {
+      "code": 6008,
+      "name": "UnableToLoadOracle",
+      "msg": "Unable To Load Oracles"
+    },
+    {
+      "code": 6009,
+      "name": "MathError",
+      "msg": "Math Error"
+    },
+    {
+      "code": 6010,
+      "name": "CastingFailure",
+      "msg": "Casting Failure"
+    },
+    {
+      "code": 6011,
+      "name": "InvalidOracle",
+      "msg": "Oracle Values are invalid"
+    },
+    {
+      "code": 6012,
+      "name": "CollateralValuationAccountsMismatch",
+      "msg": "Collateral valuation accounts must match the bucket's authorized collateral"
+    },
+    {
+      "code": 6013,
+      "name": "RebalanceNotRequired",
+      "msg": "Collateral allocations are already at their targets"
+    },
+    {
+      "code": 6014,
+      "name": "RebalancePairMismatch",
+      "msg": "Rebalance accounts do not match the computed source and destination collateral"
+    },
+    {
+      "code": 6015,
+      "name": "RebalanceIncreasedDeviation",
+      "msg": "Rebalance would increase the bucket's total allocation deviation"
+    },
+    {
+      "code": 6016,
+      "name": "SwapValueLost",
+      "msg": "Swap lost more value than the slippage tolerance allows"
+    },
+    {
+      "code": 6017,
+      "name": "KeeperBountyTooLarge",
+      "msg": "Keeper bounty exceeds the max bounty bps"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidKeeperBountyAccounts",
+      "msg": "Keeper bounty accounts are invalid"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRebalanceTokenAccounts",
+      "msg": "Rebalance token accounts must belong to the crate and bucket"
+    },
+    {
+      "code": 6020,
+      "name": "SwapPairMismatch",
+      "msg": "Swap pair does not cover the collateral being rebalanced"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidSwapVenueAccounts",
+      "msg": "Swap venue accounts are invalid"
+    },
+    {
+      "code": 6022,
+      "name": "UnregisteredSwapProgram",
+      "msg": "Swap program is not registered for this swap pair"
+    },
+    {
+      "code": 6023,
+      "name": "UnregisteredSwapPool",
+      "msg": "Swap pool is not registered for this swap pair"
+    },
+    {
+      "code": 6024,
+      "name": "DecimalWrapperRequired",
+      "msg": "Collateral decimals differ, a decimal wrapper is required"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidDecimalWrapperAccounts",
+      "msg": "Decimal wrapper accounts do not match the collateral wrapper config"
+    },
+    {
+      "code": 6026,
+      "name": "DecimalScalingOverflow",
+      "msg": "Scaling an amount between decimals overflowed"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRebalanceLimits",
+      "msg": "Rebalance limits are invalid"
+    },
+    {
+      "code": 6028,
+      "name": "RebalanceCooldownActive",
+      "msg": "Too few slots have passed since the last rebalance"
+    },
+    {
+      "code": 6029,
+      "name": "RebalanceTurnoverExceeded",
+      "msg": "Rebalance exceeds the bucket's turnover limit for the trailing window"
+    },
+    {
+      "code": 6030,
+      "name": "NoPendingAuthority",
+      "msg": "There is no pending authority transfer"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidPendingAuthority",
+      "msg": "Pending authority is invalid"
+    },
+    {
+      "code": 6032,
+      "name": "TimelockEnabled",
+      "msg": "Timelock is enabled, admin changes must be enqueued as a proposal"
+    },
+    {
+      "code": 6033,
+      "name": "TimelockDisabled",
+      "msg": "Timelock is disabled, admin changes can be applied directly"
+    },
+    {
+      "code": 6034,
+      "name": "TimelockDelayTooLong",
+      "msg": "Timelock delay exceeds the max delay"
+    },
+    {
+      "code": 6035,
+      "name": "ProposalNotReady",
+      "msg": "Proposal cannot be executed before its eta slot"
+    },
+    {
+      "code": 6036,
+      "name": "UnauthorizedProposalCanceller",
+      "msg": "Only the bucket authority or guardian can cancel a proposal"
+    },
+    {
+      "code": 6037,
+      "name": "Paused",
+      "msg": "Operation is paused"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidPauseFlags",
+      "msg": "Pause flags are invalid"
+    },
+    {
+      "code": 6039,
+      "name": "UnauthorizedPauser",
+      "msg": "Only the bucket authority or guardian can pause"
+    },
+    {
+      "code": 6040,
+      "name": "UnauthorizedGuardianUpdate",
+      "msg": "Only the bucket authority or the guardian can set the guardian"
+    },
+    {
+      "code": 6041,
+      "name": "BucketDecommissioned",
+      "msg": "Bucket is decommissioned"
+    },
+    {
+      "code": 6042,
+      "name": "BucketNotDecommissioned",
+      "msg": "Bucket must be decommissioned before it can be closed"
+    },
+    {
+      "code": 6043,
+      "name": "BucketNotEmpty",
+      "msg": "Bucket still holds collateral or has reserve supply"
+    },
+    {
+      "code": 6044,
+      "name": "BucketHasChildAccounts",
+      "msg": "Bucket still has collateral configs, swap pairs or collateral wrappers"
+    },
+    {
+      "code": 6045,
+      "name": "AccountResizeTooLarge",
+      "msg": "Account cannot grow by more than 10KB in a single instruction"
+    },
+    {
+      "code": 6046,
+      "name": "BucketAlreadyMigrated",
+      "msg": "Bucket account is already at the current version"
+    },
+    {
+      "code": 6047,
+      "name": "BucketAlreadySized",
+      "msg": "Bucket account is already at its full size"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidBucketAccount",
+      "msg": "Bucket account data is invalid"
+    },
+    {
+      "code": 6049,
+      "name": "CollateralNotAcceptingDeposits",
+      "msg": "Collateral is not accepting deposits"
+    },
+    {
+      "code": 6050,
+      "name": "CollateralFrozen",
+      "msg": "Collateral is frozen"
+    },
+    {
+      "code": 6051,
+      "name": "CollateralCapExceeded",
+      "msg": "Deposit exceeds the collateral's cap"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidCollateralConfig",
+      "msg": "Collateral config does not match the collateral"
+    },
+    {
+      "code": 6053,
+      "name": "CollateralStillAuthorized",
+      "msg": "Collateral config can only be closed once the collateral is removed"
+    },
+    {
+      "code": 6054,
+      "name": "UnsupportedOracleSource",
+      "msg": "Oracle source is not supported"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidProposalAccounts",
+      "msg": "Accounts do not match the proposal's action"
+    },
+    {
+      "code": 6056,
+      "name": "CollateralConfigHasBalance",
+      "msg": "Collateral config can only be closed once the crate holds none of the collateral"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidAllocationBand",
+      "msg": "Allocation band exceeds max bps"
+    },
+    {
+      "code": 6058,
+      "name": "CollateralWithinBand",
+      "msg": "Collateral is within its allocation band"
+    },
+    {
+      "code": 6059,
+      "name": "AllocationBandExceeded",
+      "msg": "Deposit would push the collateral above its allocation band"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidAllocationRamp",
+      "msg": "Allocation ramp must end after it starts"
+    },
+    {
+      "code": 6061,
+      "name": "NoActiveAllocationRamp",
+      "msg": "No allocation ramp in progress"
+    },
+    {
+      "code": 6062,
+      "name": "DuplicateCollateral",
+      "msg": "Collateral mints must be unique"
+    },
+    {
+      "code": 6063,
+      "name": "OracleExponentUnsupported",
+      "msg": "Oracle exponent is not supported"
+    },
+    {
+      "code": 6064,
+      "name": "IssueAmountOverflow",
+      "msg": "Issue amount does not fit in a token amount"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidRebalanceAccountCount",
+      "msg": "Wrong number of rebalance accounts"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidRedeemAccountCount",
+      "msg": "Wrong number of redeem accounts"
+    },
+    {
+      "code": 6067,
+      "name": "NavSnapshotAlreadyTaken",
+      "msg": "A NAV snapshot was already taken in this slot"
+    }
+  ]
+};
+
+export const IDL: BucketProgram = {
+  "version": "0.1.0",
+  "name": "bucket_program",
+  "instructions": [
+    {
+      "name": "createBucket",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "issueAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bucketBump",
+          "type": "u8"
+        },
+        {
+          "name": "crateBump",
+          "type": "u8"
+        },
+        {
+          "name": "issueAuthorityBump",
+          "type": "u8"
+        },
+        {
+          "name": "withdrawAuthorityBump",
+          "type": "u8"
+        },
+        {
+          "name": "rebalanceStateBump",
+          "type": "u8"
+        },
+        {
+          "name": "bucketStatsBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "updateRebalanceAuthority",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rebalanceAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "proposeAuthority",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "cancelAuthorityTransfer",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "acceptAuthority",
+      "accounts": [
+        {
+          "name": "pendingAuthority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "resizeBucket",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bucketBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "migrateBucket",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "rebalanceStateBump",
+          "type": "u8"
+        },
+        {
+          "name": "bucketStatsBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "decommissionBucket",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeBucket",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "issueAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "navSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "authorizeCollateral",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        },
+        {
+          "name": "allocation",
+          "type": "u32"
+        }
+      ]
+    },
+    {
+      "name": "removeCollateral",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "mint",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setCollateralAllocations",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "allocations",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralSet",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "collateral",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          }
+        }
+      ]
+    },
+    {
+      "name": "rampAllocations",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "allocations",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          }
+        },
+        {
+          "name": "endSlot",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "stopAllocationRamp",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "configureTimelock",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "delaySlots",
+          "type": "u64"
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setGuardian",
+      "accounts": [
+        {
+          "name": "setter",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "guardian",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enqueueProposal",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          }
+        }
+      ]
+    },
+    {
+      "name": "executeProposal",
+      "accounts": [
+        {
+          "name": "executor",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "cancelProposal",
+      "accounts": [
+        {
+          "name": "canceller",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "proposal",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "proposer",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addSwapPair",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintA",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mintB",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swap",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          }
+        }
+      ]
+    },
+    {
+      "name": "removeSwapPair",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCollateralWrapper",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralWrapper",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "wrapperProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrapper",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrapperMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "wrapperUnderlyingTokens",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "removeCollateralWrapper",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralWrapper",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "addCollateralConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "mint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "bump",
+          "type": "u8"
+        },
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          }
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey"
+        },
+        {
+          "name": "caps",
+          "type": {
+            "defined": "CollateralCaps"
+          }
+        },
+        {
+          "name": "feeOverrides",
+          "type": {
+            "defined": "FeeOverrides"
+          }
+        }
+      ]
+    },
+    {
+      "name": "updateCollateralConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          }
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey"
+        },
+        {
+          "name": "caps",
+          "type": {
+            "defined": "CollateralCaps"
+          }
+        },
+        {
+          "name": "feeOverrides",
+          "type": {
+            "defined": "FeeOverrides"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setCollateralStatus",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "status",
+          "type": {
+            "defined": "CollateralStatus"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setAllocationBand",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "band",
+          "type": {
+            "defined": "AllocationBand"
+          }
+        }
+      ]
+    },
+    {
+      "name": "reportAllocationBands",
+      "accounts": [
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "snapshotNav",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "navSnapshot",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "navSnapshotBump",
+          "type": "u8"
+        }
+      ]
+    },
+    {
+      "name": "closeCollateralConfig",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateCollateral",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setKeeperBounty",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "keeperBounty",
+          "type": {
+            "defined": "KeeperBounty"
+          }
+        }
+      ]
+    },
+    {
+      "name": "setRebalanceLimits",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "limits",
+          "type": {
+            "defined": "RebalanceLimits"
+          }
+        }
+      ]
+    },
+    {
+      "name": "pause",
+      "accounts": [
+        {
+          "name": "pauser",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "unpause",
+      "accounts": [
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "flags",
+          "type": "u8"
+        },
+        {
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          }
+        }
+      ]
+    },
+    {
+      "name": "rebalance",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minimumAmountOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rebalanceToTarget",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxAmountIn",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "rebalanceTwoHop",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "amountIn",
+          "type": "u64"
+        },
+        {
+          "name": "minimumAmountOut",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "deposit",
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "issueAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "depositorCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositorReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "depositAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "redeem",
+      "accounts": [
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawerReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteDeposit",
+      "accounts": [
+        {
+          "name": "depositor",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "issueAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralMint",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "depositorCollateral",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "depositorReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "collateralConfig",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "oracle",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "depositAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteRedeem",
+      "accounts": [
+        {
+          "name": "withdrawer",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "common",
+          "accounts": [
+            {
+              "name": "bucket",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateToken",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "crateMint",
+              "isMut": true,
+              "isSigner": false
+            },
+            {
+              "name": "crateTokenProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false
+            }
+          ]
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawerReserve",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "withdrawAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "quoteRebalance",
+      "accounts": [
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true
+        },
+        {
+          "name": "bucket",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "crateToken",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "withdrawAuthority",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "rebalanceState",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "bucketStats",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "allocationRamp",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapPair",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "crateTokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "swapProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        }
+      ],
+      "args": [
+        {
+          "name": "maxAmountIn",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "allocationRamp",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "startSlot",
+            "type": "u64"
+          },
+          {
+            "name": "endSlot",
+            "type": "u64"
+          },
+          {
+            "name": "startAllocations",
+            "type": {
+              "vec": "u32"
+            }
+          },
+          {
+            "name": "endAllocations",
+            "type": {
+              "vec": "u32"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "bucketStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "totalReserveIssued",
+            "type": "u128"
+          },
+          {
+            "name": "totalReserveBurned",
+            "type": "u128"
+          },
+          {
+            "name": "lastActivitySlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "bucket",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "version",
+            "type": "u8"
+          },
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "keeperBountyBps",
+            "type": "u16"
+          },
+          {
+            "name": "collateralLen",
+            "type": "u16"
+          },
+          {
+            "name": "pauseFlags",
+            "type": "u8"
+          },
+          {
+            "name": "decommissioned",
+            "type": "u8"
+          },
+          {
+            "name": "keeperBountySource",
+            "type": "u8"
+          },
+          {
+            "name": "padding",
+            "type": "u8"
+          },
+          {
+            "name": "collateralConfigCount",
+            "type": "u16"
+          },
+          {
+            "name": "swapPairCount",
+            "type": "u16"
+          },
+          {
+            "name": "collateralWrapperCount",
+            "type": "u16"
+          },
+          {
+            "name": "keeperBountyMaxAmount",
+            "type": "u64"
+          },
+          {
+            "name": "timelock",
+            "type": {
+              "defined": "Timelock"
+            }
+          },
+          {
+            "name": "crateMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "crateToken",
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "type": "publicKey"
+          },
+          {
+            "name": "rebalanceAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "pendingAuthority",
+            "type": "publicKey"
+          },
+          {
+            "name": "guardian",
+            "type": "publicKey"
+          },
+          {
+            "name": "collateral",
+            "type": {
+              "array": [
+                {
+                  "defined": "Collateral"
+                },
+                315
+              ]
+            }
+          },
+          {
+            "name": "collateralPauseFlags",
+            "type": {
+              "array": [
+                "u8",
+                315
+              ]
+            }
+          },
+          {
+            "name": "paddingEnd",
+            "type": {
+              "array": [
+                "u8",
+                1
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collateralConfig",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "decimals",
+            "type": "u8"
+          },
+          {
+            "name": "oracleSource",
+            "type": {
+              "defined": "OracleSource"
+            }
+          },
+          {
+            "name": "oracle",
+            "type": "publicKey"
+          },
+          {
+            "name": "caps",
+            "type": {
+              "defined": "CollateralCaps"
+            }
+          },
+          {
+            "name": "feeOverrides",
+            "type": {
+              "defined": "FeeOverrides"
+            }
+          },
+          {
+            "name": "band",
+            "type": {
+              "defined": "AllocationBand"
+            }
+          },
+          {
+            "name": "status",
+            "type": {
+              "defined": "CollateralStatus"
+            }
+          },
+          {
+            "name": "stats",
+            "type": {
+              "defined": "CollateralStats"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "collateralWrapper",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapperProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapper",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapperMint",
+            "type": "publicKey"
+          },
+          {
+            "name": "wrapperUnderlyingTokens",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
+    {
+      "name": "issueAuthority",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "navSnapshot",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "head",
+            "type": "u16"
+          },
+          {
+            "name": "snapshots",
+            "type": {
+              "vec": {
+                "defined": "Nav"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "proposal",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "id",
+            "type": "u64"
+          },
+          {
+            "name": "proposer",
+            "type": "publicKey"
+          },
+          {
+            "name": "etaSlot",
+            "type": "u64"
+          },
+          {
+            "name": "action",
+            "type": {
+              "defined": "AdminAction"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "rebalanceState",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "limits",
+            "type": {
+              "defined": "RebalanceLimits"
+            }
+          },
+          {
+            "name": "lastRebalanceSlot",
+            "type": "u64"
+          },
+          {
+            "name": "lastAuthorityRebalanceSlot",
+            "type": "u64"
+          },
+          {
+            "name": "windowStartSlot",
+            "type": "u64"
+          },
+          {
+            "name": "windowTurnover",
+            "type": "u128"
+          },
+          {
+            "name": "previousWindowTurnover",
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "swapPair",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bump",
+            "type": "u8"
+          },
+          {
+            "name": "bucket",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintA",
+            "type": "publicKey"
+          },
+          {
+            "name": "mintB",
+            "type": "publicKey"
+          },
+          {
+            "name": "venue",
+            "type": {
+              "defined": "SwapVenue"
+            }
+          },
+          {
+            "name": "swapProgram",
+            "type": "publicKey"
+          },
+          {
+            "name": "swap",
+            "type": "publicKey"
+          }
+        ]
+      }
+    },
     {
-      "name": "bucket",
+      "name": "withdrawAuthority",
       "type": {
         "kind": "struct",
         "fields": [
           {
             "name": "bump",
             "type": "u8"
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "RedeemedShare",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
           },
           {
-            "name": "crateMint",
+            "name": "amount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralWeight",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
             "type": "publicKey"
           },
           {
-            "name": "crateToken",
+            "name": "weight",
+            "type": "u32"
+          },
+          {
+            "name": "targetWeight",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "DepositQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "amount",
+            "type": "u64"
+          },
+          {
+            "name": "price",
+            "type": "i128"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "reserveIssued",
+            "type": "u64"
+          },
+          {
+            "name": "weightsBps",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebalanceQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mintIn",
             "type": "publicKey"
           },
           {
-            "name": "authority",
+            "name": "mintOut",
             "type": "publicKey"
           },
           {
-            "name": "rebalanceAuthority",
+            "name": "priceIn",
+            "type": "i128"
+          },
+          {
+            "name": "priceOut",
+            "type": "i128"
+          },
+          {
+            "name": "amountIn",
+            "type": "u64"
+          },
+          {
+            "name": "expectedAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "minimumAmountOut",
+            "type": "u64"
+          },
+          {
+            "name": "keeperBounty",
+            "type": "u64"
+          },
+          {
+            "name": "weightsBps",
+            "type": {
+              "vec": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "RedeemQuote",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "reserveBurned",
+            "type": "u64"
+          },
+          {
+            "name": "fee",
+            "type": "u64"
+          },
+          {
+            "name": "shares",
+            "type": {
+              "vec": {
+                "defined": "RedeemedShare"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Collateral",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
             "type": "publicKey"
           },
           {
-            "name": "collateral",
+            "name": "allocation",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "KeeperBounty",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "bps",
+            "type": "u16"
+          },
+          {
+            "name": "maxAmount",
+            "type": "u64"
+          },
+          {
+            "name": "source",
+            "type": {
+              "defined": "BountySource"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "Timelock",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "delaySlots",
+            "type": "u64"
+          },
+          {
+            "name": "proposalCount",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralSetDiff",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "added",
             "type": {
               "vec": {
                 "defined": "Collateral"
               }
             }
+          },
+          {
+            "name": "removed",
+            "type": {
+              "vec": "publicKey"
+            }
+          },
+          {
+            "name": "reallocated",
+            "type": {
+              "vec": {
+                "defined": "AllocationChange"
+              }
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllocationChange",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "mint",
+            "type": "publicKey"
+          },
+          {
+            "name": "previousAllocation",
+            "type": "u32"
+          },
+          {
+            "name": "allocation",
+            "type": "u32"
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralCaps",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "maxDepositAmount",
+            "type": "u64"
+          },
+          {
+            "name": "maxCrateBalance",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "AllocationBand",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "lowerBps",
+            "type": "u16"
+          },
+          {
+            "name": "upperBps",
+            "type": "u16"
+          },
+          {
+            "name": "rejectDepositsAboveBand",
+            "type": "bool"
+          }
+        ]
+      }
+    },
+    {
+      "name": "FeeOverrides",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "depositFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          },
+          {
+            "name": "redeemFeeBps",
+            "type": {
+              "option": "u16"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "CollateralStats",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "totalDeposited",
+            "type": "u128"
+          },
+          {
+            "name": "totalRedeemed",
+            "type": "u128"
+          },
+          {
+            "name": "totalSwappedIn",
+            "type": "u128"
+          },
+          {
+            "name": "totalSwappedOut",
+            "type": "u128"
+          },
+          {
+            "name": "lastActivitySlot",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "Nav",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "slot",
+            "type": "u64"
+          },
+          {
+            "name": "unixTimestamp",
+            "type": "i64"
+          },
+          {
+            "name": "totalValue",
+            "type": "u128"
+          },
+          {
+            "name": "reserveSupply",
+            "type": "u64"
+          },
+          {
+            "name": "navPerToken",
+            "type": "u128"
+          },
+          {
+            "name": "collateralizationBps",
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RebalanceLimits",
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "minSlotsBetweenRebalances",
+            "type": "u64"
+          },
+          {
+            "name": "turnoverWindowSlots",
+            "type": "u64"
+          },
+          {
+            "name": "maxTurnoverBps",
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "issueAuthority",
+      "name": "Rounding",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "Down"
+          },
+          {
+            "name": "Up"
           }
         ]
       }
     },
     {
-      "name": "withdrawAuthority",
+      "name": "ChildAccount",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "bump",
-            "type": "u8"
+            "name": "CollateralConfig"
+          },
+          {
+            "name": "SwapPair"
+          },
+          {
+            "name": "CollateralWrapper"
           }
         ]
       }
-    }
-  ],
-  "types": [
+    },
     {
-      "name": "Collateral",
+      "name": "BountySource",
       "type": {
-        "kind": "struct",
-        "fields": [
+        "kind": "enum",
+        "variants": [
           {
-            "name": "mint",
-            "type": "publicKey"
+            "name": "SwapOutput"
           },
           {
-            "name": "allocation",
-            "type": "u16"
+            "name": "Reserve"
           }
         ]
       }
     },
     {
-      "name": "OracleSource",
+      "name": "CollateralStatus",
       "type": {
         "kind": "enum",
         "variants": [
           {
-            "name": "Pyth"
+            "name": "Active"
           },
           {
-            "name": "Switchboard"
+            "name": "Sunsetting"
+          },
+          {
+            "name": "Frozen"
           }
         ]
       }
@@ -553,632 +7189,1259 @@ export type BucketProgram = {
           }
         ]
       }
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "WrongBurnError",
-      "msg": "Must burn reserve token"
     },
     {
-      "code": 6001,
-      "name": "AllocationBpsError",
-      "msg": "Allocation bps error"
+      "name": "OracleSource",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Pyth"
+          },
+          {
+            "name": "Switchboard"
+          }
+        ]
+      }
     },
     {
-      "code": 6002,
-      "name": "WrongCollateralError",
-      "msg": "Must deposit an approved collateral mint"
+      "name": "AdminAction",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "AuthorizeCollateral",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "allocation",
+                "type": "u32"
+              }
+            ]
+          },
+          {
+            "name": "RemoveCollateral",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetCollateralAllocations",
+            "fields": [
+              {
+                "name": "allocations",
+                "type": {
+                  "vec": {
+                    "defined": "Collateral"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "UpdateCollateralSet",
+            "fields": [
+              {
+                "name": "collateral",
+                "type": {
+                  "vec": {
+                    "defined": "Collateral"
+                  }
+                }
+              }
+            ]
+          },
+          {
+            "name": "UpdateRebalanceAuthority",
+            "fields": [
+              {
+                "name": "rebalance_authority",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "ConfigureTimelock",
+            "fields": [
+              {
+                "name": "delay_slots",
+                "type": "u64"
+              },
+              {
+                "name": "guardian",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetGuardian",
+            "fields": [
+              {
+                "name": "guardian",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "SetKeeperBounty",
+            "fields": [
+              {
+                "name": "keeper_bounty",
+                "type": {
+                  "defined": "KeeperBounty"
+                }
+              }
+            ]
+          },
+          {
+            "name": "UpdateCollateralConfig",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "oracle_source",
+                "type": {
+                  "defined": "OracleSource"
+                }
+              },
+              {
+                "name": "oracle",
+                "type": "publicKey"
+              },
+              {
+                "name": "caps",
+                "type": {
+                  "defined": "CollateralCaps"
+                }
+              },
+              {
+                "name": "fee_overrides",
+                "type": {
+                  "defined": "FeeOverrides"
+                }
+              }
+            ]
+          },
+          {
+            "name": "SetAllocationBand",
+            "fields": [
+              {
+                "name": "mint",
+                "type": "publicKey"
+              },
+              {
+                "name": "band",
+                "type": {
+                  "defined": "AllocationBand"
+                }
+              }
+            ]
+          },
+          {
+            "name": "AddSwapPair",
+            "fields": [
+              {
+                "name": "bump",
+                "type": "u8"
+              },
+              {
+                "name": "mint_a",
+                "type": "publicKey"
+              },
+              {
+                "name": "mint_b",
+                "type": "publicKey"
+              },
+              {
+                "name": "venue",
+                "type": {
+                  "defined": "SwapVenue"
+                }
+              },
+              {
+                "name": "swap_program",
+                "type": "publicKey"
+              },
+              {
+                "name": "swap",
+                "type": "publicKey"
+              }
+            ]
+          },
+          {
+            "name": "RemoveSwapPair",
+            "fields": [
+              {
+                "name": "mint_a",
+                "type": "publicKey"
+              },
+              {
+                "name": "mint_b",
+                "type": "publicKey"
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "code": 6003,
-      "name": "CollateralAlreadyAuthorizedError",
-      "msg": "Cannot re-authorized a collateral mint authorized"
+      "name": "SwapVenue",
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Saber"
+          },
+          {
+            "name": "ConstantProduct"
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "BucketCreated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "crateToken",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "crateMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6004,
-      "name": "CollateralDoesNotExistError",
-      "msg": "Cannot de-authorized a collateral mint that does not exist"
+      "name": "BucketMigrated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "bucketVersion",
+          "type": "u8",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6005,
-      "name": "CollateralSizeLimitsExceeded",
-      "msg": "Collateral size limits exceeded"
+      "name": "BucketResized",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "space",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6006,
-      "name": "CallerCannotRebalanceCollateral",
-      "msg": "Caller is not authorized to rebalance specified mints"
+      "name": "BucketDecommissioned",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6007,
-      "name": "MinCollateralError",
-      "msg": "Must maintain at least 1 approved collateral mint"
+      "name": "BucketClosed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6008,
-      "name": "NumberOfSizeNotSupported",
-      "msg": "Number is too large and is not supported"
+      "name": "AuthorityTransferProposed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6009,
-      "name": "UnableToLoadOracle",
-      "msg": "Unable To Load Oracles"
+      "name": "AuthorityTransferCancelled",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pendingAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6010,
-      "name": "MathError",
-      "msg": "Math Error"
+      "name": "AuthorityTransferAccepted",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6011,
-      "name": "CastingFailure",
-      "msg": "Casting Failure"
+      "name": "RebalanceAuthorityUpdated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "previousRebalanceAuthority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rebalanceAuthority",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
     },
     {
-      "code": 6012,
-      "name": "InvalidOracle",
-      "msg": "Oracle Values are invalid"
-    }
-  ]
-};
-
-export const IDL: BucketProgram = {
-  "version": "0.1.0",
-  "name": "bucket_program",
-  "instructions": [
+      "name": "TimelockConfigured",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delaySlots",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "createBucket",
-      "accounts": [
+      "name": "GuardianSet",
+      "fields": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "bucket",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "issueAuthority",
-          "isMut": true,
-          "isSigner": false
+          "name": "guardian",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "withdrawAuthority",
-          "isMut": true,
-          "isSigner": false
+          "name": "setter",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "ProposalEnqueued",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "rebalanceAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "proposalId",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "crateToken",
-          "isMut": true,
-          "isSigner": false
+          "name": "proposer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "etaSlot",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "crateTokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          },
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "ProposalExecuted",
+      "fields": [
         {
-          "name": "bucketBump",
-          "type": "u8"
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "crateBump",
-          "type": "u8"
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "issueAuthorityBump",
-          "type": "u8"
+          "name": "proposalId",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "withdrawAuthorityBump",
-          "type": "u8"
+          "name": "executor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "action",
+          "type": {
+            "defined": "AdminAction"
+          },
+          "index": false
         }
       ]
     },
     {
-      "name": "updateRebalanceAuthority",
-      "accounts": [
+      "name": "ProposalCancelled",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "bucket",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateToken",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "proposalId",
+          "type": "u64",
+          "index": false
+        },
         {
-          "name": "rebalanceAuthority",
-          "type": "publicKey"
+          "name": "canceller",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "authorizeCollateral",
-      "accounts": [
+      "name": "PauseFlagsSet",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "bucket",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateToken",
-          "isMut": false,
-          "isSigner": false
+          "name": "mint",
+          "type": {
+            "option": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "flags",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "paused",
+          "type": "bool",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "CollateralAuthorized",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "mint",
-          "type": "publicKey"
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "allocation",
-          "type": "u16"
+          "type": "u32",
+          "index": false
         }
       ]
     },
     {
-      "name": "removeCollateral",
-      "accounts": [
+      "name": "CollateralRemoved",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "bucket",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
-        {
-          "name": "crateToken",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
         {
           "name": "mint",
-          "type": "publicKey"
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "setCollateralAllocations",
-      "accounts": [
+      "name": "CollateralSetUpdated",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "bucket",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateToken",
-          "isMut": false,
-          "isSigner": false
+          "name": "added",
+          "type": {
+            "vec": {
+              "defined": "Collateral"
+            }
+          },
+          "index": false
+        },
+        {
+          "name": "removed",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        },
+        {
+          "name": "reallocated",
+          "type": {
+            "vec": {
+              "defined": "AllocationChange"
+            }
+          },
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "AllocationsSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "allocations",
           "type": {
             "vec": {
               "defined": "Collateral"
             }
-          }
+          },
+          "index": false
         }
       ]
     },
     {
-      "name": "rebalance",
-      "accounts": [
+      "name": "AllocationRampStarted",
+      "fields": [
         {
-          "name": "payer",
-          "isMut": true,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
           "name": "bucket",
-          "isMut": true,
-          "isSigner": false
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateToken",
-          "isMut": false,
-          "isSigner": false
+          "name": "startSlot",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "withdrawAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "endSlot",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "swap",
-          "isMut": false,
-          "isSigner": false
+          "name": "startAllocations",
+          "type": {
+            "vec": "u32"
+          },
+          "index": false
         },
         {
-          "name": "swapAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "endAllocations",
+          "type": {
+            "vec": "u32"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationRampStopped",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "userAuthority",
-          "isMut": true,
-          "isSigner": false
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "inputAReserve",
-          "isMut": true,
-          "isSigner": false
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationBandsReported",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "outOfBand",
+          "type": {
+            "vec": "publicKey"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralConfigAdded",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          },
+          "index": false
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralConfigUpdated",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "oracleSource",
+          "type": {
+            "defined": "OracleSource"
+          },
+          "index": false
+        },
+        {
+          "name": "oracle",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralConfigClosed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "AllocationBandSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "outputBReserve",
-          "isMut": true,
-          "isSigner": false
+          "name": "band",
+          "type": {
+            "defined": "AllocationBand"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralStatusSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "outputBFees",
-          "isMut": true,
-          "isSigner": false
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "poolMint",
-          "isMut": true,
-          "isSigner": false
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateTokenProgram",
-          "isMut": false,
-          "isSigner": false
-        },
+          "name": "status",
+          "type": {
+            "defined": "CollateralStatus"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "CollateralWrapperAdded",
+      "fields": [
         {
-          "name": "saberProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
         {
-          "name": "amountIn",
-          "type": "u64"
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "minimumAmountOut",
-          "type": "u64"
+          "name": "wrapperMint",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "deposit",
-      "accounts": [
+      "name": "CollateralWrapperRemoved",
+      "fields": [
         {
-          "name": "depositor",
-          "isMut": false,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "bucket",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateToken",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "crateTokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "issueAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "crateCollateral",
-          "isMut": true,
-          "isSigner": false
+          "name": "wrapperMint",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "SwapPairAdded",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "collateralMint",
-          "isMut": false,
-          "isSigner": false
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "depositorCollateral",
-          "isMut": true,
-          "isSigner": false
+          "name": "mintA",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "depositorReserve",
-          "isMut": true,
-          "isSigner": false
+          "name": "mintB",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "oracle",
-          "isMut": false,
-          "isSigner": false
-        }
-      ],
-      "args": [
+          "name": "venue",
+          "type": {
+            "defined": "SwapVenue"
+          },
+          "index": false
+        },
         {
-          "name": "depositAmount",
-          "type": "u64"
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "redeem",
-      "accounts": [
+      "name": "SwapPairRemoved",
+      "fields": [
         {
-          "name": "withdrawer",
-          "isMut": false,
-          "isSigner": true
+          "name": "version",
+          "type": "u8",
+          "index": false
         },
         {
-          "name": "common",
-          "accounts": [
-            {
-              "name": "bucket",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateToken",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "crateMint",
-              "isMut": true,
-              "isSigner": false
-            },
-            {
-              "name": "crateTokenProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "systemProgram",
-              "isMut": false,
-              "isSigner": false
-            },
-            {
-              "name": "tokenProgram",
-              "isMut": false,
-              "isSigner": false
-            }
-          ]
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "withdrawAuthority",
-          "isMut": false,
-          "isSigner": false
+          "name": "mintA",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "withdrawerReserve",
-          "isMut": true,
-          "isSigner": false
-        }
-      ],
-      "args": [
-        {
-          "name": "withdrawAmount",
-          "type": "u64"
+          "name": "mintB",
+          "type": "publicKey",
+          "index": false
         }
       ]
-    }
-  ],
-  "accounts": [
-    {
-      "name": "bucket",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          },
-          {
-            "name": "crateMint",
-            "type": "publicKey"
-          },
-          {
-            "name": "crateToken",
-            "type": "publicKey"
-          },
-          {
-            "name": "authority",
-            "type": "publicKey"
-          },
-          {
-            "name": "rebalanceAuthority",
-            "type": "publicKey"
-          },
-          {
-            "name": "collateral",
-            "type": {
-              "vec": {
-                "defined": "Collateral"
-              }
-            }
-          }
-        ]
-      }
-    },
-    {
-      "name": "issueAuthority",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    },
-    {
-      "name": "withdrawAuthority",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "bump",
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "types": [
-    {
-      "name": "Collateral",
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "mint",
-            "type": "publicKey"
-          },
-          {
-            "name": "allocation",
-            "type": "u16"
-          }
-        ]
-      }
     },
     {
-      "name": "OracleSource",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Pyth"
-          },
-          {
-            "name": "Switchboard"
-          }
-        ]
-      }
+      "name": "KeeperBountySet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "keeperBounty",
+          "type": {
+            "defined": "KeeperBounty"
+          },
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PriceStatus",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Unknown"
-          },
-          {
-            "name": "Trading"
-          },
-          {
-            "name": "Halted"
+      "name": "RebalanceLimitsSet",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "limits",
+          "type": {
+            "defined": "RebalanceLimits"
           },
-          {
-            "name": "Auction"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     },
     {
-      "name": "CorpAction",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "NoCorpAct"
-          }
-        ]
-      }
+      "name": "Deposited",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "depositor",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "price",
+          "type": "i128",
+          "index": false
+        },
+        {
+          "name": "reserveIssued",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "PriceType",
-      "type": {
-        "kind": "enum",
-        "variants": [
-          {
-            "name": "Unknown"
+      "name": "Redeemed",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "withdrawer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "reserveBurned",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "shares",
+          "type": {
+            "vec": {
+              "defined": "RedeemedShare"
+            }
           },
-          {
-            "name": "Price"
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "Rebalanced",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "caller",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintIn",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "mintOut",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountIn",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountOut",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "pools",
+          "type": {
+            "vec": "publicKey"
           },
-          {
-            "name": "TWAP"
+          "index": false
+        },
+        {
+          "name": "keeperBounty",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "NavSnapshotTaken",
+      "fields": [
+        {
+          "name": "version",
+          "type": "u8",
+          "index": false
+        },
+        {
+          "name": "bucket",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "slot",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "totalValue",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "reserveSupply",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "navPerToken",
+          "type": "u128",
+          "index": false
+        },
+        {
+          "name": "collateralizationBps",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "weights",
+          "type": {
+            "vec": {
+              "defined": "CollateralWeight"
+            }
           },
-          {
-            "name": "Volatility"
-          }
-        ]
-      }
+          "index": false
+        }
+      ]
     }
   ],
   "errors": [
@@ -1189,8 +8452,8 @@ export const IDL: BucketProgram = {
     },
     {
       "code": 6001,
-      "name": "AllocationBpsError",
-      "msg": "Allocation bps error"
+      "name": "AllocationPpmError",
+      "msg": "Allocation ppm error"
     },
     {
       "code": 6002,
@@ -1224,28 +8487,303 @@ export const IDL: BucketProgram = {
     },
     {
       "code": 6008,
-      "name": "NumberOfSizeNotSupported",
-      "msg": "Number is too large and is not supported"
-    },
-    {
-      "code": 6009,
       "name": "UnableToLoadOracle",
       "msg": "Unable To Load Oracles"
     },
     {
-      "code": 6010,
+      "code": 6009,
       "name": "MathError",
       "msg": "Math Error"
     },
     {
-      "code": 6011,
+      "code": 6010,
       "name": "CastingFailure",
       "msg": "Casting Failure"
     },
     {
-      "code": 6012,
+      "code": 6011,
       "name": "InvalidOracle",
       "msg": "Oracle Values are invalid"
+    },
+    {
+      "code": 6012,
+      "name": "CollateralValuationAccountsMismatch",
+      "msg": "Collateral valuation accounts must match the bucket's authorized collateral"
+    },
+    {
+      "code": 6013,
+      "name": "RebalanceNotRequired",
+      "msg": "Collateral allocations are already at their targets"
+    },
+    {
+      "code": 6014,
+      "name": "RebalancePairMismatch",
+      "msg": "Rebalance accounts do not match the computed source and destination collateral"
+    },
+    {
+      "code": 6015,
+      "name": "RebalanceIncreasedDeviation",
+      "msg": "Rebalance would increase the bucket's total allocation deviation"
+    },
+    {
+      "code": 6016,
+      "name": "SwapValueLost",
+      "msg": "Swap lost more value than the slippage tolerance allows"
+    },
+    {
+      "code": 6017,
+      "name": "KeeperBountyTooLarge",
+      "msg": "Keeper bounty exceeds the max bounty bps"
+    },
+    {
+      "code": 6018,
+      "name": "InvalidKeeperBountyAccounts",
+      "msg": "Keeper bounty accounts are invalid"
+    },
+    {
+      "code": 6019,
+      "name": "InvalidRebalanceTokenAccounts",
+      "msg": "Rebalance token accounts must belong to the crate and bucket"
+    },
+    {
+      "code": 6020,
+      "name": "SwapPairMismatch",
+      "msg": "Swap pair does not cover the collateral being rebalanced"
+    },
+    {
+      "code": 6021,
+      "name": "InvalidSwapVenueAccounts",
+      "msg": "Swap venue accounts are invalid"
+    },
+    {
+      "code": 6022,
+      "name": "UnregisteredSwapProgram",
+      "msg": "Swap program is not registered for this swap pair"
+    },
+    {
+      "code": 6023,
+      "name": "UnregisteredSwapPool",
+      "msg": "Swap pool is not registered for this swap pair"
+    },
+    {
+      "code": 6024,
+      "name": "DecimalWrapperRequired",
+      "msg": "Collateral decimals differ, a decimal wrapper is required"
+    },
+    {
+      "code": 6025,
+      "name": "InvalidDecimalWrapperAccounts",
+      "msg": "Decimal wrapper accounts do not match the collateral wrapper config"
+    },
+    {
+      "code": 6026,
+      "name": "DecimalScalingOverflow",
+      "msg": "Scaling an amount between decimals overflowed"
+    },
+    {
+      "code": 6027,
+      "name": "InvalidRebalanceLimits",
+      "msg": "Rebalance limits are invalid"
+    },
+    {
+      "code": 6028,
+      "name": "RebalanceCooldownActive",
+      "msg": "Too few slots have passed since the last rebalance"
+    },
+    {
+      "code": 6029,
+      "name": "RebalanceTurnoverExceeded",
+      "msg": "Rebalance exceeds the bucket's turnover limit for the trailing window"
+    },
+    {
+      "code": 6030,
+      "name": "NoPendingAuthority",
+      "msg": "There is no pending authority transfer"
+    },
+    {
+      "code": 6031,
+      "name": "InvalidPendingAuthority",
+      "msg": "Pending authority is invalid"
+    },
+    {
+      "code": 6032,
+      "name": "TimelockEnabled",
+      "msg": "Timelock is enabled, admin changes must be enqueued as a proposal"
+    },
+    {
+      "code": 6033,
+      "name": "TimelockDisabled",
+      "msg": "Timelock is disabled, admin changes can be applied directly"
+    },
+    {
+      "code": 6034,
+      "name": "TimelockDelayTooLong",
+      "msg": "Timelock delay exceeds the max delay"
+    },
+    {
+      "code": 6035,
+      "name": "ProposalNotReady",
+      "msg": "Proposal cannot be executed before its eta slot"
+    },
+    {
+      "code": 6036,
+      "name": "UnauthorizedProposalCanceller",
+      "msg": "Only the bucket authority or guardian can cancel a proposal"
+    },
+    {
+      "code": 6037,
+      "name": "Paused",
+      "msg": "Operation is paused"
+    },
+    {
+      "code": 6038,
+      "name": "InvalidPauseFlags",
+      "msg": "Pause flags are invalid"
+    },
+    {
+      "code": 6039,
+      "name": "UnauthorizedPauser",
+      "msg": "Only the bucket authority or guardian can pause"
+    },
+    {
+      "code": 6040,
+      "name": "UnauthorizedGuardianUpdate",
+      "msg": "Only the bucket authority or the guardian can set the guardian"
+    },
+    {
+      "code": 6041,
+      "name": "BucketDecommissioned",
+      "msg": "Bucket is decommissioned"
+    },
+    {
+      "code": 6042,
+      "name": "BucketNotDecommissioned",
+      "msg": "Bucket must be decommissioned before it can be closed"
+    },
+    {
+      "code": 6043,
+      "name": "BucketNotEmpty",
+      "msg": "Bucket still holds collateral or has reserve supply"
+    },
+    {
+      "code": 6044,
+      "name": "BucketHasChildAccounts",
+      "msg": "Bucket still has collateral configs, swap pairs or collateral wrappers"
+    },
+    {
+      "code": 6045,
+      "name": "AccountResizeTooLarge",
+      "msg": "Account cannot grow by more than 10KB in a single instruction"
+    },
+    {
+      "code": 6046,
+      "name": "BucketAlreadyMigrated",
+      "msg": "Bucket account is already at the current version"
+    },
+    {
+      "code": 6047,
+      "name": "BucketAlreadySized",
+      "msg": "Bucket account is already at its full size"
+    },
+    {
+      "code": 6048,
+      "name": "InvalidBucketAccount",
+      "msg": "Bucket account data is invalid"
+    },
+    {
+      "code": 6049,
+      "name": "CollateralNotAcceptingDeposits",
+      "msg": "Collateral is not accepting deposits"
+    },
+    {
+      "code": 6050,
+      "name": "CollateralFrozen",
+      "msg": "Collateral is frozen"
+    },
+    {
+      "code": 6051,
+      "name": "CollateralCapExceeded",
+      "msg": "Deposit exceeds the collateral's cap"
+    },
+    {
+      "code": 6052,
+      "name": "InvalidCollateralConfig",
+      "msg": "Collateral config does not match the collateral"
+    },
+    {
+      "code": 6053,
+      "name": "CollateralStillAuthorized",
+      "msg": "Collateral config can only be closed once the collateral is removed"
+    },
+    {
+      "code": 6054,
+      "name": "UnsupportedOracleSource",
+      "msg": "Oracle source is not supported"
+    },
+    {
+      "code": 6055,
+      "name": "InvalidProposalAccounts",
+      "msg": "Accounts do not match the proposal's action"
+    },
+    {
+      "code": 6056,
+      "name": "CollateralConfigHasBalance",
+      "msg": "Collateral config can only be closed once the crate holds none of the collateral"
+    },
+    {
+      "code": 6057,
+      "name": "InvalidAllocationBand",
+      "msg": "Allocation band exceeds max bps"
+    },
+    {
+      "code": 6058,
+      "name": "CollateralWithinBand",
+      "msg": "Collateral is within its allocation band"
+    },
+    {
+      "code": 6059,
+      "name": "AllocationBandExceeded",
+      "msg": "Deposit would push the collateral above its allocation band"
+    },
+    {
+      "code": 6060,
+      "name": "InvalidAllocationRamp",
+      "msg": "Allocation ramp must end after it starts"
+    },
+    {
+      "code": 6061,
+      "name": "NoActiveAllocationRamp",
+      "msg": "No allocation ramp in progress"
+    },
+    {
+      "code": 6062,
+      "name": "DuplicateCollateral",
+      "msg": "Collateral mints must be unique"
+    },
+    {
+      "code": 6063,
+      "name": "OracleExponentUnsupported",
+      "msg": "Oracle exponent is not supported"
+    },
+    {
+      "code": 6064,
+      "name": "IssueAmountOverflow",
+      "msg": "Issue amount does not fit in a token amount"
+    },
+    {
+      "code": 6065,
+      "name": "InvalidRebalanceAccountCount",
+      "msg": "Wrong number of rebalance accounts"
+    },
+    {
+      "code": 6066,
+      "name": "InvalidRedeemAccountCount",
+      "msg": "Wrong number of redeem accounts"
+    },
+    {
+      "code": 6067,
+      "name": "NavSnapshotAlreadyTaken",
+      "msg": "A NAV snapshot was already taken in this slot"
     }
   ]
 };
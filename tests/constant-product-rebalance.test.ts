@@ -0,0 +1,465 @@
+import * as anchor from "@project-serum/anchor";
+import { BN, Program } from "@project-serum/anchor";
+import { TOKEN_PROGRAM_ID, u64 } from "@solana/spl-token";
+import {
+  Keypair,
+  LAMPORTS_PER_SOL,
+  PublicKey,
+  SystemProgram,
+} from "@solana/web3.js";
+import { expect } from "chai";
+
+import { expectThrowsAsync } from "./common/util";
+import { mockOracle } from "./helpers/pyth";
+import {
+  BucketClient,
+  computeSwapAmounts,
+  executeTx,
+  NodeWallet,
+} from "../sdk";
+
+// ==================================================================================
+// rebalances through the constant-product swap venue, using the mock-amm program as
+// the venue. the pool has no fees, so the swap output only reflects price impact.
+// ==================================================================================
+describe("constant-product rebalance", () => {
+  const _provider = anchor.Provider.env();
+
+  const client = new BucketClient(
+    _provider.connection,
+    _provider.wallet as anchor.Wallet
+  );
+
+  const nodeWallet = new NodeWallet(
+    anchor.Provider.env().connection,
+    anchor.Provider.env().wallet as anchor.Wallet
+  );
+
+  const mockAmm = anchor.workspace.MockAmm as Program;
+
+  let bucketKey: PublicKey;
+  let crateKey: PublicKey;
+  let issueAuthority: PublicKey;
+  let authority: Keypair;
+  let reserve: Keypair;
+
+  let collateralA: Keypair;
+  let collateralB: Keypair;
+  // a $1 oracle for each collateral, registered on the collateral's config
+  const oracles: { [mint: string]: PublicKey } = {};
+
+  let userA: Keypair;
+  // moves the pool price before the rebalance authority swaps through it
+  let manipulator: Keypair;
+
+  // ================================
+  // mock amm related config
+  // ================================
+  let pool: PublicKey;
+  let poolAuthority: PublicKey;
+  let reserveA: PublicKey;
+  let reserveB: PublicKey;
+
+  before("Create funded user accounts", async () => {
+    authority = await nodeWallet.createFundedWallet(10 * LAMPORTS_PER_SOL);
+    userA = await nodeWallet.createFundedWallet(1 * LAMPORTS_PER_SOL);
+    manipulator = await nodeWallet.createFundedWallet(1 * LAMPORTS_PER_SOL);
+  });
+
+  before("Create bucket", async () => {
+    reserve = Keypair.generate();
+    const {
+      tx: _sig,
+      bucket,
+      crateToken,
+      issueAuthority: _issueAuthority,
+    } = await client.createBucket(reserve, authority);
+
+    bucketKey = bucket;
+    crateKey = crateToken;
+    issueAuthority = _issueAuthority;
+  });
+
+  before("Mint collateral A & B", async () => {
+    collateralA = Keypair.generate();
+    collateralB = Keypair.generate();
+
+    for (const collateral of [collateralA, collateralB]) {
+      await executeTx(
+        client.provider.connection,
+        await client.mintTokens(
+          client.provider.connection,
+          authority.publicKey,
+          collateral.publicKey,
+          authority.publicKey,
+          authority.publicKey
+        ),
+        [authority, collateral]
+      );
+    }
+  });
+
+  before("deploy & seed mock amm pool", async () => {
+    let poolBump: number;
+    let authorityBump: number;
+    [pool, poolBump] = await PublicKey.findProgramAddress(
+      [
+        Buffer.from("pool"),
+        collateralA.publicKey.toBuffer(),
+        collateralB.publicKey.toBuffer(),
+      ],
+      mockAmm.programId
+    );
+    [poolAuthority, authorityBump] = await PublicKey.findProgramAddress(
+      [Buffer.from("authority"), pool.toBuffer()],
+      mockAmm.programId
+    );
+
+    // reserves are funded by minting directly into the pool authority's ATAs
+    const reserveAmount = new u64(1_000_000_000000);
+    for (const collateral of [collateralA, collateralB]) {
+      await executeTx(
+        client.provider.connection,
+        await client.initTokenAccount(
+          client.provider.connection,
+          collateral.publicKey,
+          poolAuthority,
+          authority.publicKey,
+          reserveAmount
+        ),
+        [authority]
+      );
+    }
+    reserveA = await client.findAssociatedTokenAddress(
+      poolAuthority,
+      collateralA.publicKey
+    );
+    reserveB = await client.findAssociatedTokenAddress(
+      poolAuthority,
+      collateralB.publicKey
+    );
+
+    await mockAmm.rpc.initializePool(poolBump, authorityBump, {
+      accounts: {
+        payer: client.provider.wallet.publicKey,
+        pool,
+        poolAuthority,
+        mintA: collateralA.publicKey,
+        mintB: collateralB.publicKey,
+        reserveA,
+        reserveB,
+        systemProgram: SystemProgram.programId,
+      },
+    });
+  });
+
+  before("Fund users' accounts with collateral A", async () => {
+    await executeTx(
+      client.provider.connection,
+      await client.initTokenAccount(
+        client.provider.connection,
+        collateralA.publicKey,
+        userA.publicKey,
+        authority.publicKey,
+        new u64(1_000_000000)
+      ),
+      [userA, authority]
+    );
+  });
+
+  it("Add collateral configs for tokens A & B", async () => {
+    for (const collateral of [collateralA, collateralB]) {
+      const oracle = await mockOracle(1);
+      await client.addCollateralConfig(
+        reserve.publicKey,
+        collateral.publicKey,
+        oracle,
+        authority
+      );
+
+      oracles[collateral.publicKey.toBase58()] = oracle;
+    }
+  });
+
+  it("Register the A/B mock amm pool as the bucket's swap pair", async () => {
+    await client.addSwapPair(
+      reserve.publicKey,
+      collateralA.publicKey,
+      collateralB.publicKey,
+      { constantProduct: {} },
+      mockAmm.programId,
+      authority,
+      pool
+    );
+
+    const swapPair = await client.fetchSwapPair(
+      bucketKey,
+      collateralA.publicKey,
+      collateralB.publicKey
+    );
+    expect(swapPair.swap.toBase58()).to.equal(pool.toBase58());
+    expect(swapPair.swapProgram.toBase58()).to.equal(
+      mockAmm.programId.toBase58()
+    );
+  });
+
+  it("User A deposits token A, receives reserve tokens", async () => {
+    await client.authorizeCollateral(
+      collateralA.publicKey,
+      1_000_000,
+      reserve.publicKey,
+      authority
+    );
+
+    const depositAmount = new u64(1_000_000000);
+    await client.deposit(
+      depositAmount,
+      reserve.publicKey,
+      collateralA.publicKey,
+      issueAuthority,
+      userA,
+      oracles[collateralA.publicKey.toBase58()]
+    );
+
+    const crateCollateralAfter = await client.fetchTokenBalance(
+      collateralA.publicKey,
+      crateKey
+    );
+    expect(crateCollateralAfter).to.equal(depositAmount.toNumber());
+  });
+
+  it("Rebalance A -> B through the mock amm", async () => {
+    // token B is under its target allocation once it is authorized
+    await client.authorizeCollateral(
+      collateralB.publicKey,
+      500_000,
+      reserve.publicKey,
+      authority
+    );
+
+    const crateTokenABalanceBefore = await client.fetchTokenBalance(
+      collateralA.publicKey,
+      crateKey
+    );
+    const poolReserveBBefore = await client.fetchTokenBalance(
+      collateralB.publicKey,
+      poolAuthority
+    );
+
+    const amountIn = 1_000000;
+    const maxSlippageBps = 100;
+    const expectedSwapAmount = computeSwapAmounts(amountIn, maxSlippageBps);
+
+    // the venue accounts must belong to the registered pool
+    await expectThrowsAsync(() =>
+      client.rebalance(
+        {
+          amountIn,
+          maxSlippageBps,
+          tokenA: collateralA.publicKey,
+          tokenB: collateralB.publicKey,
+          venueAccounts: [
+            Keypair.generate().publicKey,
+            poolAuthority,
+            reserveA,
+            reserveB,
+          ],
+        },
+        reserve.publicKey,
+        authority
+      )
+    );
+
+    await client.rebalance(
+      {
+        amountIn,
+        maxSlippageBps,
+        tokenA: collateralA.publicKey,
+        tokenB: collateralB.publicKey,
+        // A -> B swap: A is the input reserve, B is the output reserve
+        venueAccounts: [pool, poolAuthority, reserveA, reserveB],
+      },
+      reserve.publicKey,
+      authority
+    );
+
+    const crateTokenABalanceAfter = await client.fetchTokenBalance(
+      collateralA.publicKey,
+      crateKey
+    );
+    expect(crateTokenABalanceAfter).to.equal(
+      crateTokenABalanceBefore - expectedSwapAmount.amountIn.toNumber()
+    );
+
+    // everything the pool paid out is moved into the crate
+    const poolReserveBAfter = await client.fetchTokenBalance(
+      collateralB.publicKey,
+      poolAuthority
+    );
+    const crateTokenBBalanceAfter = await client.fetchTokenBalance(
+      collateralB.publicKey,
+      crateKey
+    );
+    expect(crateTokenBBalanceAfter).to.equal(
+      poolReserveBBefore - poolReserveBAfter
+    );
+    expect(crateTokenBBalanceAfter).to.be.at.least(
+      expectedSwapAmount.minAmountOut.toNumber()
+    );
+
+    const bucketTokenBBalanceAfter = await client.fetchTokenBalance(
+      collateralB.publicKey,
+      bucketKey
+    );
+    expect(bucketTokenBBalanceAfter).to.equal(0);
+  });
+
+  it("Charge turnover at the input value, even for a bad fill", async () => {
+    await client.setRebalanceLimits(
+      reserve.publicKey,
+      {
+        minSlotsBetweenRebalances: new u64(0),
+        turnoverWindowSlots: new u64(1_000_000),
+        maxTurnoverBps: 10_000,
+      },
+      authority
+    );
+
+    const rebalanceAToB = (amountIn: number) =>
+      client.rebalance(
+        {
+          amountIn,
+          maxSlippageBps: 100,
+          tokenA: collateralA.publicKey,
+          tokenB: collateralB.publicKey,
+          venueAccounts: [pool, poolAuthority, reserveA, reserveB],
+        },
+        reserve.publicKey,
+        authority
+      );
+
+    // swaps amountIn of A for B, returns the turnover charged and the B received
+    const measureRebalance = async (amountIn: number) => {
+      const { windowTurnover: turnoverBefore } =
+        await client.fetchRebalanceState(bucketKey);
+      const crateTokenBBalanceBefore = await client.fetchTokenBalance(
+        collateralB.publicKey,
+        crateKey
+      );
+
+      await rebalanceAToB(amountIn);
+
+      const { windowTurnover: turnoverAfter } =
+        await client.fetchRebalanceState(bucketKey);
+      const crateTokenBBalanceAfter = await client.fetchTokenBalance(
+        collateralB.publicKey,
+        crateKey
+      );
+
+      return {
+        turnover: (turnoverAfter as BN).sub(turnoverBefore as BN),
+        amountOut: crateTokenBBalanceAfter - crateTokenBBalanceBefore,
+      };
+    };
+
+    // a fair fill, at the oracle price. both oracles are $1, so the turnover of one token is the
+    // value of one token of either collateral.
+    const oneToken = 1_000000;
+    const fairFill = await measureRebalance(oneToken);
+    const oneTokenValue = fairFill.turnover;
+
+    // move the pool price by dumping A into it, so that selling A returns ~0.6% less B
+    const manipulatorA = await client.getOrCreateATA(
+      collateralA.publicKey,
+      manipulator.publicKey,
+      authority.publicKey,
+      client.provider.connection
+    );
+    const manipulatorB = await client.getOrCreateATA(
+      collateralB.publicKey,
+      manipulator.publicKey,
+      authority.publicKey,
+      client.provider.connection
+    );
+    await executeTx(
+      client.provider.connection,
+      [
+        ...(manipulatorB.instruction ? [manipulatorB.instruction] : []),
+        ...(await client.initTokenAccount(
+          client.provider.connection,
+          collateralA.publicKey,
+          manipulator.publicKey,
+          authority.publicKey,
+          new u64(3_000_000000)
+        )),
+      ],
+      [authority]
+    );
+    await mockAmm.rpc.swap(new u64(3_000_000000), new u64(0), {
+      accounts: {
+        pool,
+        poolAuthority,
+        userAuthority: manipulator.publicKey,
+        userSource: manipulatorA.address,
+        userDestination: manipulatorB.address,
+        inputReserve: reserveA,
+        outputReserve: reserveB,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      },
+      signers: [manipulator],
+    });
+
+    // the bad fill returns less B, but is charged the same turnover
+    const badFill = await measureRebalance(oneToken);
+    expect(badFill.amountOut).to.be.below(fairFill.amountOut);
+    expect(badFill.turnover.toString()).to.equal(oneTokenValue.toString());
+
+    // size the turnover limit between the value of a bad fill's output and the value of its input.
+    // if turnover were charged at the output value, the swap would fit in the window.
+    const amountIn = 100 * oneToken;
+    const poolReserveA = await client.fetchTokenBalance(
+      collateralA.publicKey,
+      poolAuthority
+    );
+    const poolReserveB = await client.fetchTokenBalance(
+      collateralB.publicKey,
+      poolAuthority
+    );
+    const expectedAmountOut = Math.floor(
+      (poolReserveB * amountIn) / (poolReserveA + amountIn)
+    );
+    const valueOf = (amount: number) =>
+      oneTokenValue.mul(new BN(amount)).div(new BN(oneToken));
+
+    const totalValue = valueOf(
+      (await client.fetchTokenBalance(collateralA.publicKey, crateKey)) +
+        (await client.fetchTokenBalance(collateralB.publicKey, crateKey))
+    );
+    const { windowTurnover } = await client.fetchRebalanceState(bucketKey);
+    const maxTurnoverBps = (windowTurnover as BN)
+      .add(valueOf(amountIn))
+      .muln(10_000)
+      .subn(1)
+      .div(totalValue)
+      .toNumber();
+    const maxTurnover = totalValue.muln(maxTurnoverBps).divn(10_000);
+    expect(
+      (windowTurnover as BN).add(valueOf(expectedAmountOut)).lte(maxTurnover)
+    ).to.be.true;
+
+    await client.setRebalanceLimits(
+      reserve.publicKey,
+      {
+        minSlotsBetweenRebalances: new u64(0),
+        turnoverWindowSlots: new u64(1_000_000),
+        maxTurnoverBps,
+      },
+      authority
+    );
+
+    await expectThrowsAsync(() => rebalanceAToB(amountIn));
+
+    // the window still has room for a smaller swap
+    await rebalanceAToB(oneToken);
+  });
+});
@@ -0,0 +1,142 @@
+import * as anchor from "@project-serum/anchor";
+import { u64 } from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import { mockOracle } from "./helpers/pyth";
+import { BucketClient, executeTx, NodeWallet } from "../sdk";
+import { MAX_ALLOCATION_PPM } from "../sdk/src/common/constant";
+
+// the runtime's default compute budget per instruction
+const MAX_COMPUTE_UNITS = 200_000;
+
+// ==================================================================================
+// benchmarks the compute units of a deposit as the number of authorized collateral
+// grows. the bucket is a zero-copy account, so a deposit only reads the collateral it
+// needs and its cost should stay flat.
+// ==================================================================================
+describe("deposit compute units", () => {
+  const _provider = anchor.Provider.env();
+
+  const client = new BucketClient(
+    _provider.connection,
+    _provider.wallet as anchor.Wallet
+  );
+
+  const nodeWallet = new NodeWallet(
+    anchor.Provider.env().connection,
+    anchor.Provider.env().wallet as anchor.Wallet
+  );
+
+  let authority: Keypair;
+
+  const computeUnits: { [numCollateral: number]: number } = {};
+
+  before("Create funded user accounts", async () => {
+    authority = await nodeWallet.createFundedWallet(100 * LAMPORTS_PER_SOL);
+  });
+
+  const fetchComputeUnits = async (signature: string): Promise<number> => {
+    await client.provider.connection.confirmTransaction(
+      signature,
+      "confirmed"
+    );
+    const tx = await client.provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+    });
+
+    const prefix = `Program ${client.bucketProgram.programId.toBase58()} consumed `;
+    const log = tx.meta.logMessages.filter((log) => log.startsWith(prefix))[0];
+    expect(log).to.not.be.undefined;
+
+    return +log.slice(prefix.length).split(" ")[0];
+  };
+
+  for (const numCollateral of [5, 50, 300]) {
+    it(`Deposit with ${numCollateral} authorized collateral`, async () => {
+      const reserve = Keypair.generate();
+      const { bucket, issueAuthority } = await client.createBucket(
+        reserve,
+        authority
+      );
+
+      // only the deposited collateral needs a mint, oracle and config. the rest of the
+      // collateral is never read by a deposit, so placeholder mints are enough.
+      const collateral = Keypair.generate();
+      await executeTx(
+        client.provider.connection,
+        await client.mintTokens(
+          client.provider.connection,
+          authority.publicKey,
+          collateral.publicKey,
+          authority.publicKey,
+          authority.publicKey
+        ),
+        [authority, collateral]
+      );
+
+      const oracle = await mockOracle(1);
+      await client.addCollateralConfig(
+        reserve.publicKey,
+        collateral.publicKey,
+        oracle,
+        authority
+      );
+
+      await client.authorizeCollateral(
+        collateral.publicKey,
+        MAX_ALLOCATION_PPM,
+        reserve.publicKey,
+        authority
+      );
+      for (let i = 1; i < numCollateral; i++) {
+        await client.authorizeCollateral(
+          Keypair.generate().publicKey,
+          Math.floor(MAX_ALLOCATION_PPM / numCollateral),
+          reserve.publicKey,
+          authority
+        );
+      }
+
+      const { collateral: collateralList } = await client.fetchBucket(bucket);
+      expect(collateralList.length).to.equal(numCollateral);
+
+      const depositAmount = new u64(1_000_000);
+      await executeTx(
+        client.provider.connection,
+        await client.initTokenAccount(
+          client.provider.connection,
+          collateral.publicKey,
+          authority.publicKey,
+          authority.publicKey,
+          depositAmount
+        ),
+        [authority]
+      );
+
+      const signature = await client.deposit(
+        depositAmount,
+        reserve.publicKey,
+        collateral.publicKey,
+        issueAuthority,
+        authority,
+        oracle
+      );
+
+      computeUnits[numCollateral] = await fetchComputeUnits(signature);
+      console.log(
+        `deposit with ${numCollateral} collateral => ${computeUnits[numCollateral]} compute units`
+      );
+
+      expect(computeUnits[numCollateral]).to.be.below(MAX_COMPUTE_UNITS);
+    });
+  }
+
+  after("Print compute units per deposit", () => {
+    console.table(
+      Object.keys(computeUnits).map((numCollateral) => ({
+        numCollateral,
+        computeUnits: computeUnits[numCollateral],
+      }))
+    );
+  });
+});
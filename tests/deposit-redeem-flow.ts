@@ -8,7 +8,17 @@ import {
   isApproximatelyEqual,
 } from "./common/util";
 import { mockOracle } from "./helpers/pyth";
-import { BucketClient, executeTx, NodeWallet } from "../sdk/dist/cjs";
+import {
+  BucketClient,
+  Collateral,
+  executeTx,
+  NodeWallet,
+} from "../sdk/dist/cjs";
+
+// collateral is kept sorted by mint, so look allocations up by mint
+const allocationOf = (collateral: Collateral[], mint: PublicKey) =>
+  collateral.filter((c) => c.mint.toBase58() === mint.toBase58())[0]
+    .allocation;
 
 describe("deposit-redeem-flow", () => {
   const _provider = anchor.Provider.env();
@@ -33,6 +43,8 @@ describe("deposit-redeem-flow", () => {
   let collateralA: Keypair;
   let collateralB: Keypair;
   let collateralC: Keypair;
+  // a $1 oracle for each collateral, registered on the collateral's config
+  const oracles: { [mint: string]: PublicKey } = {};
 
   let userA: Keypair;
 
@@ -143,9 +155,23 @@ describe("deposit-redeem-flow", () => {
     );
   });
 
+  it("Add collateral configs for mint A, B, C", async () => {
+    for (const collateral of [collateralA, collateralB, collateralC]) {
+      const oracle = await mockOracle(1);
+      await client.addCollateralConfig(
+        reserve.publicKey,
+        collateral.publicKey,
+        oracle,
+        authority
+      );
+
+      oracles[collateral.publicKey.toBase58()] = oracle;
+    }
+  });
+
   it("Authorize collateral mint A, B, C", async () => {
     // authorize collateral A
-    const allocationA: number = 10000;
+    const allocationA: number = 1_000_000;
     await client.authorizeCollateral(
       collateralA.publicKey,
       allocationA,
@@ -164,7 +190,7 @@ describe("deposit-redeem-flow", () => {
     ).to.be.true;
 
     // authorize collateral B
-    const allocationB: number = 6000;
+    const allocationB: number = 600_000;
     await client.authorizeCollateral(
       collateralB.publicKey,
       allocationB,
@@ -182,12 +208,16 @@ describe("deposit-redeem-flow", () => {
       ).length > 0
     ).to.be.true;
 
-    // 10000 bps - 6000 bps
-    expect(collateralListB[0].allocation).to.equal(4000);
-    expect(collateralListB[1].allocation).to.equal(allocationB);
+    // 1_000_000 ppm - 600_000 ppm
+    expect(allocationOf(collateralListB, collateralA.publicKey)).to.equal(
+      400_000
+    );
+    expect(allocationOf(collateralListB, collateralB.publicKey)).to.equal(
+      allocationB
+    );
 
     // authorize collateral C
-    const allocationC: number = 1200;
+    const allocationC: number = 120_000;
     await client.authorizeCollateral(
       collateralC.publicKey,
       allocationC,
@@ -205,21 +235,25 @@ describe("deposit-redeem-flow", () => {
       ).length > 0
     ).to.be.true;
 
-    // new alloc => 1200, broken down => 1200 * .4 = 480, 1200 * .6 = 720, respectively
-    // 4000 bps => 40% of new allocation => 4000 - 480 = 3520 bps
-    expect(collateralListC[0].allocation).to.equal(3520);
-    // 6000 bps => 60% of new allocation => 6000 - 720 = 5280 bps
-    expect(collateralListC[1].allocation).to.equal(5280);
-    // 1200 bps
-    expect(collateralListC[2].allocation).to.equal(allocationC);
+    // new alloc => 120_000, broken down => 120_000 * .4 = 48_000, 120_000 * .6 = 72_000, respectively
+    // 400_000 ppm => 40% of new allocation => 400_000 - 48_000 = 352_000 ppm
+    expect(allocationOf(collateralListC, collateralA.publicKey)).to.equal(
+      352_000
+    );
+    // 600_000 ppm => 60% of new allocation => 600_000 - 72_000 = 528_000 ppm
+    expect(allocationOf(collateralListC, collateralB.publicKey)).to.equal(
+      528_000
+    );
+    // 120_000 ppm
+    expect(allocationOf(collateralListC, collateralC.publicKey)).to.equal(
+      allocationC
+    );
   });
 
   it("User A deposits authorized collateral A, issue reserve tokens", async () => {
     // mint collateral and fund depositor ATA with collateral
     const depositAmount = new u64(1_000_000);
 
-    const oracle = await mockOracle(1);
-
     // fetch depositor ATA balance before deposit
     const depositorCollateralBefore = await client.fetchTokenBalance(
       collateralA.publicKey,
@@ -234,7 +268,7 @@ describe("deposit-redeem-flow", () => {
       collateralA.publicKey,
       issueAuthority,
       userA,
-      oracle
+      oracles[collateralA.publicKey.toBase58()]
     );
 
     // fetch depositor & crate ATA balances after deposit
@@ -262,7 +296,6 @@ describe("deposit-redeem-flow", () => {
   it("User B, C deposits authorized collateral B, C, issue reserve tokens", async () => {
     // mint collateral and fund depositor ATA with collateral
     const depositAmount = new u64(1_000_000);
-    const oracle = await mockOracle(1);
 
     // ==================================================================
     // collateral B checks & rpc call
@@ -287,7 +320,7 @@ describe("deposit-redeem-flow", () => {
       collateralB.publicKey,
       issueAuthority,
       userA,
-      oracle
+      oracles[collateralB.publicKey.toBase58()]
     );
 
     // fetch user B & crate ATA balances for collateral B after deposit
@@ -331,7 +364,7 @@ describe("deposit-redeem-flow", () => {
       collateralC.publicKey,
       issueAuthority,
       userA,
-      oracle
+      oracles[collateralC.publicKey.toBase58()]
     );
 
     // fetch user B & crate ATA balances for collateral B after deposit
@@ -56,7 +56,9 @@ export const AMP_FACTOR = 100;
 export const FEES: Fees = {
   adminTrade: DEFAULT_FEE,
   adminWithdraw: DEFAULT_FEE,
-  trade: new Percent(1, 4),
+  // below the 30 bps slippage cap on permissionless rebalances, so that stray collateral can be
+  // swapped out of the crate
+  trade: new Percent(4, 10_000),
   withdraw: DEFAULT_FEE,
 };
 
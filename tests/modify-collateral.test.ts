@@ -5,6 +5,11 @@ import { expect } from "chai";
 import { expectThrowsAsync } from "./common/util";
 import { BucketClient, Collateral, executeTx, NodeWallet } from "../sdk";
 
+// collateral is kept sorted by mint, so look allocations up by mint
+const allocationOf = (collateral: Collateral[], mint: PublicKey) =>
+  collateral.filter((c) => c.mint.toBase58() === mint.toBase58())[0]
+    .allocation;
+
 describe("modify-collateral", () => {
   const _provider = anchor.Provider.env();
 
@@ -68,7 +73,7 @@ describe("modify-collateral", () => {
 
   it("Authorize collateral mint A", async () => {
     // authorize collateral A
-    const allocationA: number = 10000;
+    const allocationA: number = 1_000_000;
     await client.authorizeCollateral(
       collateralA.publicKey,
       allocationA,
@@ -88,7 +93,7 @@ describe("modify-collateral", () => {
   });
 
   it("Attempt to re-authorize collateral mint", async () => {
-    const allocationA: number = 5000;
+    const allocationA: number = 500_000;
     expectThrowsAsync(() =>
       client.authorizeCollateral(
         collateralA.publicKey,
@@ -99,8 +104,8 @@ describe("modify-collateral", () => {
     );
   });
 
-  it("Attempt to authorize collateral mint with 10000 bps when other collaterals are already authorized", async () => {
-    const allocationB: number = 10000;
+  it("Attempt to authorize collateral mint with 1_000_000 ppm when other collaterals are already authorized", async () => {
+    const allocationB: number = 1_000_000;
     expectThrowsAsync(() =>
       client.authorizeCollateral(
         collateralB.publicKey,
@@ -113,7 +118,7 @@ describe("modify-collateral", () => {
 
   it("Authorize to collateral mints B, C, D", async () => {
     // authorize collateral B
-    const allocationB: number = 6000;
+    const allocationB: number = 600_000;
     await client.authorizeCollateral(
       collateralB.publicKey,
       allocationB,
@@ -131,12 +136,16 @@ describe("modify-collateral", () => {
       ).length > 0
     ).to.be.true;
 
-    // 10000 bps - 6000 bps
-    expect(collateralListB[0].allocation).to.equal(4000);
-    expect(collateralListB[1].allocation).to.equal(allocationB);
+    // 1_000_000 ppm - 600_000 ppm
+    expect(allocationOf(collateralListB, collateralA.publicKey)).to.equal(
+      400_000
+    );
+    expect(allocationOf(collateralListB, collateralB.publicKey)).to.equal(
+      allocationB
+    );
 
     // authorize collateral C
-    const allocationC: number = 1200;
+    const allocationC: number = 120_000;
     await client.authorizeCollateral(
       collateralC.publicKey,
       allocationC,
@@ -154,16 +163,22 @@ describe("modify-collateral", () => {
       ).length > 0
     ).to.be.true;
 
-    // new alloc => 1200, broken down => 1200 * .4 = 480, 1200 * .6 = 720, respectively
-    // 4000 bps => 40% of new allocation => 4000 - 480 = 3520 bps
-    expect(collateralListC[0].allocation).to.equal(3520);
-    // 6000 bps => 60% of new allocation => 6000 - 720 = 5280 bps
-    expect(collateralListC[1].allocation).to.equal(5280);
-    // 1200 bps
-    expect(collateralListC[2].allocation).to.equal(allocationC);
+    // new alloc => 120_000, broken down => 120_000 * .4 = 48_000, 120_000 * .6 = 72_000, respectively
+    // 400_000 ppm => 40% of new allocation => 400_000 - 48_000 = 352_000 ppm
+    expect(allocationOf(collateralListC, collateralA.publicKey)).to.equal(
+      352_000
+    );
+    // 600_000 ppm => 60% of new allocation => 600_000 - 72_000 = 528_000 ppm
+    expect(allocationOf(collateralListC, collateralB.publicKey)).to.equal(
+      528_000
+    );
+    // 120_000 ppm
+    expect(allocationOf(collateralListC, collateralC.publicKey)).to.equal(
+      allocationC
+    );
 
     // authorize collateral C
-    const allocationD: number = 1000;
+    const allocationD: number = 100_000;
     await client.authorizeCollateral(
       collateralD.publicKey,
       allocationD,
@@ -228,7 +243,7 @@ describe("modify-collateral", () => {
     // ========================================================
     // set with 1 additional mint, all shares equal for 6 mints
     // ========================================================
-    const equal_allocation = 1667;
+    const equal_allocation = 166_667;
     expectThrowsAsync(async () => {
       client.setCollateralAllocations(
         reserve.publicKey,
@@ -249,52 +264,52 @@ describe("modify-collateral", () => {
     });
 
     // ========================================================
-    // set allocations != 10000
+    // set allocations != 1_000_000
     // ========================================================
-    const newCollateralsAllocationOverMaxBps = [
+    const newCollateralsAllocationOverMaxPpm = [
       {
         mint: collateralA.publicKey,
-        allocation: 2000,
+        allocation: 200_000,
       },
       {
         mint: collateralB.publicKey,
-        allocation: 3000,
+        allocation: 300_000,
       },
       {
         mint: collateralC.publicKey,
-        allocation: 7000,
+        allocation: 700_000,
       },
     ];
 
     expectThrowsAsync(async () => {
       await client.setCollateralAllocations(
         reserve.publicKey,
-        newCollateralsAllocationOverMaxBps,
+        newCollateralsAllocationOverMaxPpm,
         authority
       );
     });
 
     // ============================================================
-    // set allocations == 10000, verify updated mints & allocations
+    // set allocations == 1_000_000, verify updated mints & allocations
     // ============================================================
-    const newCollateralsAllocationValidBps = [
+    const newCollateralsAllocationValidPpm = [
       {
         mint: collateralA.publicKey,
-        allocation: 2000,
+        allocation: 200_000,
       },
       {
         mint: collateralB.publicKey,
-        allocation: 3000,
+        allocation: 300_000,
       },
       {
         mint: collateralC.publicKey,
-        allocation: 5000,
+        allocation: 500_000,
       },
     ];
 
     await client.setCollateralAllocations(
       reserve.publicKey,
-      newCollateralsAllocationValidBps,
+      newCollateralsAllocationValidPpm,
       authority
     );
 
@@ -305,7 +320,7 @@ describe("modify-collateral", () => {
     // use loop to check equality instead of direct index checks since order might be different
     // than what's stored on-chain
     for (const col of collateralAfterUpdate) {
-      const updatedCollateral = newCollateralsAllocationValidBps.filter(
+      const updatedCollateral = newCollateralsAllocationValidPpm.filter(
         (newCol) => newCol.mint.toBase58() === col.mint.toBase58()
       )[0];
       expect(updatedCollateral.allocation).to.equal(col.allocation);
@@ -1,5 +1,6 @@
 import * as anchor from "@project-serum/anchor";
 import { u64 } from "@solana/spl-token";
+import { SWAP_PROGRAM_ID } from "@saberhq/stableswap-sdk";
 import { Keypair, LAMPORTS_PER_SOL, PublicKey } from "@solana/web3.js";
 import { expect } from "chai";
 
@@ -12,11 +13,16 @@ import { mockOracle } from "./helpers/pyth";
 import { PoolClient, verifyPoolIntegrity } from "./helpers/saber/pool";
 import {
   BucketClient,
+  Collateral,
   executeTx,
   NodeWallet,
   computeSwapAmounts,
 } from "../sdk";
-import { LOCALNET, DEVNET } from "../sdk/src/common/constant";
+
+// collateral is kept sorted by mint, so look allocations up by mint
+const allocationOf = (collateral: Collateral[], mint: PublicKey) =>
+  collateral.filter((c) => c.mint.toBase58() === mint.toBase58())[0]
+    .allocation;
 
 describe("rebalance underlyings", () => {
   const _provider = anchor.Provider.env();
@@ -40,6 +46,8 @@ describe("rebalance underlyings", () => {
 
   let collateralA: Keypair;
   let collateralB: Keypair;
+  // a $1 oracle for each collateral, registered on the collateral's config
+  const oracles: { [mint: string]: PublicKey } = {};
 
   let userA: Keypair;
 
@@ -140,8 +148,43 @@ describe("rebalance underlyings", () => {
     }
   });
 
+  it("Add collateral configs for tokens A & B", async () => {
+    for (const collateral of [collateralA, collateralB]) {
+      const oracle = await mockOracle(1);
+      await client.addCollateralConfig(
+        reserve.publicKey,
+        collateral.publicKey,
+        oracle,
+        authority
+      );
+
+      oracles[collateral.publicKey.toBase58()] = oracle;
+    }
+  });
+
+  it("Register the A/B saber pool as the bucket's swap pair", async () => {
+    await client.addSwapPair(
+      reserve.publicKey,
+      collateralA.publicKey,
+      collateralB.publicKey,
+      { saber: {} },
+      SWAP_PROGRAM_ID,
+      authority,
+      abPool.stableSwapAccount.publicKey
+    );
+
+    const swapPair = await client.fetchSwapPair(
+      bucketKey,
+      collateralA.publicKey,
+      collateralB.publicKey
+    );
+    expect(swapPair.swap.toBase58()).to.equal(
+      abPool.stableSwapAccount.publicKey.toBase58()
+    );
+  });
+
   it("Authorize tokens A & B as collateraal", async () => {
-    const allocationA: number = 10_000;
+    const allocationA: number = 1_000_000;
     await client.authorizeCollateral(
       collateralA.publicKey,
       allocationA,
@@ -159,7 +202,7 @@ describe("rebalance underlyings", () => {
       ).length > 0
     ).to.be.true;
 
-    const allocationB: number = 5_000;
+    const allocationB: number = 500_000;
     await client.authorizeCollateral(
       collateralB.publicKey,
       allocationB,
@@ -177,14 +220,15 @@ describe("rebalance underlyings", () => {
       ).length > 0
     ).to.be.true;
 
-    expect(collateralListB[0].allocation).to.be.equal(5_000);
-    expect(collateralListB[1].allocation).to.be.equal(5_000);
+    expect(allocationOf(collateralListB, collateralA.publicKey)).to.be.equal(
+      500_000
+    );
+    expect(allocationOf(collateralListB, collateralB.publicKey)).to.be.equal(
+      500_000
+    );
   });
 
   it("User A deposits token A and token B, receives reserve tokens", async () => {
-    const oracle = await mockOracle(1);
-    console.log("MOCK ORACLE: ", oracle);
-
     for (const collateral of [
       {
         mint: collateralA.publicKey,
@@ -213,7 +257,7 @@ describe("rebalance underlyings", () => {
         collateral.mint,
         issueAuthority,
         userA,
-        oracle // todo: future figure out how to create a more dynamic mapping
+        oracles[collateral.mint.toBase58()]
       );
 
       // fetch depositor & crate ATA balances after deposit
@@ -291,9 +335,7 @@ describe("rebalance underlyings", () => {
       client.removeUnauthorizedCollateralTokens(
         collateralA.publicKey,
         reserve.publicKey,
-        userA,
-        DEVNET, // ignored for localnet when we supply stableswap account
-        abPool.stableSwapAccount.publicKey
+        userA
       )
     );
 
@@ -311,9 +353,7 @@ describe("rebalance underlyings", () => {
     await client.removeUnauthorizedCollateralTokens(
       collateralB.publicKey,
       reserve.publicKey,
-      userA,
-      LOCALNET,
-      abPool.stableSwapAccount.publicKey
+      userA
     );
 
     const createTokenBAmountAfter = await client.fetchTokenBalance(
@@ -490,7 +530,6 @@ describe("rebalance underlyings", () => {
           maxSlippageBps,
           tokenA: collateralA.publicKey,
           tokenB: collateralB.publicKey,
-          swapAccount: abPool.stableSwapAccount.publicKey,
         },
         reserve.publicKey,
         authority
@@ -498,7 +537,7 @@ describe("rebalance underlyings", () => {
     );
 
     // re-authorize token
-    const allocationB: number = 5_000;
+    const allocationB: number = 500_000;
     await client.authorizeCollateral(
       collateralB.publicKey,
       allocationB,
@@ -513,7 +552,6 @@ describe("rebalance underlyings", () => {
         maxSlippageBps,
         tokenA: collateralA.publicKey,
         tokenB: collateralB.publicKey,
-        swapAccount: abPool.stableSwapAccount.publicKey,
       },
       reserve.publicKey,
       authority
@@ -543,7 +581,8 @@ describe("rebalance underlyings", () => {
       collateralB.publicKey,
       crateKey
     );
-    expect(crateTokenCBalanceAfter).to.equal(
+    // everything the swap produced is moved back into the crate
+    expect(crateTokenCBalanceAfter).to.be.at.least(
       crateTokenBBalanceBefore + expectedSwapAmount.minAmountOut.toNumber()
     );
   });